@@ -0,0 +1,76 @@
+//!Runtime companion of `auto-trait` implementations
+//!
+//!Wrapper implements [Delegate](trait.Delegate.html) instead of `Deref`, `DerefMut` and `Into`,
+//!so that it forwards traits without exposing its inner value through dereference.
+//!Fallible counterpart of the trait, generated by `try_trait` option, reports failures as [AccessError](enum.AccessError.html).
+//!
+//!```rust
+//!use auto_trait_delegate::Delegate;
+//!
+//!pub struct Wrapper(u32);
+//!
+//!impl Delegate for Wrapper {
+//!    type Target = u32;
+//!
+//!    fn delegate(&self) -> &Self::Target {
+//!        &self.0
+//!    }
+//!
+//!    fn delegate_mut(&mut self) -> &mut Self::Target {
+//!        &mut self.0
+//!    }
+//!
+//!    fn delegate_owned(self) -> Self::Target {
+//!        self.0
+//!    }
+//!}
+//!
+//!let mut wrapper = Wrapper(1);
+//!*wrapper.delegate_mut() += 1;
+//!assert_eq!(*wrapper.delegate(), 2);
+//!assert_eq!(wrapper.delegate_owned(), 2);
+//!```
+
+#![no_std]
+#![warn(missing_docs)]
+
+use core::fmt;
+
+///Conversion of wrapper into value, which traits are forwarded to
+///
+///Used by `#[auto_trait(via = Delegate)]`, converting `&self`, `&mut self` and `self` respectively.
+pub trait Delegate {
+    ///Type to forward to
+    type Target;
+
+    ///Borrows value to forward `&self` methods to
+    fn delegate(&self) -> &Self::Target;
+    ///Mutably borrows value to forward `&mut self` methods to
+    fn delegate_mut(&mut self) -> &mut Self::Target;
+    ///Converts into value to forward `self` methods to
+    fn delegate_owned(self) -> Self::Target;
+}
+
+///Failure to access value, which method is forwarded to
+///
+///Returned by `#[auto_trait(try_trait(...))]` implementations instead of panicking, unless error type is specified,
+///which is converted from this error by `From`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AccessError {
+    ///`Mutex` is poisoned
+    Poisoned,
+    ///`RefCell` is already borrowed incompatibly
+    Borrowed,
+    ///`Weak` pointer is dropped
+    Dropped,
+}
+
+impl fmt::Display for AccessError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessError::Poisoned => fmt.write_str("Mutex is poisoned"),
+            AccessError::Borrowed => fmt.write_str("RefCell is already borrowed"),
+            AccessError::Dropped => fmt.write_str("Weak pointer is dropped"),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//!Measures time of expanding large traits for several targets
+//!
+//!Run with `cargo bench -p auto-trait-expand`.
+
+use proc_macro2::TokenStream;
+use std::time::Instant;
+
+#[path = "../tests/large/mod.rs"]
+mod large;
+
+fn measure(name: &str, args: TokenStream, input: TokenStream, repeat: u32) {
+    //Warm up allocator, so that first iteration doesn't skew result
+    auto_trait_expand::expand(args.clone(), input.clone()).expect("to expand");
+
+    let started = Instant::now();
+    for _ in 0..repeat {
+        let output = auto_trait_expand::expand(args.clone(), input.clone()).expect("to expand");
+        std::hint::black_box(output);
+    }
+    println!("Expansion of {}: {:?} per iteration", name, started.elapsed() / repeat);
+}
+
+fn main() {
+    measure(&format!("trait with {} methods for 8 targets", large::METHODS), large::args(), large::input(), 50);
+    measure(&format!("trait with {} methods for 5 targets", large::HUGE_METHODS), large::args_with(3), large::input_with(large::HUGE_METHODS), 20);
+}
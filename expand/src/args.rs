@@ -0,0 +1,947 @@
+//!Attribute arguments parsing
+
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::ext::IdentExt;
+use syn::Token;
+
+use crate::diagnostic::{self, Code};
+use crate::strategy::Receiver;
+use crate::target::Target;
+
+///Alternative way to reach forwarding target
+#[derive(Clone)]
+pub enum Dispatch {
+    ///Matches on variants of the target, each holding single value, `Left` and `Right` unless specified
+    ///
+    ///Variant is paired with type of its value, if it is known, e.g. for enum generated by `enum` option.
+    Match(Vec<(syn::Ident, Option<syn::Type>)>),
+}
+
+impl Parse for Dispatch {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let token = input.parse::<Token![match]>()?;
+        if !input.peek(syn::token::Paren) {
+            return Ok(Dispatch::Match(vec![(syn::Ident::new("Left", token.span), None), (syn::Ident::new("Right", token.span), None)]));
+        }
+
+        let content;
+        let parens = syn::parenthesized!(content in input);
+        let variants = Punctuated::<syn::Ident, Token![,]>::parse_terminated(&content)?;
+        if variants.is_empty() {
+            return Err(diagnostic::error(parens.span.join(), Code::DispatchVariantsRequired, "`dispatch = match(...)` requires variants to match on, e.g. `dispatch = match(Fast, Safe)`"));
+        }
+        Ok(Dispatch::Match(variants.into_iter().map(|variant| (variant, None)).collect()))
+    }
+}
+
+///Policy on failure to upgrade `Weak` pointer
+#[derive(Clone, Copy, Default)]
+pub enum Upgrade {
+    ///Panics
+    #[default]
+    Panic,
+    ///Returns `Default::default()`
+    Default,
+}
+
+impl Parse for Upgrade {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: syn::Ident = input.parse()?;
+        if name == "panic" {
+            Ok(Upgrade::Panic)
+        } else if name == "default" {
+            Ok(Upgrade::Default)
+        } else {
+            Err(diagnostic::error_spanned(&name, Code::UnknownUpgradePolicy, format!("Unknown upgrade policy '{}', expected `panic` or `default`", name)))
+        }
+    }
+}
+
+///Body of methods in stub implementation
+#[derive(Clone, Copy)]
+pub enum Stub {
+    ///Panics with `unimplemented!()`
+    Panic,
+    ///Does nothing for methods returning `()`, panicking otherwise
+    Noop,
+}
+
+impl Parse for Stub {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: syn::Ident = input.parse()?;
+        if name == "panic" {
+            Ok(Stub::Panic)
+        } else if name == "noop" {
+            Ok(Stub::Noop)
+        } else {
+            Err(diagnostic::error_spanned(&name, Code::UnknownStubKind, format!("Unknown stub kind '{}', expected `panic` or `noop`", name)))
+        }
+    }
+}
+
+///Inline hint for generated methods
+#[derive(Clone, Copy)]
+pub enum Inline {
+    ///`#[inline]`
+    Hint,
+    ///`#[inline(always)]`
+    Always,
+    ///`#[inline(never)]`
+    Never,
+}
+
+impl Inline {
+    ///Returns attribute to annotate method with
+    pub fn attribute(self) -> syn::Attribute {
+        match self {
+            Inline::Hint => syn::parse_quote!(#[inline]),
+            Inline::Always => syn::parse_quote!(#[inline(always)]),
+            Inline::Never => syn::parse_quote!(#[inline(never)]),
+        }
+    }
+}
+
+///Instrumentation of every forwarded call
+#[derive(Clone)]
+pub enum Instrument {
+    ///Annotates methods with `instrument` attribute of `tracing` crate at specified path
+    Tracing(syn::Path),
+    ///Calls function with names of trait and method before forwarding
+    OnCall(syn::Path),
+}
+
+impl Instrument {
+    ///Parses `instrument` option, following its name
+    fn parse(name: &syn::Ident, input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let option: syn::Ident = content.parse()?;
+            if option != "on_call" {
+                return Err(diagnostic::error_spanned(&option, Code::UnknownInstrumentOption, format!("Unknown instrument option '{}', expected `on_call = <function>`", option)));
+            }
+            content.parse::<Token![=]>()?;
+            return Ok(Instrument::OnCall(content.parse()?));
+        }
+
+        let path = match input.parse::<Option<Token![=]>>()? {
+            Some(_) => input.parse()?,
+            None => syn::parse_quote!(::tracing),
+        };
+        match cfg!(feature = "tracing") {
+            true => Ok(Instrument::Tracing(path)),
+            false => Err(diagnostic::error_spanned(name, Code::TracingFeatureRequired, "`instrument` option requires `tracing` feature of auto-trait, or use `instrument(on_call = <function>)` instead")),
+        }
+    }
+}
+
+///Fallible counterpart of the trait, generated by `try_trait` option
+#[derive(Clone)]
+pub struct TryTrait {
+    ///Name of generated trait
+    pub name: syn::Ident,
+    ///Error type returned by its methods, `AccessError` of runtime companion crate by default
+    pub error: Option<syn::Type>,
+}
+
+impl Parse for TryTrait {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let mut error = None;
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let option: syn::Ident = input.parse()?;
+            if option != "error" {
+                return Err(diagnostic::error_spanned(&option, Code::UnknownTryTraitOption, format!("Unknown try_trait option '{}', expected `error = <type>`", option)));
+            }
+            input.parse::<Token![=]>()?;
+            error = Some(input.parse()?);
+        }
+
+        Ok(Self {
+            name,
+            error,
+        })
+    }
+}
+
+///Options of generated equivalence tests
+#[derive(Clone)]
+pub struct TestOptions {
+    ///Function returning target and its inner value
+    pub with: syn::Path,
+    ///Methods not to test
+    pub skip: Vec<syn::Ident>,
+}
+
+impl Parse for TestOptions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut with = None;
+        let mut skip = Vec::new();
+
+        while !input.is_empty() {
+            let name: syn::Ident = input.parse()?;
+            if name == "with" {
+                input.parse::<Token![=]>()?;
+                with = Some(input.parse()?);
+            } else if name == "skip" {
+                let content;
+                syn::parenthesized!(content in input);
+                skip.extend(Punctuated::<syn::Ident, Token![,]>::parse_terminated(&content)?);
+            } else {
+                return Err(diagnostic::error_spanned(&name, Code::UnknownTestOption, format!("Unknown test option '{}'", name)));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        match with {
+            Some(with) => Ok(Self {
+                with,
+                skip,
+            }),
+            None => Err(diagnostic::error(input.span(), Code::TestFunctionRequired, "`test` requires function to create target with `with = <function>`")),
+        }
+    }
+}
+
+///Options of `auto_trait` attribute
+#[derive(Clone, Default)]
+pub struct Options {
+    ///Explicitly selected parameter to forward to
+    pub forward: Option<syn::Ident>,
+    ///Dispatch strategy
+    pub dispatch: Option<Dispatch>,
+    ///Name of enum to generate over targets of the attribute, dispatching to them
+    pub dispatch_enum: Option<syn::Ident>,
+    ///Name of target's variant within enum, generated by `enum` option
+    pub variant: Option<syn::Ident>,
+    ///Whether to forward `&mut self` methods by locking
+    pub lock_mut: bool,
+    ///`Weak` upgrade failure policy, `panic` by default
+    pub upgrade: Option<Upgrade>,
+    ///Function to use instead of `Deref::deref`
+    pub deref: Option<syn::Path>,
+    ///Function to use instead of `DerefMut::deref_mut`
+    pub deref_mut: Option<syn::Path>,
+    ///Function to use instead of `Into::into`
+    pub into: Option<syn::Path>,
+    ///Field of target to forward to, instead of `Deref`
+    pub field: Option<syn::Member>,
+    ///Trait of `Delegate` shape, which converts target instead of `Deref`, `DerefMut` and `Into`
+    pub via: Option<syn::Path>,
+    ///Span of `via = transparent` option, if target is converted through `bytemuck::TransparentWrapper` instead of `Deref`, `DerefMut` and `Into`
+    pub transparent: Option<proc_macro2::Span>,
+    ///Pinned field of target, which methods taking pinned `self` are forwarded to through `pin-project` projection
+    pub pin_project: Option<syn::Member>,
+    ///Path to `core` crate used by generated code
+    pub crate_path: Option<syn::Path>,
+    ///Inline hint for generated methods
+    pub inline: Option<Inline>,
+    ///Predicates of `cfg` attribute to gate generated implementations with
+    pub cfg: Vec<proc_macro2::TokenStream>,
+    ///Additional predicates of generated implementation's where clause
+    pub bounds: Vec<syn::WherePredicate>,
+    ///Whether to print generated implementations
+    pub debug: bool,
+    ///Whether to only validate target, without emitting its implementations
+    pub dry_run: bool,
+    ///Whether to export trait's definition for `AutoTrait` derive
+    pub export: bool,
+    ///Methods to forward, if restricted
+    pub only: Option<Vec<syn::Ident>>,
+    ///Methods not to forward
+    pub except: Vec<syn::Ident>,
+    ///Whether to skip `&mut self` methods
+    pub no_mut: bool,
+    ///Whether to skip methods taking `self` by value
+    pub no_owned: bool,
+    ///Whether to forward to target through `Deref`, even when its name is the same as of built-in target, e.g. `Mutex`
+    pub no_builtin: bool,
+    ///Span of `unsafe` option, if implementation of unsafe trait is acknowledged
+    pub unsafe_impl: Option<proc_macro2::Span>,
+    ///Path to the trait used by generated implementation
+    pub trait_path: Option<syn::Path>,
+    ///Equivalence tests to generate
+    pub test: Option<TestOptions>,
+    ///Type to forward to in `cfg(test)` builds, instead of the usual one
+    pub test_target: Option<syn::Type>,
+    ///Whether to generate stub implementation instead of forwarding
+    pub stub: Option<Stub>,
+    ///Prefix of module to place generated implementations in
+    pub module: Option<syn::Ident>,
+    ///Span of `also_ref` option, if trait is to be implemented for shared reference to every target
+    pub also_ref: Option<proc_macro2::Span>,
+    ///Span of `also_mut_ref` option, if trait is to be implemented for mutable reference to every target
+    pub also_mut_ref: Option<proc_macro2::Span>,
+    ///Span of `inherent` option, if trait's methods are to be also generated as inherent methods of target
+    pub inherent: Option<proc_macro2::Span>,
+    ///Function to pass result of every forwarded call to
+    pub map_return: Option<syn::Path>,
+    ///Module with `before` and `after` functions to call around every forwarded call
+    pub hooks: Option<syn::Path>,
+    ///Instrumentation of every forwarded call
+    pub instrument: Option<Instrument>,
+    ///Name of wrapper struct to generate as target
+    pub newtype: Option<syn::Ident>,
+    ///Name of wrapper struct to generate as target, which caches results of methods marked with `cache`
+    pub cache: Option<syn::Ident>,
+    ///Fallible counterpart of the trait to generate and implement for targets, which can fail to access value
+    pub try_trait: Option<TryTrait>,
+    ///Span of `strict` option, if items left with their default implementation are to be reported as error
+    pub strict: Option<proc_macro2::Span>,
+    ///Message of panics in generated methods
+    pub panic_msg: Option<syn::LitStr>,
+    ///Generics with where clause, replacing generics of generated implementation
+    pub generics: Option<syn::Generics>,
+    ///Whether to document generated implementations with what they forward to
+    pub doc_note: bool,
+    ///Span of `const_impl` option, if implementation is to be `impl const`
+    pub const_impl: Option<proc_macro2::Span>,
+    ///Type to forward to by single blanket implementation with `default` methods, which wrappers can specialize
+    pub specialization: Option<syn::Type>,
+    ///Whether method bodies are generated once by hidden helper trait, which targets implement by conversion only
+    pub shared: bool,
+}
+
+///Names of options, which can be specified without targets
+const TRAIT_OPTIONS: &[&str] = &["crate_path", "trait_path", "export", "registered", "module"];
+
+///Hint, describing what attribute expects
+const EXPECTED_TARGET: &str = "expected a target type, e.g. #[auto_trait(Wrapper)] or #[auto_trait(Box<T: MyTrait>)]";
+
+///Names of supported options
+const OPTIONS: &[&str] = &["forward", "dispatch", "enum", "variant", "lock_mut", "upgrade", "deref", "deref_mut", "into", "field", "via", "pin_project", "crate_path", "inline", "cfg", "bound", "debug", "dry_run", "export", "registered", "only", "except", "ref_only", "no_mut", "no_owned", "no_builtin", "unsafe", "trait_path", "test", "test_target", "stub", "module", "also_ref", "also_mut_ref", "inherent", "map_return", "hooks", "instrument", "newtype", "cache", "try_trait", "shared", "strict", "panic_msg", "generics", "doc_note", "const_impl", "specialization"];
+
+impl Options {
+    ///Returns path to `core` crate, `::core` by default
+    pub fn core_path(&self) -> proc_macro2::TokenStream {
+        match self.crate_path {
+            Some(ref path) => quote::quote! {
+                #path
+            },
+            None => crate::extern_crate("core", proc_macro2::Span::call_site()),
+        }
+    }
+
+    ///Checks whether input starts with option, rather than target
+    ///
+    ///Lowercase name, which is close to option's name, is treated as misspelled option, rather than target.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        match fork.call(syn::Ident::parse_any) {
+            Ok(name) => {
+                let name = name.to_string();
+                let is_option = OPTIONS.contains(&name.as_str()) || (!name.chars().any(char::is_uppercase) && closest_option(&name).is_some());
+                is_option && (fork.is_empty() || fork.peek(Token![,]) || fork.peek(Token![=]) || fork.peek(syn::token::Paren))
+            },
+            Err(_) => false,
+        }
+    }
+
+    ///Inherits options of attribute, which are not specified for target
+    pub fn inherit(&mut self, parent: &Options) {
+        fn inherit_option<T: Clone>(option: &mut Option<T>, parent: &Option<T>) {
+            if option.is_none() {
+                *option = parent.clone();
+            }
+        }
+
+        fn inherit_list<T: Clone>(list: &mut Vec<T>, parent: &[T]) {
+            list.splice(0..0, parent.iter().cloned());
+        }
+
+        inherit_option(&mut self.forward, &parent.forward);
+        inherit_option(&mut self.dispatch, &parent.dispatch);
+        inherit_option(&mut self.upgrade, &parent.upgrade);
+        inherit_option(&mut self.deref, &parent.deref);
+        inherit_option(&mut self.deref_mut, &parent.deref_mut);
+        inherit_option(&mut self.into, &parent.into);
+        inherit_option(&mut self.field, &parent.field);
+        inherit_option(&mut self.via, &parent.via);
+        inherit_option(&mut self.transparent, &parent.transparent);
+        inherit_option(&mut self.pin_project, &parent.pin_project);
+        inherit_option(&mut self.crate_path, &parent.crate_path);
+        inherit_option(&mut self.inline, &parent.inline);
+        inherit_option(&mut self.only, &parent.only);
+        inherit_option(&mut self.trait_path, &parent.trait_path);
+        inherit_option(&mut self.test, &parent.test);
+        inherit_option(&mut self.test_target, &parent.test_target);
+        inherit_option(&mut self.stub, &parent.stub);
+        inherit_option(&mut self.module, &parent.module);
+        inherit_option(&mut self.also_ref, &parent.also_ref);
+        inherit_option(&mut self.also_mut_ref, &parent.also_mut_ref);
+        inherit_option(&mut self.inherent, &parent.inherent);
+        inherit_option(&mut self.map_return, &parent.map_return);
+        inherit_option(&mut self.hooks, &parent.hooks);
+        inherit_option(&mut self.instrument, &parent.instrument);
+        inherit_option(&mut self.try_trait, &parent.try_trait);
+        inherit_option(&mut self.strict, &parent.strict);
+        inherit_option(&mut self.unsafe_impl, &parent.unsafe_impl);
+        inherit_option(&mut self.panic_msg, &parent.panic_msg);
+        inherit_option(&mut self.generics, &parent.generics);
+        inherit_option(&mut self.const_impl, &parent.const_impl);
+        inherit_list(&mut self.cfg, &parent.cfg);
+        inherit_list(&mut self.bounds, &parent.bounds);
+        inherit_list(&mut self.except, &parent.except);
+        self.lock_mut |= parent.lock_mut;
+        self.debug |= parent.debug;
+        self.dry_run |= parent.dry_run;
+        self.export |= parent.export;
+        self.no_mut |= parent.no_mut;
+        self.no_owned |= parent.no_owned;
+        self.no_builtin |= parent.no_builtin;
+        self.doc_note |= parent.doc_note;
+        self.shared |= parent.shared;
+    }
+
+    ///Returns `cfg` attribute, combining all predicates, if any
+    pub fn cfg_attribute(&self) -> Option<syn::Attribute> {
+        match self.cfg.as_slice() {
+            [] => None,
+            [predicate] => Some(syn::parse_quote!(#[cfg(#predicate)])),
+            predicates => Some(syn::parse_quote!(#[cfg(all(#(#predicates),*))])),
+        }
+    }
+
+    ///Returns name of filter, which excludes method from forwarding, if any
+    pub fn excluded_by(&self, method: &syn::TraitItemFn) -> Option<&'static str> {
+        let name = &method.sig.ident;
+        if let Some(ref only) = self.only {
+            if !only.contains(name) {
+                return Some("only");
+            }
+        }
+
+        if self.except.contains(name) {
+            return Some("except");
+        }
+
+        match Receiver::new(&method.sig) {
+            Receiver::Mut if self.no_mut => Some(if self.no_owned { "ref_only" } else { "no_mut" }),
+            Receiver::Owned if self.no_owned => Some(if self.no_mut { "ref_only" } else { "no_owned" }),
+            _ => None,
+        }
+    }
+
+    ///Returns methods named by filters
+    pub fn filtered_methods(&self) -> impl Iterator<Item = &syn::Ident> {
+        self.only.iter().flatten().chain(self.except.iter()).chain(self.test.iter().flat_map(|test| test.skip.iter()))
+    }
+
+    pub fn parse_option(&mut self, input: ParseStream) -> syn::Result<syn::Ident> {
+        let name = input.call(syn::Ident::parse_any)?;
+        self.parse_value(&name, input)?;
+        Ok(name)
+    }
+
+    fn parse_value(&mut self, name: &syn::Ident, input: ParseStream) -> syn::Result<()> {
+        if name == "forward" {
+            input.parse::<Token![=]>()?;
+            self.forward = Some(input.parse()?);
+            Ok(())
+        } else if name == "dispatch" {
+            input.parse::<Token![=]>()?;
+            self.dispatch = Some(input.parse()?);
+            Ok(())
+        } else if name == "enum" {
+            input.parse::<Token![=]>()?;
+            self.dispatch_enum = Some(input.parse()?);
+            Ok(())
+        } else if name == "variant" {
+            input.parse::<Token![=]>()?;
+            self.variant = Some(input.parse()?);
+            Ok(())
+        } else if name == "upgrade" {
+            input.parse::<Token![=]>()?;
+            self.upgrade = Some(input.parse()?);
+            Ok(())
+        } else if name == "deref" {
+            input.parse::<Token![=]>()?;
+            self.deref = Some(input.parse()?);
+            Ok(())
+        } else if name == "deref_mut" {
+            input.parse::<Token![=]>()?;
+            self.deref_mut = Some(input.parse()?);
+            Ok(())
+        } else if name == "into" {
+            input.parse::<Token![=]>()?;
+            self.into = Some(input.parse()?);
+            Ok(())
+        } else if name == "field" {
+            input.parse::<Token![=]>()?;
+            self.field = Some(input.parse()?);
+            Ok(())
+        } else if name == "pin_project" {
+            let content;
+            syn::parenthesized!(content in input);
+            self.pin_project = Some(content.parse()?);
+            Ok(())
+        } else if name == "via" {
+            input.parse::<Token![=]>()?;
+            let path: syn::Path = input.parse()?;
+            if path.is_ident("transparent") {
+                match cfg!(feature = "bytemuck") {
+                    true => self.transparent = Some(path.segments[0].ident.span()),
+                    false => return Err(diagnostic::error_spanned(&path, Code::BytemuckFeatureRequired, "`via = transparent` option requires `bytemuck` feature of auto-trait")),
+                }
+                return Ok(());
+            }
+            //Bare name refers to trait of runtime companion crate
+            self.via = match path.is_ident("Delegate") {
+                true => Some(syn::parse_quote_spanned!(path.segments[0].ident.span()=> ::auto_trait_delegate::Delegate)),
+                false => Some(path),
+            };
+            Ok(())
+        } else if name == "crate_path" {
+            input.parse::<Token![=]>()?;
+            let path: syn::LitStr = input.parse()?;
+            self.crate_path = Some(path.parse().map_err(|_| diagnostic::error_spanned(&path, Code::InvalidCratePath, "`crate_path` must be a path, e.g. \"::core\""))?);
+            Ok(())
+        } else if name == "generics" {
+            input.parse::<Token![=]>()?;
+            let generics: syn::LitStr = input.parse()?;
+            self.generics = Some(generics.parse_with(|input: ParseStream| {
+                let mut generics: syn::Generics = input.parse()?;
+                generics.where_clause = input.parse()?;
+                Ok(generics)
+            }).map_err(|error| diagnostic::error_spanned(&generics, Code::InvalidGenerics, format!("`generics` must be generics with optional where clause, e.g. \"<'a, T: Lolka + 'a>\": {}", error)))?);
+            Ok(())
+        } else if name == "inline" {
+            self.inline = if input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in input);
+                let kind: syn::Ident = content.parse()?;
+                match kind.to_string().as_str() {
+                    "always" => Some(Inline::Always),
+                    "never" => Some(Inline::Never),
+                    _ => return Err(diagnostic::error_spanned(&kind, Code::UnknownInlineHint, format!("Unknown inline hint '{}', expected `always` or `never`", kind))),
+                }
+            } else {
+                Some(Inline::Hint)
+            };
+            Ok(())
+        } else if name == "cfg" {
+            let content;
+            syn::parenthesized!(content in input);
+            if content.is_empty() {
+                return Err(diagnostic::error_spanned(name, Code::CfgPredicateRequired, "`cfg` requires predicate"));
+            }
+            self.cfg.push(content.parse()?);
+            Ok(())
+        } else if name == "bound" {
+            let content;
+            syn::parenthesized!(content in input);
+            let bounds = Punctuated::<syn::WherePredicate, Token![,]>::parse_terminated(&content)?;
+            if bounds.is_empty() {
+                return Err(diagnostic::error_spanned(name, Code::BoundPredicateRequired, "`bound` requires where clause predicate"));
+            }
+            self.bounds.extend(bounds);
+            Ok(())
+        } else if name == "only" || name == "except" {
+            let content;
+            syn::parenthesized!(content in input);
+            let methods = Punctuated::<syn::Ident, Token![,]>::parse_terminated(&content)?;
+            if name == "only" {
+                self.only.get_or_insert_with(Vec::new).extend(methods);
+            } else {
+                self.except.extend(methods);
+            }
+            Ok(())
+        } else if name == "test" {
+            let content;
+            syn::parenthesized!(content in input);
+            self.test = Some(content.parse()?);
+            Ok(())
+        } else if name == "test_target" {
+            input.parse::<Token![=]>()?;
+            self.test_target = Some(input.parse()?);
+            Ok(())
+        } else if name == "trait_path" {
+            input.parse::<Token![=]>()?;
+            self.trait_path = Some(input.parse()?);
+            Ok(())
+        } else if name == "also_ref" {
+            self.also_ref = Some(name.span());
+            Ok(())
+        } else if name == "also_mut_ref" {
+            self.also_mut_ref = Some(name.span());
+            Ok(())
+        } else if name == "inherent" {
+            self.inherent = Some(name.span());
+            Ok(())
+        } else if name == "doc_note" {
+            self.doc_note = true;
+            Ok(())
+        } else if name == "shared" {
+            self.shared = true;
+            Ok(())
+        } else if name == "const_impl" {
+            match cfg!(feature = "const-impl") {
+                true => self.const_impl = Some(name.span()),
+                false => return Err(diagnostic::error_spanned(name, Code::ConstImplFeatureRequired, "`const_impl` option requires `const-impl` feature of auto-trait, which requires nightly compiler")),
+            }
+            Ok(())
+        } else if name == "specialization" {
+            input.parse::<Token![=]>()?;
+            match cfg!(feature = "specialization") {
+                true => self.specialization = Some(input.parse()?),
+                false => return Err(diagnostic::error_spanned(name, Code::SpecializationFeatureRequired, "`specialization` option requires `specialization` feature of auto-trait, which requires nightly compiler")),
+            }
+            Ok(())
+        } else if name == "strict" {
+            self.strict = Some(name.span());
+            Ok(())
+        } else if name == "panic_msg" {
+            input.parse::<Token![=]>()?;
+            self.panic_msg = Some(input.parse()?);
+            Ok(())
+        } else if name == "map_return" {
+            input.parse::<Token![=]>()?;
+            self.map_return = Some(input.parse()?);
+            Ok(())
+        } else if name == "hooks" {
+            input.parse::<Token![=]>()?;
+            self.hooks = Some(input.parse()?);
+            Ok(())
+        } else if name == "instrument" {
+            self.instrument = Some(Instrument::parse(&name, input)?);
+            Ok(())
+        } else if name == "newtype" {
+            input.parse::<Token![=]>()?;
+            self.newtype = Some(input.parse()?);
+            Ok(())
+        } else if name == "cache" {
+            input.parse::<Token![=]>()?;
+            self.cache = Some(input.parse()?);
+            match cfg!(feature = "std") {
+                true => Ok(()),
+                false => Err(diagnostic::error_spanned(name, Code::StdFeatureRequired, "`cache` option requires `std` feature of auto-trait, as results are stored in `std::sync::OnceLock`")),
+            }
+        } else if name == "try_trait" {
+            let content;
+            syn::parenthesized!(content in input);
+            self.try_trait = Some(content.parse()?);
+            Ok(())
+        } else if name == "module" {
+            input.parse::<Token![=]>()?;
+            self.module = Some(input.parse()?);
+            Ok(())
+        } else if name == "stub" {
+            self.stub = if input.parse::<Option<Token![=]>>()?.is_some() {
+                Some(input.parse()?)
+            } else {
+                Some(Stub::Panic)
+            };
+            Ok(())
+        } else if name == "unsafe" {
+            self.unsafe_impl = Some(name.span());
+            Ok(())
+        } else if name == "ref_only" {
+            self.no_mut = true;
+            self.no_owned = true;
+            Ok(())
+        } else if name == "no_mut" {
+            self.no_mut = true;
+            Ok(())
+        } else if name == "no_owned" {
+            self.no_owned = true;
+            Ok(())
+        } else if name == "no_builtin" {
+            self.no_builtin = true;
+            Ok(())
+        } else if name == "export" || name == "registered" {
+            //Registered targets are implemented by expanding exported definition, hence both are the same
+            self.export = true;
+            Ok(())
+        } else if name == "debug" {
+            self.debug = true;
+            Ok(())
+        } else if name == "dry_run" {
+            self.dry_run = true;
+            Ok(())
+        } else if name == "lock_mut" {
+            self.lock_mut = true;
+            Ok(())
+        } else {
+            let hint = match closest_option(&name.to_string()) {
+                Some(option) => format!("did you mean `{}`? Expected", option),
+                None => "expected".to_owned(),
+            };
+            Err(diagnostic::error_spanned(name, Code::UnknownOption, format!("Unknown option '{}', {} one of: {}", name, hint, OPTIONS.join(", "))))
+        }
+    }
+}
+
+///Arguments of `auto_trait` attribute
+#[derive(Default)]
+pub struct Args {
+    ///Types to implement trait for
+    pub targets: Vec<Target>,
+    ///Options, applied to every target
+    ///
+    ///If there are no targets, attribute only specifies `crate_path`, `trait_path`, `module` or `export` for the whole trait.
+    pub options: Options,
+    ///Targets with their own options, written as `<target>; <option>, ...`, which inherit attribute's options
+    ///
+    ///Every target is paired with number of attribute's targets, which are listed before it, so that targets keep their order.
+    pub grouped: Vec<(usize, Args)>,
+}
+
+impl Args {
+    ///Parses arguments of `auto_trait` attribute, reporting forms of attribute without arguments list
+    pub fn from_meta(meta: &syn::Meta) -> syn::Result<Self> {
+        match meta {
+            syn::Meta::List(list) if !list.tokens.is_empty() => list.parse_args(),
+            syn::Meta::List(_) => Err(diagnostic::error_spanned(meta, Code::MissingArguments, format!("Attribute has no arguments, {}", EXPECTED_TARGET))),
+            syn::Meta::NameValue(_) => Err(diagnostic::error_spanned(meta, Code::MissingArguments, format!("Attribute cannot be assigned with `=`, {}", EXPECTED_TARGET))),
+            syn::Meta::Path(_) => Err(diagnostic::error_spanned(meta, Code::MissingArguments, format!("Attribute requires arguments, {}", EXPECTED_TARGET))),
+        }
+    }
+
+    ///Returns options of attribute and of every target with its own options
+    pub fn options_mut(&mut self) -> impl Iterator<Item = &mut Options> {
+        core::iter::once(&mut self.options).chain(self.grouped.iter_mut().map(|(_, group)| &mut group.options))
+    }
+
+    ///Returns targets in order of attribute, along with their own options, if any
+    pub fn listed(&self) -> Vec<(&Target, Option<&Options>)> {
+        let mut listed = self.targets.iter().map(|target| (target, None)).collect::<Vec<_>>();
+        //Every group follows previous ones, hence it is placed after attribute's targets and groups before it
+        for (idx, (position, group)) in self.grouped.iter().enumerate() {
+            for target in group.targets.iter() {
+                listed.insert(position + idx, (target, Some(&group.options)));
+            }
+        }
+        listed
+    }
+
+    ///Splits arguments, so that every target with its own options is separate, keeping order of targets
+    pub fn flatten(mut self) -> impl Iterator<Item = Args> {
+        let grouped = core::mem::take(&mut self.grouped);
+        let mut targets = core::mem::take(&mut self.targets).into_iter();
+        let mut result = Vec::new();
+        let mut listed = 0;
+        for (position, group) in grouped {
+            if position > listed {
+                result.push(Args {
+                    targets: targets.by_ref().take(position - listed).collect(),
+                    options: self.options.clone(),
+                    grouped: Vec::new(),
+                });
+                listed = position;
+            }
+            result.push(group);
+        }
+        let targets = targets.collect::<Vec<_>>();
+        if !targets.is_empty() || result.is_empty() {
+            result.push(Args {
+                targets,
+                options: self.options,
+                grouped: Vec::new(),
+            });
+        }
+        result.into_iter()
+    }
+
+    ///Parses options of target, following `;`, until next target
+    fn parse_group(input: ParseStream) -> syn::Result<Options> {
+        let mut options = Options::default();
+        loop {
+            options.parse_option(input)?;
+
+            let fork = input.fork();
+            if fork.parse::<Token![,]>().is_ok() && Options::peek(&fork) {
+                input.parse::<Token![,]>()?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut targets = Vec::new();
+        let mut options = Options::default();
+        let mut grouped = Vec::new();
+        let mut trait_options_only = true;
+
+        while !input.is_empty() {
+            if Options::peek(input) {
+                let name = options.parse_option(input)?;
+                trait_options_only &= TRAIT_OPTIONS.iter().any(|option| name == option);
+            } else {
+                let parsed = Target::parse_targets(input).map_err(|error| match diagnostic::has_code(&error) {
+                    true => error,
+                    false => diagnostic::error(error.span(), Code::InvalidTarget, format!("Argument must be a type, {}", EXPECTED_TARGET)),
+                })?;
+                if input.parse::<Option<Token![;]>>()?.is_some() {
+                    let options = Self::parse_group(input)?;
+                    grouped.extend(parsed.into_iter().map(|target| (targets.len(), Args {
+                        targets: vec![target],
+                        options: options.clone(),
+                        grouped: Vec::new(),
+                    })));
+                } else {
+                    targets.extend(parsed);
+                }
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        //Attribute with only trait options applies to the whole trait, while newtype, cache and blanket implementation are target itself
+        if targets.is_empty() && grouped.is_empty() && options.newtype.is_none() && options.cache.is_none() && options.specialization.is_none() && !(trait_options_only && (options.crate_path.is_some() || options.trait_path.is_some() || options.export || options.module.is_some())) {
+            return Err(diagnostic::error(input.span(), Code::MissingArguments, format!("Argument is required, {}", EXPECTED_TARGET)));
+        }
+
+        for (_, group) in grouped.iter_mut() {
+            group.options.inherit(&options);
+        }
+
+        Ok(Self {
+            targets,
+            options,
+            grouped,
+        })
+    }
+}
+
+///Returns name of option, which is the closest to misspelled `name`, if it is close enough
+fn closest_option(name: &str) -> Option<&'static str> {
+    OPTIONS.iter().map(|option| (edit_distance(name, option), *option)).filter(|(distance, option)| *distance <= core::cmp::max(1, option.len() / 3)).min_by_key(|(distance, _)| *distance).map(|(_, option)| option)
+}
+
+///Returns number of single character edits, required to turn one string into another
+pub fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<_>>();
+    let mut row = (0..=right.len()).collect::<Vec<_>>();
+    for (idx, left) in left.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = idx + 1;
+        for (jdx, right) in right.iter().enumerate() {
+            let substitution = diagonal + (left != *right) as usize;
+            diagonal = row[jdx + 1];
+            row[jdx + 1] = core::cmp::min(substitution, core::cmp::min(row[jdx], row[jdx + 1]) + 1);
+        }
+    }
+
+    row[right.len()]
+}
+
+///Returns whether path names attribute of this crate, either by its name or qualified, e.g. `auto_trait::auto_trait`
+pub fn is_attribute(path: &syn::Path, name: &str) -> bool {
+    path.segments.last().map(|segment| segment.ident == name).unwrap_or(false)
+}
+
+///Options of `auto_trait_default` attribute, inherited by every `auto_trait` attribute of the trait
+impl Parse for Options {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut options = Options::default();
+
+        while !input.is_empty() {
+            if !Options::peek(input) {
+                return Err(diagnostic::error(input.span(), Code::DefaultTarget, "Expected option, `auto_trait_default` cannot specify targets"));
+            }
+            options.parse_option(input)?;
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(options)
+    }
+}
+
+///Content of `cfg_attr` attribute
+pub struct CfgAttr {
+    ///Configuration predicate
+    pub predicate: syn::Meta,
+    ///Attributes, applied when predicate holds
+    pub attrs: Punctuated<syn::Meta, Token![,]>,
+}
+
+impl CfgAttr {
+    ///Parses `cfg_attr` attribute, returning `None` for any other attribute.
+    ///
+    ///Malformed `cfg_attr` is left for compiler to report.
+    pub fn parse(attr: &syn::Attribute) -> Option<Self> {
+        if !attr.path().is_ident("cfg_attr") {
+            return None;
+        }
+
+        attr.parse_args_with(|input: ParseStream| {
+            let predicate = input.parse()?;
+            input.parse::<Token![,]>()?;
+            Ok(Self {
+                predicate,
+                attrs: Punctuated::parse_terminated(input)?,
+            })
+        }).ok()
+    }
+
+    ///Returns whether any of attributes is `auto_trait`
+    pub fn contains_auto_trait(&self) -> bool {
+        self.attrs.iter().any(|attr| is_attribute(attr.path(), "auto_trait"))
+    }
+
+    ///Extracts `auto_trait` attributes, gating their implementations by predicate.
+    ///
+    ///Returns `cfg_attr` with remaining attributes, if any.
+    pub fn extract(self, args: &mut Vec<Args>) -> syn::Result<Option<syn::Attribute>> {
+        let mut remaining = Punctuated::<syn::Meta, Token![,]>::new();
+        let mut errors: Option<syn::Error> = None;
+
+        for attr in self.attrs {
+            if !is_attribute(attr.path(), "auto_trait") {
+                remaining.push(attr);
+                continue;
+            }
+
+            //Every attribute is reported with its own span, rather than stopping at the first one
+            let mut arg = match Args::from_meta(&attr) {
+                Ok(arg) => arg,
+                Err(error) => {
+                    match errors {
+                        Some(ref mut errors) => errors.combine(error),
+                        None => errors = Some(error),
+                    }
+                    continue;
+                },
+            };
+            let predicate = &self.predicate;
+            for options in arg.options_mut() {
+                options.cfg.push(quote::quote! {
+                    #predicate
+                });
+            }
+            args.push(arg);
+        }
+
+        if let Some(errors) = errors {
+            Err(errors)
+        } else if remaining.is_empty() {
+            Ok(None)
+        } else {
+            let predicate = self.predicate;
+            Ok(Some(syn::parse_quote!(#[cfg_attr(#predicate, #remaining)])))
+        }
+    }
+}
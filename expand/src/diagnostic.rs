@@ -0,0 +1,532 @@
+//!Errors and compiler warnings
+//!
+//!Every error is prefixed with identifier (e.g. `[auto_trait::unsupported_item]`), which stays the same, when wording of message changes,
+//!so that tests of dependent crates can match on it.
+//!Warnings are emitted only with `nightly-diagnostics` feature, as stable compiler provides no way to emit them.
+
+use core::fmt;
+
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+
+///Identifier of error
+///
+///Name of identifier is never reused for error with different meaning, hence removed identifiers are not to be taken by new errors.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Code {
+    //Attribute arguments
+    ///Attribute has no arguments to parse targets from
+    MissingArguments,
+    ///Argument is neither option, nor type
+    InvalidTarget,
+    ///Option is not known
+    UnknownOption,
+    ///`upgrade` option has unknown policy
+    UnknownUpgradePolicy,
+    ///`stub` option has unknown kind
+    UnknownStubKind,
+    ///`inline` option has unknown hint
+    UnknownInlineHint,
+    ///`test` option has unknown nested option
+    UnknownTestOption,
+    ///`test` option has no function to create target
+    TestFunctionRequired,
+    ///`instrument` option has unknown nested option
+    UnknownInstrumentOption,
+    ///`try_trait` option has unknown nested option
+    UnknownTryTraitOption,
+    ///`instrument` option requires `tracing` feature
+    TracingFeatureRequired,
+    ///`const_impl` option requires `const-impl` feature
+    ConstImplFeatureRequired,
+    ///`via = transparent` option requires `bytemuck` feature
+    BytemuckFeatureRequired,
+    ///`specialization` option requires `specialization` feature
+    SpecializationFeatureRequired,
+    ///Target, option or trait refers to `std`, which requires `std` feature
+    StdFeatureRequired,
+    ///`crate_path` is not a path
+    InvalidCratePath,
+    ///`generics` cannot be parsed
+    InvalidGenerics,
+    ///`cfg` option has no predicate
+    CfgPredicateRequired,
+    ///`bound` option has no predicate
+    BoundPredicateRequired,
+    ///`auto_trait_default` specifies target
+    DefaultTarget,
+    ///Option, applied to the whole trait, is specified by several attributes
+    DuplicateTraitOption,
+    ///`complete` attribute specifies target
+    CompleteTarget,
+
+    //Annotated items
+    ///Attribute is applied to item, which is not trait
+    NotTrait,
+    ///`auto_trait_default` is applied to trait without `auto_trait` attribute
+    DefaultWithoutAttribute,
+    ///`auto_trait_all` is applied to module without content
+    ModuleWithoutContent,
+    ///`auto_trait_all` attribute of trait is not `skip`
+    InvalidAutoTraitAll,
+    ///`complete` is applied to item, which is not trait implementation
+    CompleteNotTraitImpl,
+    ///Derive doesn't list traits to implement
+    DeriveTraitsRequired,
+    ///`AutoWrapper` is derived for item other than struct
+    WrapperNotStruct,
+    ///Wrapper has several fields, none of which is selected
+    WrapperFieldRequired,
+    ///Wrapper has no selected field
+    WrapperFieldNotFound,
+    ///Wrapper's field is selected several times
+    DuplicateWrapperField,
+    ///Option of `auto_wrapper` attribute is not known
+    UnknownWrapperOption,
+    ///Option of `auto_wrapper` attribute is specified twice
+    DuplicateWrapperOption,
+    ///`path` option of wrapper doesn't start with `crate` or is used without forwarder
+    InvalidWrapperPath,
+    ///Trait's path cannot be used to refer to exported trait
+    InvalidTraitPath,
+    ///Exported or foreign trait is generic
+    GenericTrait,
+    ///Trait has no bundled signatures for `auto_trait_std`
+    UnknownStdTrait,
+
+    //Dispatch enum
+    ///Target of `enum` option cannot be held by variant
+    EnumTargetUnsupported,
+    ///Variant of target cannot be named after its type
+    EnumVariantRequired,
+    ///Variant name is taken by several targets
+    DuplicateEnumVariant,
+    ///`variant` option is specified without `enum` option
+    VariantWithoutEnum,
+    ///`enum` option is specified for single target
+    EnumTargetOption,
+
+    //Targets
+    ///Target is listed by several attributes
+    DuplicateTarget,
+    ///Implementation for generic target covers another target
+    OverlappingTargets,
+    ///Kind of type is not supported as target
+    UnsupportedTarget,
+    ///Target is qualified path
+    QualifiedTarget,
+    ///Target has parenthesized arguments of `Fn` traits
+    ParenthesizedArguments,
+    ///Target specifies arguments for another trait
+    PinnedTraitMismatch,
+    ///Target of generic trait doesn't specify its arguments
+    PinnedTraitArgumentsRequired,
+    ///Target specifies wrong number of trait's arguments
+    TraitArgumentsCount,
+    ///Target's argument doesn't match kind of trait's parameter
+    TraitArgumentMismatch,
+    ///None of target's parameters is bound by the trait
+    UnboundTarget,
+    ///Several target's parameters are bound by the trait
+    MultipleBounds,
+    ///Target's bound looks like misspelled trait's name
+    MisspelledBound,
+    ///Target's bound is qualified path, which doesn't match trait's name
+    MismatchedBound,
+    ///Target contains trait object written without `dyn`
+    BareTraitObject,
+    ///`forward` option names unknown parameter
+    ForwardNotParameter,
+    ///`forward` option is used along with type to forward to
+    ForwardConflict,
+    ///`via = transparent` option is used without type to forward to
+    TransparentWithoutTarget,
+    ///`dispatch = match(...)` lists no variants
+    DispatchVariantsRequired,
+    ///Variant of derived enum doesn't hold single value to dispatch to
+    EnumVariantFields,
+    ///Derived enum has no variants to dispatch to
+    EnumWithoutVariants,
+    ///`stub` option is used along with incompatible options
+    StubConflict,
+    ///`inherent` option is used along with incompatible options
+    InherentConflict,
+    ///`test_target` option is used along with incompatible options
+    TestTargetConflict,
+    ///`newtype` option is used along with targets
+    NewtypeConflict,
+    ///`cache` option is used along with targets
+    CacheConflict,
+    ///`specialization` option is used along with targets
+    SpecializationConflict,
+    ///`cache` option is used for trait without methods to cache
+    CacheWithoutMethods,
+    ///`try_trait` option is used for generic trait
+    TryTraitConflict,
+    ///`shared` option is used for generic trait or along with `const_impl`
+    SharedConflict,
+    ///`shared` option is used for target, which cannot be converted into value to forward to
+    SharedIncompatible,
+    ///Unsafe trait is implemented without `unsafe` option
+    UnsafeRequired,
+    ///Safe trait is implemented with `unsafe` option
+    UnsafeUnneeded,
+    ///Trait cannot be made into trait object, required by target
+    DynIncompatibleTrait,
+    ///Trait cannot be implemented by function, required by `fn` target
+    FnIncompatibleTrait,
+    ///Items are left with default implementation, while `strict` option is specified
+    Strict,
+
+    //Items of trait
+    ///Item of trait is not supported
+    UnsupportedItem,
+    ///Item is defined by macro invocation
+    MacroItem,
+    ///Option names method, which trait doesn't have
+    UnknownMethod,
+    ///Implementation, completed by `complete`, has item, which trait doesn't have
+    CompleteUnknownItem,
+    ///Method is excluded by receiver filter, but has no default implementation
+    FilteredWithoutDefault,
+    ///Method without receiver would call itself
+    RecursiveFunction,
+    ///Associated const has no type to forward to
+    ConstWithoutTarget,
+    ///Associated type has no type to forward to
+    TypeWithoutTarget,
+    ///Method's receiver cannot be forwarded through strategy
+    UnsupportedReceiver,
+    ///Method cannot be forwarded for keyword target
+    KeywordIncompatible,
+    ///Method cannot be forwarded through trait object
+    DynIncompatibleMethod,
+    ///Method cannot be called on every element of tuple target
+    FanOutIncompatible,
+    ///Method cannot be forwarded by `const_impl` through strategy, which is not const-callable
+    ConstIncompatible,
+    ///Method taking pinned `self` is forwarded without `pin_project` option
+    PinWithoutProjection,
+    ///Method cannot be forwarded to unsized type
+    UnsizedIncompatible,
+    ///Method cannot be forwarded through guard
+    GuardIncompatible,
+    ///Method's return value borrows receiver, which is reached through temporary
+    TemporaryBorrow,
+    ///Method cannot be forwarded by fallible counterpart of the trait
+    TryIncompatible,
+    ///Method cannot be forwarded for reference, required by `also_ref` or `also_mut_ref`
+    ReferenceIncompatible,
+    ///`&mut self` method is forwarded through `Mutex` without `lock_mut`
+    MutexLockRequired,
+    ///Method taking `self` by value has no type to convert into
+    OwnedWithoutTarget,
+    ///Method taking `self` by value cannot obtain owned value
+    OwnedUnavailable,
+    ///Function without receiver returning `Self` has no type to construct from
+    ConstructorWithoutTarget,
+
+    //Options of items
+    ///Option of item is not known
+    UnknownMethodOption,
+    ///Item's options are wrapped into `cfg_attr`
+    CfgAttrMethodOptions,
+    ///Item's option is specified twice
+    DuplicateMethodOption,
+    ///Body override is specified twice
+    DuplicateBody,
+    ///Body override names type, which is not target
+    BodyUnknownTarget,
+    ///`value` option is used on item other than associated const
+    ValueNotConst,
+    ///`body` option is used on item other than method
+    BodyNotMethod,
+    ///`call` option is used on item other than method
+    CallNotMethod,
+    ///`map_return` option is used on item other than method
+    MapReturnNotMethod,
+    ///`call` option is not a path
+    CallNotPath,
+    ///`map_return` option is not a path
+    MapReturnNotPath,
+    ///`call` option is used along with body override for every target
+    CallWithBody,
+    ///Item without default is skipped
+    SkipWithoutDefault,
+    ///Skipped item has options, which override it
+    SkipConflict,
+    ///`cache` option is used on item other than method
+    CacheNotMethod,
+    ///Method cannot be cached
+    CacheIncompatible,
+}
+
+impl Code {
+    ///Returns name of identifier
+    pub fn name(self) -> &'static str {
+        match self {
+            Code::MissingArguments => "missing_arguments",
+            Code::InvalidTarget => "invalid_target",
+            Code::UnknownOption => "unknown_option",
+            Code::UnknownUpgradePolicy => "unknown_upgrade_policy",
+            Code::UnknownStubKind => "unknown_stub_kind",
+            Code::UnknownInlineHint => "unknown_inline_hint",
+            Code::UnknownTestOption => "unknown_test_option",
+            Code::TestFunctionRequired => "test_function_required",
+            Code::UnknownInstrumentOption => "unknown_instrument_option",
+            Code::UnknownTryTraitOption => "unknown_try_trait_option",
+            Code::TracingFeatureRequired => "tracing_feature_required",
+            Code::ConstImplFeatureRequired => "const_impl_feature_required",
+            Code::BytemuckFeatureRequired => "bytemuck_feature_required",
+            Code::SpecializationFeatureRequired => "specialization_feature_required",
+            Code::StdFeatureRequired => "std_feature_required",
+            Code::InvalidCratePath => "invalid_crate_path",
+            Code::InvalidGenerics => "invalid_generics",
+            Code::CfgPredicateRequired => "cfg_predicate_required",
+            Code::BoundPredicateRequired => "bound_predicate_required",
+            Code::DefaultTarget => "default_target",
+            Code::DuplicateTraitOption => "duplicate_trait_option",
+            Code::CompleteTarget => "complete_target",
+            Code::NotTrait => "not_trait",
+            Code::DefaultWithoutAttribute => "default_without_attribute",
+            Code::ModuleWithoutContent => "module_without_content",
+            Code::InvalidAutoTraitAll => "invalid_auto_trait_all",
+            Code::CompleteNotTraitImpl => "complete_not_trait_impl",
+            Code::DeriveTraitsRequired => "derive_traits_required",
+            Code::WrapperNotStruct => "wrapper_not_struct",
+            Code::WrapperFieldRequired => "wrapper_field_required",
+            Code::WrapperFieldNotFound => "wrapper_field_not_found",
+            Code::DuplicateWrapperField => "duplicate_wrapper_field",
+            Code::UnknownWrapperOption => "unknown_wrapper_option",
+            Code::DuplicateWrapperOption => "duplicate_wrapper_option",
+            Code::InvalidWrapperPath => "invalid_wrapper_path",
+            Code::InvalidTraitPath => "invalid_trait_path",
+            Code::GenericTrait => "generic_trait",
+            Code::UnknownStdTrait => "unknown_std_trait",
+            Code::EnumTargetUnsupported => "enum_target_unsupported",
+            Code::EnumVariantRequired => "enum_variant_required",
+            Code::DuplicateEnumVariant => "duplicate_enum_variant",
+            Code::VariantWithoutEnum => "variant_without_enum",
+            Code::EnumTargetOption => "enum_target_option",
+            Code::DuplicateTarget => "duplicate_target",
+            Code::OverlappingTargets => "overlapping_targets",
+            Code::UnsupportedTarget => "unsupported_target",
+            Code::QualifiedTarget => "qualified_target",
+            Code::ParenthesizedArguments => "parenthesized_arguments",
+            Code::PinnedTraitMismatch => "pinned_trait_mismatch",
+            Code::PinnedTraitArgumentsRequired => "pinned_trait_arguments_required",
+            Code::TraitArgumentsCount => "trait_arguments_count",
+            Code::TraitArgumentMismatch => "trait_argument_mismatch",
+            Code::UnboundTarget => "unbound_target",
+            Code::MultipleBounds => "multiple_bounds",
+            Code::MisspelledBound => "misspelled_bound",
+            Code::BareTraitObject => "bare_trait_object",
+            Code::MismatchedBound => "mismatched_bound",
+            Code::ForwardNotParameter => "forward_not_parameter",
+            Code::ForwardConflict => "forward_conflict",
+            Code::TransparentWithoutTarget => "transparent_without_target",
+            Code::DispatchVariantsRequired => "dispatch_variants_required",
+            Code::EnumVariantFields => "enum_variant_fields",
+            Code::EnumWithoutVariants => "enum_without_variants",
+            Code::StubConflict => "stub_conflict",
+            Code::InherentConflict => "inherent_conflict",
+            Code::TestTargetConflict => "test_target_conflict",
+            Code::NewtypeConflict => "newtype_conflict",
+            Code::CacheConflict => "cache_conflict",
+            Code::SpecializationConflict => "specialization_conflict",
+            Code::CacheWithoutMethods => "cache_without_methods",
+            Code::TryTraitConflict => "try_trait_conflict",
+            Code::SharedConflict => "shared_conflict",
+            Code::SharedIncompatible => "shared_incompatible",
+            Code::UnsafeRequired => "unsafe_required",
+            Code::UnsafeUnneeded => "unsafe_unneeded",
+            Code::DynIncompatibleTrait => "dyn_incompatible_trait",
+            Code::FnIncompatibleTrait => "fn_incompatible_trait",
+            Code::Strict => "strict",
+            Code::UnsupportedItem => "unsupported_item",
+            Code::MacroItem => "macro_item",
+            Code::UnknownMethod => "unknown_method",
+            Code::CompleteUnknownItem => "complete_unknown_item",
+            Code::FilteredWithoutDefault => "filtered_without_default",
+            Code::RecursiveFunction => "recursive_function",
+            Code::ConstWithoutTarget => "const_without_target",
+            Code::TypeWithoutTarget => "type_without_target",
+            Code::UnsupportedReceiver => "unsupported_receiver",
+            Code::KeywordIncompatible => "keyword_incompatible",
+            Code::DynIncompatibleMethod => "dyn_incompatible_method",
+            Code::FanOutIncompatible => "fan_out_incompatible",
+            Code::ConstIncompatible => "const_incompatible",
+            Code::PinWithoutProjection => "pin_without_projection",
+            Code::UnsizedIncompatible => "unsized_incompatible",
+            Code::GuardIncompatible => "guard_incompatible",
+            Code::TemporaryBorrow => "temporary_borrow",
+            Code::TryIncompatible => "try_incompatible",
+            Code::ReferenceIncompatible => "reference_incompatible",
+            Code::MutexLockRequired => "mutex_lock_required",
+            Code::OwnedWithoutTarget => "owned_without_target",
+            Code::OwnedUnavailable => "owned_unavailable",
+            Code::ConstructorWithoutTarget => "constructor_without_target",
+            Code::UnknownMethodOption => "unknown_method_option",
+            Code::CfgAttrMethodOptions => "cfg_attr_method_options",
+            Code::DuplicateMethodOption => "duplicate_method_option",
+            Code::DuplicateBody => "duplicate_body",
+            Code::BodyUnknownTarget => "body_unknown_target",
+            Code::ValueNotConst => "value_not_const",
+            Code::BodyNotMethod => "body_not_method",
+            Code::CallNotMethod => "call_not_method",
+            Code::MapReturnNotMethod => "map_return_not_method",
+            Code::CallNotPath => "call_not_path",
+            Code::MapReturnNotPath => "map_return_not_path",
+            Code::CallWithBody => "call_with_body",
+            Code::SkipWithoutDefault => "skip_without_default",
+            Code::SkipConflict => "skip_conflict",
+            Code::CacheNotMethod => "cache_not_method",
+            Code::CacheIncompatible => "cache_incompatible",
+        }
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "[auto_trait::{}]", self.name())
+    }
+}
+
+///Creates error pointing at `span`
+pub fn error(span: Span, code: Code, message: impl fmt::Display) -> syn::Error {
+    syn::Error::new(span, format!("{} {}", code, message))
+}
+
+///Creates error pointing at `tokens`
+pub fn error_spanned(tokens: impl ToTokens, code: Code, message: impl fmt::Display) -> syn::Error {
+    syn::Error::new_spanned(tokens, format!("{} {}", code, message))
+}
+
+///Converts error into `compile_error!` invocations, which are reported within crate of any edition
+///
+///`syn` invokes `::core::compile_error`, which leading `::` refers to crate root within crate of edition 2015,
+///hence invocation is spanned by the macro, which is of later edition, while located at the same tokens, so that error is reported instead of missing `core`.
+pub fn to_compile_error(error: &syn::Error) -> TokenStream {
+    error.to_compile_error().into_iter().map(|mut token| {
+        token.set_span(Span::mixed_site().located_at(token.span()));
+        token
+    }).collect()
+}
+
+///Returns whether error is already prefixed with identifier, hence it is more specific than error of enclosing syntax
+pub fn has_code(error: &syn::Error) -> bool {
+    error.to_string().starts_with("[auto_trait::")
+}
+
+///Returns tokens, e.g. type or path, as they are written within message, `Box<T>` rather than `Box < T >`
+///
+///Space separates words and follows `,`, `;` and `:`, while binary operators, e.g. `+` and `->`, are surrounded by spaces.
+pub fn display(tokens: impl ToTokens) -> String {
+    ///Previously written token, which decides whether space precedes the next one
+    #[derive(Clone, Copy, PartialEq)]
+    enum Last {
+        Start,
+        ///Word, e.g. identifier or closing `>`, which is followed by its arguments without space, unless it is keyword or lifetime
+        Word { arguments: bool },
+        ///Punctuation, which is followed by space
+        Spaced,
+        ///Punctuation, which is followed by the next token without space
+        Joined,
+    }
+
+    fn write(tokens: TokenStream, result: &mut String) {
+        let mut last = Last::Start;
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            let word = matches!(last, Last::Word { .. } | Last::Spaced);
+            last = match token {
+                TokenTree::Ident(ident) => {
+                    let ident = ident.to_string();
+                    let lifetime = result.ends_with('\'');
+                    if word {
+                        result.push(' ');
+                    }
+                    result.push_str(&ident);
+                    Last::Word { arguments: !lifetime && !matches!(ident.as_str(), "as" | "const" | "dyn" | "for" | "impl" | "mut" | "where") }
+                },
+                TokenTree::Literal(literal) => {
+                    if word {
+                        result.push(' ');
+                    }
+                    result.push_str(&literal.to_string());
+                    Last::Word { arguments: false }
+                },
+                TokenTree::Punct(punct) => {
+                    let ch = punct.as_char();
+                    let next = match tokens.peek() {
+                        Some(TokenTree::Punct(next)) if punct.spacing() == Spacing::Joint => Some(next.as_char()),
+                        _ => None,
+                    };
+                    match (ch, next) {
+                        ('-', Some('>')) | ('=', Some('>')) => {
+                            tokens.next();
+                            result.push_str(&format!(" {}> ", ch));
+                            Last::Joined
+                        },
+                        ('+', _) | ('=', _) => {
+                            result.push_str(&format!(" {} ", ch));
+                            Last::Joined
+                        },
+                        _ => {
+                            if last == Last::Spaced || (word && matches!(ch, '&' | '*' | '\'')) {
+                                result.push(' ');
+                            }
+                            result.push(ch);
+                            match ch {
+                                ',' | ';' => Last::Spaced,
+                                ':' if next.is_none() && !result.ends_with("::") => Last::Spaced,
+                                '>' => Last::Word { arguments: true },
+                                _ => Last::Joined,
+                            }
+                        },
+                    }
+                },
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::Brace => ("{ ", " }"),
+                        Delimiter::None => ("", ""),
+                    };
+                    if matches!(last, Last::Spaced | Last::Word { arguments: false }) {
+                        result.push(' ');
+                    }
+                    result.push_str(open);
+                    write(group.stream(), result);
+                    result.push_str(close);
+                    Last::Word { arguments: false }
+                },
+            };
+        }
+    }
+
+    let mut result = String::new();
+    write(tokens.into_token_stream(), &mut result);
+    result
+}
+
+///Creates note pointing at `tokens`, which is combined with error to point at related location
+pub fn note_spanned(tokens: impl ToTokens, message: impl fmt::Display) -> syn::Error {
+    syn::Error::new_spanned(tokens, message)
+}
+
+///Emits warning pointing at `span`
+///
+///Message is formatted only when warning is emitted, as it is requested for every method of every target.
+#[cfg(feature = "nightly-diagnostics")]
+pub fn warning(span: Span, message: impl FnOnce() -> String) {
+    //Outside of macro's expansion, e.g. in unit tests, there is no compiler to report to
+    if proc_macro::is_available() {
+        proc_macro::Diagnostic::spanned(span.unwrap(), proc_macro::Level::Warning, message()).emit();
+    }
+}
+
+///Emits warning pointing at `span`
+#[cfg(not(feature = "nightly-diagnostics"))]
+pub fn warning(_span: Span, _message: impl FnOnce() -> String) {
+}
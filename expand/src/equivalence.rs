@@ -0,0 +1,45 @@
+//!Generation of forwarding equivalence tests
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::args::TestOptions;
+use crate::strategy::Receiver;
+
+///Returns whether method can be called without arguments, other than `&self`
+fn is_testable(method: &syn::TraitItemFn) -> bool {
+    Receiver::new(&method.sig) == Receiver::Ref && method.sig.inputs.len() == 1 && method.sig.generics.params.is_empty()
+}
+
+///Generates test module, comparing results of forwarded methods called on target and its inner value
+pub fn tests(test: &TestOptions, trait_path: &syn::Path, trait_name: &syn::Ident, index: usize, methods: &[&syn::TraitItemFn], cfg: Option<&syn::Attribute>, core: &TokenStream) -> TokenStream {
+    let name = format_ident!("__auto_trait_test_{}_{}", trait_name, index);
+    let with = &test.with;
+
+    let tests = methods.iter().filter(|method| is_testable(method) && !test.skip.contains(&method.sig.ident)).map(|method| {
+        let method_name = &method.sig.ident;
+        let message = format!("{}::{} result differs between target and its inner value", trait_name, method_name);
+        let target = crate::hygienic("target", proc_macro2::Span::call_site());
+        let inner = crate::hygienic("inner", proc_macro2::Span::call_site());
+        quote! {
+            #[test]
+            fn #method_name() {
+                let (#target, #inner) = #with();
+                #core::assert!(#trait_path::#method_name(&#target) == #trait_path::#method_name(&#inner), #message);
+            }
+        }
+    });
+
+    quote! {
+        #cfg
+        #[cfg(test)]
+        #[allow(non_snake_case)]
+        mod #name {
+            use super::*;
+
+            #(
+                #tests
+            )*
+        }
+    }
+}
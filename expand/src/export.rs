@@ -0,0 +1,414 @@
+//!Exporting trait definition for `AutoTrait` derive
+//!
+//!Trait annotated with `#[auto_trait(export)]` is accompanied by hidden `macro_rules` macro `__auto_trait_<Trait>`,
+//!which keeps trait's definition and passes it to `__forward` together with target supplied by derive,
+//!or to `__complete` together with implementation annotated by `complete`.
+//!Type, annotated by `auto_trait_register`, supplies target the same way as derive, so that trait with `registered` option needs no list of targets.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::Token;
+
+use crate::args::{is_attribute, Args, Options};
+use crate::diagnostic::{self, Code};
+use crate::target::Target;
+
+fn macro_name(trait_name: &syn::Ident) -> syn::Ident {
+    format_ident!("__auto_trait_{}", trait_name)
+}
+
+///Generates macro exporting definition of the trait
+pub fn export(input: &syn::ItemTrait) -> TokenStream {
+    let name = macro_name(&input.ident);
+    let auto_trait = crate::extern_crate("auto_trait", proc_macro2::Span::call_site());
+    //Macro is named by path, which edition 2015 resolves relative to crate root, rather than to macros in scope
+    let path = syn::Ident::new(&name.to_string(), proc_macro2::Span::mixed_site());
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        macro_rules! #name {
+            (@complete $($input:tt)*) => {
+                #auto_trait::__complete! {
+                    $($input)*
+                    #input
+                }
+            };
+            ($($target:tt)*) => {
+                #auto_trait::__forward! {
+                    [$($target)*]
+                    #input
+                }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #path;
+    }
+}
+
+///Input of `__forward` macro: bracketed arguments followed by trait's definition
+pub struct Forward {
+    ///Arguments supplied by derive
+    pub args: Args,
+    ///Exported trait
+    pub input: syn::ItemTrait,
+}
+
+impl Parse for Forward {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let args;
+        syn::bracketed!(args in input);
+
+        Ok(Self {
+            args: args.parse()?,
+            input: input.parse()?,
+        })
+    }
+}
+
+///Trait listed in derive's attribute, which is prefixed with `unsafe` to acknowledge contract of unsafe trait
+pub struct DeriveTrait {
+    unsafety: Option<TokenStream>,
+    path: syn::Path,
+}
+
+impl Parse for DeriveTrait {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let unsafety = match input.parse::<Option<Token![unsafe]>>()? {
+            Some(unsafety) => Some(quote! {
+                , #unsafety
+            }),
+            None => None,
+        };
+
+        Ok(Self {
+            unsafety,
+            path: input.parse()?,
+        })
+    }
+}
+
+///Writes type's generics as target declarations, e.g. `Wrapper<'a, T: Bound, const N: usize>`
+fn target(input: &syn::DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    if input.generics.params.is_empty() {
+        return quote! {
+            #name
+        };
+    }
+
+    let params = input.generics.params.iter().map(|param| match param {
+        syn::GenericParam::Lifetime(param) => {
+            let lifetime = &param.lifetime;
+            quote! {
+                #lifetime
+            }
+        },
+        syn::GenericParam::Type(param) => {
+            let ident = &param.ident;
+            let bounds = &param.bounds;
+            if bounds.is_empty() {
+                quote! {
+                    #ident
+                }
+            } else {
+                quote! {
+                    #ident: #bounds
+                }
+            }
+        },
+        syn::GenericParam::Const(param) => {
+            let ident = &param.ident;
+            let ty = &param.ty;
+            quote! {
+                const #ident: #ty
+            }
+        },
+    });
+
+    //Unbounded parameters are declared explicitly, as plain identifier within target names type
+    let free = input.generics.type_params().filter(|param| param.bounds.is_empty()).map(|param| &param.ident).collect::<Vec<_>>();
+    match free.is_empty() {
+        true => quote! {
+            #name<#(#params),*>
+        },
+        false => quote! {
+            for<#(#free),*> #name<#(#params),*>
+        },
+    }
+}
+
+///Generates implementations of traits, listed in `auto_trait` attributes of derive input
+pub fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let mut traits = Vec::new();
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("auto_trait")) {
+        traits.extend(attr.parse_args_with(Punctuated::<DeriveTrait, Token![,]>::parse_terminated)?);
+    }
+
+    if traits.is_empty() {
+        return Err(diagnostic::error_spanned(&input.ident, Code::DeriveTraitsRequired, "Specify traits to implement with `#[auto_trait(<trait>, ...)]` attribute"));
+    }
+
+    implement(input, traits, None)
+}
+
+///Generates implementations of traits, which type registers itself for by `auto_trait_register` attribute
+pub fn register(args: TokenStream, input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let traits = syn::parse::Parser::parse2(Punctuated::<DeriveTrait, Token![,]>::parse_terminated, args)?;
+    if traits.is_empty() {
+        return Err(diagnostic::error_spanned(&input.ident, Code::DeriveTraitsRequired, "Specify traits to register type for with `#[auto_trait_register(<trait>, ...)]` attribute"));
+    }
+
+    implement(input, traits.into_iter().collect(), None)
+}
+
+///Generates implementations of exported traits for derive input, forwarding to `inner` type, if specified
+pub fn implement(input: &syn::DeriveInput, traits: Vec<DeriveTrait>, inner: Option<&syn::Type>) -> syn::Result<TokenStream> {
+    let target = target(input);
+    let inner = inner.map(|inner| quote! {
+        => #inner
+    });
+    let bounds = match input.generics.where_clause {
+        Some(ref where_clause) => where_clause.predicates.iter().map(|predicate| quote! {
+            , bound(#predicate)
+        }).collect(),
+        None => TokenStream::new(),
+    };
+
+    //Enum is dispatched to value of its variants
+    let dispatch = match input.data {
+        syn::Data::Enum(ref data) => {
+            if data.variants.is_empty() {
+                return Err(diagnostic::error_spanned(&input.ident, Code::EnumWithoutVariants, format!("Enum '{}' has no variants to dispatch to", input.ident)));
+            }
+            for variant in data.variants.iter() {
+                let count = match variant.fields {
+                    syn::Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => continue,
+                    syn::Fields::Named(_) => return Err(diagnostic::error_spanned(&variant.fields, Code::EnumVariantFields, format!("Variant '{}' cannot be dispatched to, as it has named fields, hold value implementing trait as its only unnamed field, e.g. `{}(Inner)`", variant.ident, variant.ident))),
+                    ref fields => fields.len(),
+                };
+                return Err(diagnostic::error_spanned(variant, Code::EnumVariantFields, format!("Variant '{}' cannot be dispatched to, as it has {} fields, hold value implementing trait as its only field, e.g. `{}(Inner)`", variant.ident, count, variant.ident)));
+            }
+            let variants = data.variants.iter().map(|variant| &variant.ident);
+            quote! {
+                , dispatch = match(#(#variants),*)
+            }
+        },
+        _ => TokenStream::new(),
+    };
+
+    let mut result = TokenStream::new();
+    for DeriveTrait { unsafety, path } in traits {
+        let (macro_path, import) = exported(&path)?;
+        result.extend(quote! {
+            const _: () = {
+                #import
+                #macro_path! {
+                    #target #inner #bounds #dispatch #unsafety
+                }
+            };
+        });
+    }
+
+    Ok(result)
+}
+
+///Returns path to macro exporting trait at `path`, along with import of the trait
+///
+///Trait is imported, so that generated implementation can refer to it by name.
+fn exported(path: &syn::Path) -> syn::Result<(syn::Path, TokenStream)> {
+    let mut macro_path = path.clone();
+    let segment = match macro_path.segments.last_mut() {
+        Some(segment) => segment,
+        None => return Err(diagnostic::error_spanned(path, Code::InvalidTraitPath, "Expected path to trait")),
+    };
+    if !segment.arguments.is_empty() {
+        return Err(diagnostic::error_spanned(&segment.arguments, Code::GenericTrait, "Generic traits are not supported"));
+    }
+    segment.ident = macro_name(&segment.ident);
+
+    let import = match path.get_ident() {
+        Some(_) => TokenStream::new(),
+        None => quote! {
+            use #path;
+        },
+    };
+    Ok((macro_path, import))
+}
+
+///Generates invocation of exported trait's macro, which passes `implementation` to `__complete` along with trait's definition
+pub fn complete(args: TokenStream, implementation: &syn::ItemImpl) -> syn::Result<TokenStream> {
+    let path = match implementation.trait_ {
+        Some((None, ref path, _)) => path,
+        Some((Some(ref negative), _, _)) => return Err(diagnostic::error_spanned(negative, Code::CompleteNotTraitImpl, "Negative implementation has no items to complete")),
+        None => return Err(diagnostic::error_spanned(&implementation.self_ty, Code::CompleteNotTraitImpl, "`#[complete]` must be applied to trait implementation, e.g. `impl Lolka for Wrapper { ... }`")),
+    };
+    let (macro_path, import) = exported(path)?;
+
+    Ok(quote! {
+        const _: () = {
+            #import
+            #macro_path! {
+                @complete [#args] #implementation
+            }
+        };
+    })
+}
+
+///Arguments of `complete` attribute: type to forward to, followed by options
+pub struct CompleteArgs {
+    ///Explicitly specified type to forward to
+    pub inner: Option<syn::Type>,
+    ///Options of generated implementation
+    pub options: Options,
+}
+
+impl Parse for CompleteArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let inner = match input.parse::<Option<Token![=>]>>()? {
+            Some(_) => Some(input.parse()?),
+            None => None,
+        };
+        if inner.is_some() && !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        let mut options = Options::default();
+        while !input.is_empty() {
+            if !Options::peek(input) {
+                return Err(diagnostic::error(input.span(), Code::CompleteTarget, "Expected option, `complete` implements trait for type of the implementation, while type to forward to is specified as `=> <type>`"));
+            }
+            options.parse_option(input)?;
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            inner,
+            options,
+        })
+    }
+}
+
+///Input of `__complete` macro: bracketed arguments of `complete`, followed by implementation to complete and trait's definition
+pub struct Complete {
+    ///Arguments of `complete` attribute
+    pub args: CompleteArgs,
+    ///Implementation with items written by hand
+    pub implementation: syn::ItemImpl,
+    ///Exported trait
+    pub input: syn::ItemTrait,
+}
+
+impl Parse for Complete {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let args;
+        syn::bracketed!(args in input);
+
+        Ok(Self {
+            args: args.parse()?,
+            implementation: input.parse()?,
+            input: input.parse()?,
+        })
+    }
+}
+
+///Generates implementation, which forwards every item of the trait, except those written by implementation, which are kept as they are
+///
+///Written items are skipped by forwarding, having them as placeholder defaults, as trait's definition isn't emitted.
+pub fn completion(complete: Complete) -> TokenStream {
+    let Complete { args: CompleteArgs { inner, mut options }, implementation, mut input } = complete;
+    let mut failures = None;
+
+    for item in implementation.items.iter() {
+        let (name, kind) = match item {
+            syn::ImplItem::Fn(item) => (&item.sig.ident, "method"),
+            syn::ImplItem::Const(item) => (&item.ident, "associated const"),
+            syn::ImplItem::Type(item) => (&item.ident, "associated type"),
+            item => {
+                crate::push_error(&mut failures, diagnostic::error_spanned(item, Code::CompleteUnknownItem, "Cannot complete implementation with items other than methods, associated consts and types"));
+                continue;
+            },
+        };
+        let found = input.items.iter_mut().find_map(|trait_item| match (trait_item, item) {
+            (syn::TraitItem::Fn(trait_item), syn::ImplItem::Fn(item)) if trait_item.sig.ident == *name => {
+                trait_item.default = Some(item.block.clone());
+                Some(&mut trait_item.attrs)
+            },
+            (syn::TraitItem::Const(trait_item), syn::ImplItem::Const(item)) if trait_item.ident == *name => {
+                trait_item.default = Some((item.eq_token, item.expr.clone()));
+                Some(&mut trait_item.attrs)
+            },
+            (syn::TraitItem::Type(trait_item), syn::ImplItem::Type(item)) if trait_item.ident == *name => {
+                trait_item.default = Some((item.eq_token, item.ty.clone()));
+                Some(&mut trait_item.attrs)
+            },
+            _ => None,
+        });
+        match found {
+            Some(attrs) => {
+                attrs.retain(|attr| !is_attribute(attr.path(), "auto_trait"));
+                attrs.push(syn::parse_quote!(#[auto_trait(skip)]));
+            },
+            None => crate::push_error(&mut failures, diagnostic::error_spanned(name, Code::CompleteUnknownItem, format!("Trait '{}' has no {} '{}'", input.ident, kind, name))),
+        }
+    }
+
+    //Writing `unsafe impl` acknowledges trait's contract
+    options.unsafe_impl = implementation.unsafety.map(|unsafety| unsafety.span);
+    let args = Args {
+        targets: vec![Target {
+            typ: (*implementation.self_ty).clone(),
+            generics: implementation.generics.clone(),
+            inner,
+            keyword: None,
+            trait_path: None,
+        }],
+        options,
+        grouped: Vec::new(),
+    };
+
+    let mut result = TokenStream::new();
+    if failures.is_none() {
+        let (generated, errors) = crate::generate_checked(args, input, false);
+        failures = errors;
+        match syn::parse2::<syn::File>(generated.clone()) {
+            Ok(mut file) => {
+                if let Some(generated) = file.items.iter_mut().find_map(trait_impl) {
+                    generated.attrs.extend(implementation.attrs.iter().cloned());
+                    generated.items.extend(implementation.items.iter().cloned());
+                }
+                result.extend(file.items.iter().map(|item| quote!(#item)));
+            },
+            Err(_) => result.extend(generated),
+        }
+    }
+
+    if let Some(failures) = failures {
+        result.extend(diagnostic::to_compile_error(&failures));
+    }
+    result
+}
+
+///Returns generated trait implementation within item, which is scoped by `const _` block or placed into module
+fn trait_impl(item: &mut syn::Item) -> Option<&mut syn::ItemImpl> {
+    match item {
+        syn::Item::Impl(item) if item.trait_.is_some() => Some(item),
+        syn::Item::Const(item) => match *item.expr {
+            syn::Expr::Block(ref mut block) => block.block.stmts.iter_mut().find_map(|stmt| match stmt {
+                syn::Stmt::Item(item) => trait_impl(item),
+                _ => None,
+            }),
+            _ => None,
+        },
+        syn::Item::Mod(item) => item.content.as_mut().and_then(|(_, items)| items.iter_mut().find_map(trait_impl)),
+        _ => None,
+    }
+}
@@ -0,0 +1,172 @@
+//!Generation of fallible counterpart of the trait, which reports failure to access value instead of panicking
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+use crate::args::{Options, TryTrait};
+use crate::diagnostic::{self, Code};
+use crate::method::{cfg_variants, normalize_args, MethodOptions};
+use crate::strategy::{borrows_receiver, contains_ident, Receiver, Strategy};
+
+///Returns error of runtime companion crate, which describes access failure
+fn access_error() -> TokenStream {
+    quote! {
+        ::auto_trait_delegate::AccessError
+    }
+}
+
+///Returns methods of the trait, which have counterpart within fallible trait
+///
+///Only methods borrowing `self` access value, while signature referring to `Self` or result borrowing receiver cannot be restated by other trait.
+pub fn methods<'a>(items: &'a [syn::TraitItem], method_options: &'a [MethodOptions]) -> Vec<(&'a syn::TraitItemFn, &'a MethodOptions)> {
+    items.iter().zip(method_options.iter()).filter_map(|(item, options)| match item {
+        syn::TraitItem::Fn(method) if options.skip.is_none() => Some((method, options)),
+        _ => None,
+    }).filter(|(method, _)| {
+        let sig = &method.sig;
+        let inputs = sig.inputs.iter().filter(|arg| matches!(arg, syn::FnArg::Typed(_)));
+        let output = &sig.output;
+        let generics = &sig.generics;
+        let where_clause = &generics.where_clause;
+        matches!(Receiver::new(sig), Receiver::Ref | Receiver::Mut) && !borrows_receiver(sig) && !contains_ident(quote!(#(#inputs)* #output #generics #where_clause), "Self")
+    }).collect()
+}
+
+///Returns signature of method's counterpart, which wraps its output into `Result`
+fn signature(method: &syn::TraitItemFn, try_trait: &TryTrait, core: &TokenStream) -> syn::Signature {
+    let mut sig = normalize_args(&method.sig);
+    let output = match sig.output {
+        syn::ReturnType::Type(_, ref typ) => quote!(#typ),
+        syn::ReturnType::Default => quote!(()),
+    };
+    let error = match try_trait.error {
+        Some(ref error) => quote!(#error),
+        None => access_error(),
+    };
+    sig.output = syn::parse_quote!(-> #core::result::Result<#output, #error>);
+    sig
+}
+
+///Generates fallible counterpart of `trait_name` with visibility of the trait
+pub fn definition(try_trait: &TryTrait, vis: &syn::Visibility, trait_name: &syn::Ident, methods: &[(&syn::TraitItemFn, &MethodOptions)], core: &TokenStream) -> TokenStream {
+    let name = &try_trait.name;
+    let doc = format!("Fallible counterpart of `{}`, which reports failure to access value instead of panicking", trait_name);
+    let methods = methods.iter().map(|(method, _)| {
+        let attrs = method.attrs.iter().filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("cfg"));
+        let sig = signature(method, try_trait, core);
+        quote! {
+            #(#attrs)*
+            #sig;
+        }
+    });
+
+    quote! {
+        #[doc = #doc]
+        #vis trait #name {
+            #(
+                #methods
+            )*
+        }
+    }
+}
+
+///Generates implementation of fallible counterpart for target `typ`, calling methods of `callee` on accessed value
+///
+///Returns `None` for strategy, which cannot fail to access value.
+pub fn implementation(try_trait: &TryTrait, typ: &syn::Type, generics: &syn::Generics, callee: &TokenStream, strategy: &Strategy, options: &Options, methods: &[(&syn::TraitItemFn, &MethodOptions)]) -> Option<syn::Result<TokenStream>> {
+    if !matches!(strategy, Strategy::Mutex | Strategy::RefCell | Strategy::Weak) {
+        return None;
+    }
+
+    //Generated code points at the target, so that its errors are reported for it, rather than for whole attribute
+    let span = Span::call_site().located_at(typ.span());
+    let core = options.core_path();
+    let error = access_error();
+    let mut errors = None;
+    let mut implemented = Vec::with_capacity(methods.len());
+    for (method, method_options) in methods.iter() {
+        let method_name = &method.sig.ident;
+        let receiver = Receiver::new(&method.sig);
+        let access = match (strategy, receiver) {
+            (Strategy::Mutex, Receiver::Ref) => quote_spanned! {span=>
+                &*#core::result::Result::map_err(::std::sync::Mutex::lock(self), |_| #error::Poisoned)?
+            },
+            (Strategy::Mutex, Receiver::Mut) if options.lock_mut => quote_spanned! {span=>
+                &mut *#core::result::Result::map_err(::std::sync::Mutex::lock(self), |_| #error::Poisoned)?
+            },
+            (Strategy::RefCell, Receiver::Ref) => quote_spanned! {span=>
+                &*#core::result::Result::map_err(#core::cell::RefCell::try_borrow(self), |_| #error::Borrowed)?
+            },
+            (Strategy::RefCell, Receiver::Mut) => quote_spanned! {span=>
+                &mut *#core::result::Result::map_err(#core::cell::RefCell::try_borrow_mut(self), |_| #error::Borrowed)?
+            },
+            (Strategy::Weak, Receiver::Ref) => quote_spanned! {span=>
+                &*#core::option::Option::ok_or(Self::upgrade(self), #error::Dropped)?
+            },
+            (strategy, receiver) => {
+                let hint = match strategy {
+                    Strategy::Mutex => ", use `lock_mut` option to forward it by locking",
+                    _ => ", skip it with `#[auto_trait(skip)]`, if it has default implementation",
+                };
+                crate::push_error(&mut errors, diagnostic::error(span, Code::TryIncompatible, format!("Cannot forward {} '{}' of '{}' through {}{}", receiver.describe(), method_name, try_trait.name, strategy.name(), hint)));
+                continue;
+            },
+        };
+
+        let function = match method_options.call {
+            Some(ref call) => quote_spanned! {span=>
+                #call
+            },
+            None => quote_spanned! {span=>
+                #callee::#method_name
+            },
+        };
+        let awaited = method.sig.asyncness.map(|_| quote_spanned! {span=>
+            .await
+        });
+        let sig = signature(method, try_trait, &core);
+        //Parameters gated by `#[cfg]` are passed only by body, gated the same way
+        let bodies = cfg_variants(&sig).into_iter().map(|(predicate, variant)| {
+            let args = variant.inputs.iter().filter_map(|arg| match arg {
+                syn::FnArg::Typed(arg) => Some(&arg.pat),
+                syn::FnArg::Receiver(_) => None,
+            });
+            let body = quote_spanned! {span=>
+                #core::result::Result::Ok(#function(#access, #(#args,)*)#awaited)
+            };
+            match predicate {
+                Some(predicate) => quote! {
+                    #[cfg(#predicate)]
+                    {
+                        #body
+                    }
+                },
+                None => body,
+            }
+        });
+        let cfgs = method.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+        implemented.push(quote! {
+            #(#cfgs)*
+            #[inline]
+            #sig {
+                #(#bodies)*
+            }
+        });
+    }
+
+    if let Some(errors) = errors {
+        return Some(Err(errors));
+    }
+
+    let name = &try_trait.name;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    Some(Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #name for #typ #where_clause {
+            #(
+                #implemented
+            )*
+        }
+    }))
+}
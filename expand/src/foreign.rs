@@ -0,0 +1,58 @@
+//!Parsing of `auto_trait_impl` input
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::Token;
+
+use crate::args::Args;
+use crate::diagnostic::{self, Code};
+
+///Input of `auto_trait_impl` macro: `[unsafe] impl <trait> for <targets> { <methods> }`
+pub struct ForeignImpl {
+    ///Path to the trait
+    pub path: syn::Path,
+    ///Targets and options
+    pub args: Args,
+    ///Trait definition, restated from methods
+    pub input: syn::ItemTrait,
+}
+
+impl Parse for ForeignImpl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let unsafety: Option<Token![unsafe]> = input.parse()?;
+        input.parse::<Token![impl]>()?;
+        let path: syn::Path = input.parse()?;
+        input.parse::<Token![for]>()?;
+
+        let name = match path.segments.last() {
+            Some(segment) if segment.arguments.is_empty() => segment.ident.clone(),
+            Some(segment) => return Err(diagnostic::error_spanned(&segment.arguments, Code::GenericTrait, "Generic traits are not supported")),
+            None => return Err(diagnostic::error_spanned(&path, Code::InvalidTraitPath, "Expected path to trait")),
+        };
+
+        //Targets are followed by braced methods, which is last token
+        let mut args = TokenStream::new();
+        let methods = loop {
+            let token: TokenTree = input.parse()?;
+            match token {
+                TokenTree::Group(ref group) if group.delimiter() == proc_macro2::Delimiter::Brace && input.is_empty() => break group.clone(),
+                token => args.extend(Some(token)),
+            }
+        };
+
+        //Writing `unsafe impl` acknowledges trait's contract
+        let mut args: Args = syn::parse2(args)?;
+        for options in args.options_mut() {
+            options.unsafe_impl = unsafety.map(|unsafety| unsafety.span);
+        }
+
+        Ok(Self {
+            path,
+            args,
+            input: syn::parse2(quote! {
+                #unsafety trait #name #methods
+            })?,
+        })
+    }
+}
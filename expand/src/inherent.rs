@@ -0,0 +1,87 @@
+//!Generation of inherent methods, delegating to trait's implementation
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+
+///Replaces `Self::Item` with `<Self as Trait>::Item` for every associated type of the trait, as it is ambiguous outside of trait's implementation
+fn qualify_self(tokens: TokenStream, trait_path: &syn::Path, types: &[&syn::Ident]) -> TokenStream {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut result = TokenStream::new();
+    let mut idx = 0;
+    while idx < tokens.len() {
+        match &tokens[idx] {
+            TokenTree::Ident(ident) if ident == "Self" => {
+                let is_associated = match (tokens.get(idx + 1), tokens.get(idx + 2), tokens.get(idx + 3)) {
+                    (Some(TokenTree::Punct(first)), Some(TokenTree::Punct(second)), Some(TokenTree::Ident(name))) => first.as_char() == ':' && second.as_char() == ':' && types.contains(&name),
+                    _ => false,
+                };
+                match is_associated {
+                    true => result.extend(quote! {
+                        <#ident as #trait_path>
+                    }),
+                    false => result.extend(core::iter::once(tokens[idx].clone())),
+                }
+            },
+            TokenTree::Group(group) => {
+                let mut qualified = proc_macro2::Group::new(group.delimiter(), qualify_self(group.stream(), trait_path, types));
+                qualified.set_span(group.span());
+                result.extend(core::iter::once(TokenTree::Group(qualified)));
+            },
+            token => result.extend(core::iter::once(token.clone())),
+        }
+        idx += 1;
+    }
+
+    result
+}
+
+///Generates inherent methods with visibility of the trait, calling the same methods of trait's implementation
+pub fn methods(trait_path: &syn::Path, trait_name: &syn::Ident, vis: &syn::Visibility, items: &[syn::TraitItem]) -> Vec<TokenStream> {
+    let types = items.iter().filter_map(|item| match item {
+        syn::TraitItem::Type(item) => Some(&item.ident),
+        _ => None,
+    }).collect::<Vec<_>>();
+
+    items.iter().filter_map(|item| match item {
+        syn::TraitItem::Fn(method) => Some(method),
+        _ => None,
+    }).map(|method| {
+        let name = &method.sig.ident;
+        let doc = format!("Delegates to `{}::{}`.", trait_name, name);
+        let cfgs = method.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+        //Inherent method is deprecated as well, while calling trait's method is not reported
+        let deprecated = method.attrs.iter().filter(|attr| attr.path().is_ident("deprecated"));
+        let allow_deprecated = crate::method::allow_deprecated(&method.attrs);
+        let sig = crate::method::normalize_args(&method.sig);
+        let bodies = crate::method::cfg_variants(&sig).into_iter().map(|(predicate, variant)| {
+            let args = variant.inputs.iter().map(|arg| match arg {
+                syn::FnArg::Receiver(receiver) => receiver.self_token.to_token_stream(),
+                syn::FnArg::Typed(arg) => arg.pat.to_token_stream(),
+            });
+            let call = quote! {
+                <Self as #trait_path>::#name(#(#args),*)
+            };
+            match predicate {
+                Some(predicate) => quote! {
+                    #[cfg(#predicate)]
+                    {
+                        #call
+                    }
+                },
+                None => call,
+            }
+        }).collect::<Vec<_>>();
+        let sig = qualify_self(sig.to_token_stream(), trait_path, &types);
+
+        quote! {
+            #[doc = #doc]
+            #(#cfgs)*
+            #(#deprecated)*
+            #allow_deprecated
+            #[inline]
+            #vis #sig {
+                #(#bodies)*
+            }
+        }
+    }).collect()
+}
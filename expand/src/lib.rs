@@ -0,0 +1,2185 @@
+//!Expansion logic of `auto-trait` macros
+//!
+//!Macros of `auto-trait` are thin wrappers over this crate, which works with `proc_macro2` tokens,
+//!so that expansion can be inspected outside of compiler, e.g. in snapshot tests or by other code generators:
+//!
+//!```rust
+//!use quote::quote;
+//!
+//!let output = auto_trait_expand::expand(quote!(Box<T: Lolka>), quote! {
+//!    pub trait Lolka {
+//!        fn lolka(&self) -> u32;
+//!    }
+//!}).expect("to expand");
+//!
+//!let output: syn::File = syn::parse2(output).expect("valid output");
+//!assert_eq!(output.items.len(), 2);
+//!```
+//!
+//![expand](fn.expand.html) fails on first error, while [macros](macros/index.html) report errors as `compile_error!`, alongside the rest of expansion, as macros do.
+//!Options and errors are the same as of `auto-trait` crate, which documents them.
+//!
+//!Expansion is a pure function of its input: implementations follow targets in order of attributes, their items follow declaration order of the trait,
+//!while nothing depends on unordered collections, time or environment, other than printing of `AUTO_TRAIT_DEBUG`.
+//!Hence incremental builds reuse expansion and diffs of expanded code stay stable, which snapshot tests guard.
+//!
+//!## Features
+//!
+//!- `std` (default) - refers to `Box`, `Rc` and `Arc` through `::std` rather than `::alloc`, and enables `Mutex` targets, `cache` option and `io` traits, which require `std`.
+//!- `nightly-diagnostics` - emits warnings about suspicious forwarding, which requires nightly compiler and works only within procedural macro.
+//!- `tracing` - enables `instrument` option.
+//!- `const-impl` - enables `const_impl` option.
+//!- `bytemuck` - enables `via = transparent` option.
+//!- `specialization` - enables `specialization` option.
+#![cfg_attr(feature = "nightly-diagnostics", feature(proc_macro_diagnostic))]
+#![warn(missing_docs)]
+#![allow(clippy::style)]
+
+#[cfg(feature = "nightly-diagnostics")]
+extern crate proc_macro;
+
+use quote::quote;
+
+mod target;
+use target::{Keyword, Target};
+mod args;
+use args::{edit_distance, is_attribute, Args, CfgAttr};
+mod strategy;
+use strategy::{Forward, Receiver, Strategy};
+mod method;
+use method::{allow_deprecated, Analyzed, MethodOptions};
+mod export;
+mod foreign;
+mod std_traits;
+mod equivalence;
+mod inherent;
+mod fallible;
+mod shared;
+mod wrapper;
+mod diagnostic;
+use diagnostic::Code;
+pub mod macros;
+
+///Expands `#[auto_trait(args)]` attribute of `item` trait into trait and its implementations
+///
+///Returns error, when `item` is not a trait, `args` are invalid, or implementation of any target cannot be generated,
+///combining errors of every failed target.
+pub fn expand(args: proc_macro2::TokenStream, item: proc_macro2::TokenStream) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let item = parse_trait(item, "auto_trait")?;
+    let args = syn::parse2(args)?;
+    match generate_checked(args, item, true) {
+        (result, None) => Ok(result),
+        (_, Some(failures)) => Err(failures),
+    }
+}
+
+///Parses trait, annotated by `attribute`, reporting other items with dedicated error at their keyword
+///
+///Trait declared as `const trait`, which is not parsed by syn, is parsed without `const`, which is restored by marker attribute when trait is emitted.
+pub(crate) fn parse_trait(input: proc_macro2::TokenStream, attribute: &str) -> syn::Result<syn::ItemTrait> {
+    let (input, constness) = strip_const(input);
+    let keyword = match syn::parse2(input.clone()) {
+        Ok(syn::Item::Trait(mut input)) => {
+            if let Some(constness) = constness {
+                input.attrs.push(syn::parse_quote_spanned!(constness=> #[__auto_trait_const]));
+            }
+            return Ok(input);
+        },
+        Ok(syn::Item::Struct(item)) => item.struct_token.span,
+        Ok(syn::Item::Enum(item)) => item.enum_token.span,
+        Ok(syn::Item::Union(item)) => item.union_token.span,
+        Ok(syn::Item::Impl(item)) => item.impl_token.span,
+        Ok(syn::Item::Fn(item)) => item.sig.fn_token.span,
+        Ok(syn::Item::Mod(item)) => item.mod_token.span,
+        Ok(syn::Item::Type(item)) => item.type_token.span,
+        Ok(syn::Item::TraitAlias(item)) => item.trait_token.span,
+        _ => return syn::parse2(input),
+    };
+
+    Err(diagnostic::error(keyword, Code::NotTrait, format!("`#[{}]` must be applied to trait definition, to generate implementations from the type side, see `#[derive(AutoTrait)]`", attribute)))
+}
+
+///Removes `const` of `const trait`, returning its span
+fn strip_const(input: proc_macro2::TokenStream) -> (proc_macro2::TokenStream, Option<proc_macro2::Span>) {
+    let tokens = input.into_iter().collect::<Vec<_>>();
+    let is_ident = |token: &proc_macro2::TokenTree, name: &str| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == name);
+    let position = tokens.iter().position(|token| is_ident(token, "const")).filter(|&idx| {
+        tokens[idx + 1..].iter().find(|token| !is_ident(token, "unsafe") && !is_ident(token, "auto")).map(|token| is_ident(token, "trait")).unwrap_or(false)
+    });
+
+    match position {
+        Some(idx) => {
+            let span = tokens[idx].span();
+            (tokens.into_iter().enumerate().filter(|(jdx, _)| *jdx != idx).map(|(_, token)| token).collect(), Some(span))
+        },
+        None => (tokens.into_iter().collect(), None),
+    }
+}
+
+///Emits trait, restoring `const` of `const trait` before its other qualifiers
+fn emit_const(input: &syn::ItemTrait, constness: proc_macro2::Span) -> proc_macro2::TokenStream {
+    let mut result = proc_macro2::TokenStream::new();
+    let mut inserted = false;
+    for token in quote!(#input) {
+        if let proc_macro2::TokenTree::Ident(ref ident) = token {
+            if !inserted && (ident == "unsafe" || ident == "auto" || ident == "trait") {
+                result.extend(Some(proc_macro2::TokenTree::Ident(syn::Ident::new("const", constness))));
+                inserted = true;
+            }
+        }
+        result.extend(Some(token));
+    }
+
+    result
+}
+
+///Generates implementations, followed by trait itself if `emit_trait`
+///
+///Errors are reported alongside trait and implementations of remaining targets, so that they don't cause errors elsewhere.
+pub(crate) fn generate(args: Args, input: syn::ItemTrait, emit_trait: bool) -> proc_macro2::TokenStream {
+    let (mut result, failures) = generate_checked(args, input, emit_trait);
+    if let Some(failures) = failures {
+        result.extend(diagnostic::to_compile_error(&failures));
+    }
+
+    result
+}
+
+///Generates implementations, followed by trait itself if `emit_trait`, returning errors separately from them
+fn generate_checked(args: Args, mut input: syn::ItemTrait, emit_trait: bool) -> (proc_macro2::TokenStream, Option<syn::Error>) {
+    let mut args = vec![args];
+    let mut attrs_to_remove = Vec::new();
+    let mut failures = None;
+
+    let mut constness = None;
+    input.attrs.retain(|attr| match attr.path().is_ident("__auto_trait_const") {
+        true => {
+            constness = Some(syn::spanned::Spanned::span(attr));
+            false
+        },
+        false => true,
+    });
+
+    let mut defaults = Vec::new();
+    input.attrs.retain(|attr| {
+        if !is_attribute(attr.path(), "auto_trait_default") {
+            return true;
+        }
+
+        match attr.parse_args::<args::Options>() {
+            Ok(options) => defaults.push(options),
+            Err(error) => push_error(&mut failures, error),
+        }
+        false
+    });
+
+    for idx in 0..input.attrs.len() {
+        let attr = &input.attrs[idx];
+
+        if is_attribute(attr.path(), "auto_trait") {
+            match Args::from_meta(&attr.meta) {
+                Ok(arg) => args.push(arg),
+                Err(error) => push_error(&mut failures, error),
+            }
+
+            attrs_to_remove.push(idx);
+        } else {
+            let cfg_attr = match CfgAttr::parse(attr) {
+                Some(cfg_attr) if cfg_attr.contains_auto_trait() => cfg_attr,
+                _ => continue,
+            };
+
+            match cfg_attr.extract(&mut args) {
+                Ok(Some(remaining)) => input.attrs[idx] = remaining,
+                Ok(None) => attrs_to_remove.push(idx),
+                Err(error) => {
+                    push_error(&mut failures, error);
+                    attrs_to_remove.push(idx);
+                },
+            }
+        }
+    }
+
+    //We need to remove attributes that we're going to parse, starting from the last one, so that indices stay valid
+    for idx in attrs_to_remove.into_iter().rev() {
+        input.attrs.remove(idx);
+    }
+
+    //`crate_path` for the whole trait is resolved before items generated along with trait, which refer to `core` by it
+    let mut crate_path = None;
+    for options in args.iter().filter(|args| args.targets.is_empty() && args.grouped.is_empty()).map(|args| &args.options) {
+        if options.dispatch_enum.is_some() || options.newtype.is_some() || options.cache.is_some() || options.specialization.is_some() {
+            continue;
+        }
+        if let Some(ref path) = options.crate_path {
+            if crate_path.replace(path.clone()).is_some() {
+                push_error(&mut failures, diagnostic::error_spanned(path, Code::DuplicateTraitOption, "Duplicate `crate_path` for the whole trait"));
+            }
+        }
+    }
+    let crate_path = crate_path.or_else(|| defaults.iter().find_map(|default| default.crate_path.clone()));
+    for args in args.iter_mut() {
+        for options in core::iter::once(&mut args.options).chain(args.grouped.iter_mut().map(|(_, group)| &mut group.options)) {
+            if options.crate_path.is_none() {
+                options.crate_path = crate_path.clone();
+            }
+        }
+    }
+
+    //Dispatch enum is generated along with trait, holding target of every variant, and becomes additional target of its attribute
+    let mut enums = proc_macro2::TokenStream::new();
+    let mut enum_args = Vec::new();
+    for args in args.iter_mut() {
+        for name in args.grouped.iter_mut().filter_map(|(_, group)| group.options.dispatch_enum.take()) {
+            push_error(&mut failures, diagnostic::error_spanned(&name, Code::EnumTargetOption, format!("`enum` option holds every target of the attribute, specify it before targets, e.g. `enum = {}, Wrapper, Box<u32>`", name)));
+        }
+        let name = match args.options.dispatch_enum.take() {
+            Some(name) => name,
+            None => {
+                let variants = core::iter::once(&args.options).chain(args.grouped.iter().map(|(_, group)| &group.options)).filter_map(|options| options.variant.as_ref());
+                for variant in variants {
+                    push_error(&mut failures, diagnostic::error_spanned(variant, Code::VariantWithoutEnum, format!("`variant` option names variant of enum, which requires `enum = <name>` option, e.g. `enum = Any{}`", input.ident)));
+                }
+                continue;
+            },
+        };
+        if let Some(ref variant) = args.options.variant {
+            push_error(&mut failures, diagnostic::error_spanned(variant, Code::EnumVariantRequired, "`variant` option names variant of single target, specify it after target, e.g. `Wrapper; variant = Name`"));
+            continue;
+        }
+
+        let targets = args.listed().into_iter().map(|(target, options)| (target, options.and_then(|options| options.variant.as_ref())));
+        let mut variants: Vec<(syn::Ident, syn::Type)> = Vec::new();
+        let mut valid = true;
+        for (target, variant) in targets {
+            let typ = &target.typ;
+            //Variant cannot borrow for lifetime, which is not declared by enum
+            let borrowed = matches!(typ, syn::Type::Reference(reference) if reference.lifetime.as_ref().map(|lifetime| lifetime.ident != "static").unwrap_or(true));
+            if target.keyword.is_some() || !target.generics.params.is_empty() || borrowed {
+                push_error(&mut failures, diagnostic::error_spanned(typ, Code::EnumTargetUnsupported, format!("Enum '{}' cannot hold '{}', as its variants require concrete type without lifetimes, e.g. `Box<u32>` or `&'static str`", name, diagnostic::display(&typ))));
+                valid = false;
+                continue;
+            }
+            //Variant is named after last segment of type's path by default
+            let variant = match (variant, typ) {
+                (Some(variant), _) => variant.clone(),
+                (None, syn::Type::Path(typ)) if typ.qself.is_none() => match typ.path.segments.last() {
+                    Some(segment) => syn::ext::IdentExt::unraw(&segment.ident),
+                    None => continue,
+                },
+                (None, _) => {
+                    push_error(&mut failures, diagnostic::error_spanned(typ, Code::EnumVariantRequired, format!("Cannot name variant of enum '{}' after '{}', name it with `{}; variant = <name>`", name, diagnostic::display(&typ), diagnostic::display(&typ))));
+                    valid = false;
+                    continue;
+                },
+            };
+            if let Some((_, other)) = variants.iter().find(|(other, _)| *other == variant) {
+                push_error(&mut failures, diagnostic::error_spanned(typ, Code::DuplicateEnumVariant, format!("Variant '{}' of enum '{}' is already taken by '{}', name it with `{}; variant = <name>`", variant, name, diagnostic::display(&other), diagnostic::display(&typ))));
+                valid = false;
+                continue;
+            }
+            variants.push((variant, typ.clone()));
+        }
+        if !valid {
+            continue;
+        }
+
+        let mut options = args.options.clone();
+        options.dispatch = Some(args::Dispatch::Match(variants.iter().map(|(variant, typ)| (variant.clone(), Some(typ.clone()))).collect()));
+        //Enum holds the same types in every configuration
+        options.test = None;
+        options.test_target = None;
+        enums.extend(wrapper::dispatch_enum(&name, &input.vis, &input.ident, &variants, options.cfg_attribute().as_ref(), &options.core_path()));
+        enum_args.push(Args {
+            targets: vec![syn::parse_quote!(self::#name)],
+            options,
+            grouped: Vec::new(),
+        });
+    }
+    args.extend(enum_args);
+
+    let mut args = args.into_iter().flat_map(Args::flatten).collect::<Vec<_>>();
+
+    //Newtype is generated along with trait and becomes target of its attribute, forwarding to its only field
+    let mut newtypes = proc_macro2::TokenStream::new();
+    for args in args.iter_mut() {
+        let name = match args.options.newtype.take() {
+            Some(name) => name,
+            None => continue,
+        };
+        if let Some(target) = args.targets.first() {
+            push_error(&mut failures, diagnostic::error_spanned(&target.typ, Code::NewtypeConflict, format!("`newtype` option cannot be used with targets, as newtype '{}' is target itself", name)));
+            continue;
+        }
+        let trait_name = &input.ident;
+        args.targets.push(syn::parse_quote!(self::#name<T: #trait_name>));
+        //Field is moved out by methods taking `self` directly, rather than through conversion, unless it is specified
+        if args.options.into.is_none() {
+            args.options.field.get_or_insert_with(|| syn::Member::Unnamed(syn::Index::from(0)));
+        }
+        newtypes.extend(wrapper::newtype(&name, &input.vis, trait_name, &args.options.core_path()));
+    }
+
+    //Blanket implementation is target of its attribute, implemented for any type dereferencing to type to forward to
+    for args in args.iter_mut() {
+        let inner = match args.options.specialization {
+            Some(ref inner) => inner,
+            None => continue,
+        };
+        if let Some(target) = args.targets.first() {
+            push_error(&mut failures, diagnostic::error_spanned(&target.typ, Code::SpecializationConflict, format!("`specialization` option cannot be used with targets, as it implements trait for every type dereferencing to '{}', which can specialize it", diagnostic::display(&inner))));
+            args.options.specialization = None;
+            continue;
+        }
+        let core = args.options.core_path();
+        args.targets.push(syn::parse_quote!(for<__AutoTrait: ?#core::marker::Sized> __AutoTrait => #inner));
+    }
+
+    //Cache is generated the same way as newtype, once methods to cache are known
+    let mut caches = Vec::new();
+    for (index, args) in args.iter_mut().enumerate() {
+        let name = match args.options.cache.take() {
+            Some(name) => name,
+            None => continue,
+        };
+        let conflict = match args.targets.first() {
+            Some(target) => Some((quote::ToTokens::to_token_stream(&target.typ), format!("targets, as cache '{}' is target itself", name))),
+            None if !input.generics.params.is_empty() => Some((quote!(#name), "generic trait".to_owned())),
+            None => None,
+        };
+        if let Some((tokens, conflict)) = conflict {
+            push_error(&mut failures, diagnostic::error_spanned(tokens, Code::CacheConflict, format!("`cache` option cannot be used with {}", conflict)));
+            continue;
+        }
+        let trait_name = &input.ident;
+        args.targets.push(syn::parse_quote!(self::#name<T: #trait_name>));
+        args.options.deref_mut.get_or_insert_with(|| syn::parse_quote!(#name::inner_mut));
+        args.options.into.get_or_insert_with(|| syn::parse_quote!(#name::into_inner));
+        caches.push((name, args.options.cfg_attribute(), index));
+    }
+
+    let mut trait_path = None;
+    let mut module = None;
+    for options in args.iter().filter(|args| args.targets.is_empty()).map(|args| &args.options) {
+        if let Some(ref path) = options.trait_path {
+            if trait_path.replace(path.clone()).is_some() {
+                push_error(&mut failures, diagnostic::error_spanned(path, Code::DuplicateTraitOption, "Duplicate `trait_path` for the whole trait"));
+            }
+        }
+        if let Some(ref name) = options.module {
+            if module.replace(name.clone()).is_some() {
+                push_error(&mut failures, diagnostic::error_spanned(name, Code::DuplicateTraitOption, "Duplicate `module` for the whole trait"));
+            }
+        }
+    }
+    for options in args.iter_mut().map(|args| &mut args.options) {
+        if options.trait_path.is_none() {
+            options.trait_path = trait_path.clone();
+        }
+        if options.module.is_none() {
+            options.module = module.clone();
+        }
+    }
+
+    for options in args.iter_mut().map(|args| &mut args.options) {
+        for default in defaults.iter() {
+            options.inherit(default);
+        }
+    }
+
+    //Target with `test_target` is implemented twice, gated by `cfg(test)` and `cfg(not(test))`, so that every configuration has single implementation
+    let mut test_args = Vec::new();
+    for args in args.iter_mut() {
+        let test_target = match args.options.test_target.take() {
+            Some(test_target) => test_target,
+            None => continue,
+        };
+        let conflict = match (&args.options.stub, &args.options.test) {
+            _ if args.targets.iter().any(|target| target.keyword.is_some()) => Some("keyword target"),
+            (Some(_), _) => Some("`stub` option"),
+            (_, Some(_)) => Some("`test` option"),
+            (None, None) => None,
+        };
+        if let Some(conflict) = conflict {
+            push_error(&mut failures, diagnostic::error_spanned(&test_target, Code::TestTargetConflict, format!("`test_target` option cannot be used with {}", conflict)));
+            continue;
+        }
+        //Test target is borrowed through `AsRef` and `AsMut`, as wrapper dereferences to its usual inner value
+        let core = args.options.core_path();
+        let mut options = args.options.clone();
+        options.via = None;
+        options.transparent = None;
+        options.cfg.push(quote!(test));
+        options.deref.get_or_insert_with(|| syn::parse_quote!(#core::convert::AsRef::<#test_target>::as_ref));
+        options.deref_mut.get_or_insert_with(|| syn::parse_quote!(#core::convert::AsMut::<#test_target>::as_mut));
+        let targets = args.targets.iter().map(|target| Target {
+            inner: Some(test_target.clone()),
+            ..target.clone()
+        }).collect();
+        args.options.cfg.push(quote!(not(test)));
+        test_args.push(Args {
+            targets,
+            options,
+            grouped: Vec::new(),
+        });
+    }
+    args.extend(test_args);
+
+    //Trait of `via` option converts target by its methods, unless conversion is specified explicitly
+    for options in args.iter_mut().map(|args| &mut args.options) {
+        if let Some(via) = options.via.clone() {
+            options.deref.get_or_insert_with(|| syn::parse_quote!(#via::delegate));
+            options.deref_mut.get_or_insert_with(|| syn::parse_quote!(#via::delegate_mut));
+            options.into.get_or_insert_with(|| syn::parse_quote!(#via::delegate_owned));
+        }
+        if options.transparent.is_some() {
+            options.deref.get_or_insert_with(|| syn::parse_quote!(::bytemuck::TransparentWrapper::peel_ref));
+            options.deref_mut.get_or_insert_with(|| syn::parse_quote!(::bytemuck::TransparentWrapper::peel_mut));
+            options.into.get_or_insert_with(|| syn::parse_quote!(::bytemuck::TransparentWrapper::peel));
+        }
+    }
+
+    let export = match args.iter().any(|args| args.options.export) {
+        true => export::export(&input),
+        false => proc_macro2::TokenStream::new(),
+    };
+
+    let mut method_options = Vec::with_capacity(input.items.len());
+    let mut invalid_options = false;
+    for item in input.items.iter_mut() {
+        let options = match item {
+            syn::TraitItem::Fn(ref mut method) => MethodOptions::extract(method),
+            item => MethodOptions::extract_item(item),
+        };
+        //Item with invalid options is forwarded as if it had none
+        let options = match options {
+            Ok(options) => options,
+            Err(error) => {
+                push_error(&mut failures, error);
+                invalid_options = true;
+                MethodOptions::default()
+            },
+        };
+        method_options.push(options);
+    }
+
+    //Cached method's result is stored by cache on the first call, while every call returns its clone
+    for (name, cfg, index) in caches.iter() {
+        let cached = input.items.iter().zip(method_options.iter_mut()).filter_map(|(item, options)| match item {
+            syn::TraitItem::Fn(method) if options.cache.is_some() => Some((method, options)),
+            _ => None,
+        }).collect::<Vec<_>>();
+        //Methods with invalid options might have been marked, which is already reported
+        if cached.is_empty() {
+            if !invalid_options {
+                push_error(&mut failures, diagnostic::error_spanned(name, Code::CacheWithoutMethods, format!("Cache '{}' has no methods to cache, mark them with `#[auto_trait(cache)]`", name)));
+            }
+            continue;
+        }
+
+        let trait_name = &input.ident;
+        let trait_path = args[*index].options.trait_path.clone().unwrap_or_else(|| trait_name.clone().into());
+        let mut fields = Vec::with_capacity(cached.len());
+        for (method, options) in cached {
+            let method_name = &method.sig.ident;
+            let field = wrapper::cache_field(method_name);
+            let typ = match method.sig.output {
+                syn::ReturnType::Type(_, ref typ) => typ.as_ref().clone(),
+                syn::ReturnType::Default => continue,
+            };
+            let core = args[*index].options.core_path();
+            options.target_bodies.push((syn::parse_quote!(self::#name<T>), syn::parse_quote!({
+                #core::clone::Clone::clone(::std::sync::OnceLock::get_or_init(&self.#field, || <T as #trait_path>::#method_name(&self.inner)))
+            })));
+            fields.push((field, typ));
+        }
+        newtypes.extend(wrapper::cache(name, &input.vis, trait_name, &fields, cfg.as_ref(), &args[*index].options.core_path()));
+    }
+
+    //Fallible counterpart is generated once for every name, mirroring methods, which access value
+    let try_methods = fallible::methods(&input.items, &method_options);
+    let mut try_traits = Vec::<&args::TryTrait>::new();
+    for options in args.iter().map(|args| &args.options) {
+        let try_trait = match options.try_trait {
+            Some(ref try_trait) if !try_traits.iter().any(|other| other.name == try_trait.name) => try_trait,
+            _ => continue,
+        };
+        if !input.generics.params.is_empty() {
+            push_error(&mut failures, diagnostic::error_spanned(&try_trait.name, Code::TryTraitConflict, format!("`try_trait` option cannot be used with generic trait, as '{}' cannot restate its parameters", try_trait.name)));
+            break;
+        }
+        newtypes.extend(fallible::definition(try_trait, &input.vis, &input.ident, &try_methods, &options.core_path()));
+        try_traits.push(try_trait);
+    }
+
+    for options in method_options.iter() {
+        for (typ, _) in options.target_bodies.iter() {
+            if !args.iter().flat_map(|args| args.targets.iter()).any(|target| target.typ == *typ) {
+                push_error(&mut failures, diagnostic::error_spanned(typ, Code::BodyUnknownTarget, "Body override refers to type, which is not a target"));
+            }
+        }
+    }
+
+    for name in args.iter().flat_map(|args| args.options.filtered_methods()) {
+        let exists = input.items.iter().any(|item| match item {
+            syn::TraitItem::Fn(method) => method.sig.ident == *name,
+            _ => false,
+        });
+        if !exists {
+            push_error(&mut failures, diagnostic::error_spanned(name, Code::UnknownMethod, format!("Trait has no method '{}'", name)));
+        }
+    }
+
+    let mut impls = Vec::new();
+    let mut modules = Vec::<(syn::Ident, Vec<proc_macro2::TokenStream>)>::new();
+
+    //Parameters of trait and its methods cannot be redeclared by implementation
+    let mut reserved = input.generics.clone();
+    for item in input.items.iter() {
+        if let syn::TraitItem::Fn(method) = item {
+            reserved.params.extend(method.sig.generics.params.iter().cloned());
+        }
+    }
+
+    //Arguments of generic trait, which are added to its path and bounds to it
+    let trait_arguments: Option<syn::AngleBracketedGenericArguments> = match input.generics.params.is_empty() {
+        true => None,
+        false => {
+            let (_, ty_generics, _) = input.generics.split_for_impl();
+            let turbofish = ty_generics.as_turbofish();
+            Some(syn::parse_quote!(#turbofish))
+        },
+    };
+
+    let mut targets = Vec::new();
+    //Targets are compared token-wise, as implementations for the same type conflict, unless they are gated by different `cfg`
+    let mut listed = Vec::new();
+    for (target, options) in args.iter().flat_map(|args| args.targets.iter().map(move |target| (target, &args.options))) {
+        let Target { typ, keyword, trait_path: pinned, .. } = target;
+        let cfg = &options.cfg;
+        let listing = (quote!(#typ #pinned #(#cfg)*).to_string(), keyword.map(Keyword::name));
+        if listed.contains(&listing) {
+            let name = match keyword {
+                Some(keyword) => keyword.name().to_owned(),
+                None => diagnostic::display(typ),
+            };
+            push_error(&mut failures, diagnostic::error_spanned(typ, Code::DuplicateTarget, format!("Target '{}' is listed twice", name)));
+            continue;
+        }
+        //Implementation for generic target conflicts with implementation for its instantiation
+        let cfg = quote!(#(#cfg)*).to_string();
+        let overlapping = targets.iter().filter(|(previous, previous_options, reference): &&(Target, &args::Options, Option<_>)| {
+            let previous_cfg = &previous_options.cfg;
+            reference.is_none() && previous.trait_path == *pinned && quote!(#(#previous_cfg)*).to_string() == cfg
+        }).map(|(previous, _, _)| previous).find(|previous| previous.subsumes(target) || target.subsumes(previous));
+        if let Some(previous) = overlapping {
+            let name = |target: &Target| match target.keyword {
+                Some(keyword) => keyword.name().to_owned(),
+                None => diagnostic::display(&target.typ),
+            };
+            let (generic, concrete) = match previous.subsumes(target) {
+                true => (name(previous), name(target)),
+                false => (name(target), name(previous)),
+            };
+            let mut error = diagnostic::error_spanned(typ, Code::OverlappingTargets, format!("Implementation for target '{}' overlaps with implementation for target '{}', which covers '{}' as well", concrete, generic, concrete));
+            error.combine(diagnostic::note_spanned(&previous.typ, format!("Target '{}' is listed here", name(previous))));
+            push_error(&mut failures, error);
+            continue;
+        }
+        listed.push(listing);
+        targets.push((target.clone(), options, None));
+
+        //References to concrete targets forward to target's implementation
+        if target.keyword.is_none() {
+            for &(span, keyword, flag) in [(options.also_ref, Keyword::Ref, "also_ref"), (options.also_mut_ref, Keyword::Mut, "also_mut_ref")].iter() {
+                if let Some(span) = span {
+                    targets.push((target.reference(keyword), options, Some((flag, span))));
+                }
+            }
+        }
+    }
+
+    //Whether trait can be made into trait object, checked once for every target requiring it
+    let mut dyn_compatible = None;
+    //Methods are analyzed once, so that every target only renders their bodies
+    let analyzed = input.items.iter().map(|item| match item {
+        syn::TraitItem::Fn(method) => Some(Analyzed::new(method)),
+        _ => None,
+    }).collect::<Vec<_>>();
+    //Helper of `shared` option is generated once, restating trait's methods, which are shared by every target
+    let mut helper = args.iter().map(|args| &args.options).find(|options| options.shared);
+    if let (Some(_), false) = (helper, input.generics.params.is_empty()) {
+        push_error(&mut failures, diagnostic::error_spanned(&input.generics, Code::SharedConflict, format!("`shared` option cannot be used with generic trait, as helper of '{}' cannot restate its parameters", input.ident)));
+        helper = None;
+    }
+    if let Some(options) = helper {
+        newtypes.extend(shared::definition(&input, &analyzed, &options.core_path()));
+    }
+    'targets: for (index, (written, options, reference)) in targets.iter().enumerate() {
+        let (target, renames) = match written.rename(&reserved) {
+            Ok(target) => target,
+            Err(error) => { push_error(&mut failures, error); continue 'targets; },
+        };
+        let target = &target;
+        let Target { typ: args, generics, inner, keyword, trait_path: pinned } = target;
+        let trait_name = input.ident.clone();
+        //Bound to generic trait with arguments, e.g. `T: Convert<u32>`, selects arguments the same way
+        let pinned = match pinned {
+            Some(pinned) => Some(pinned.clone()),
+            None if !input.generics.params.is_empty() => bound_with_arguments(generics, &trait_name),
+            None => None,
+        };
+        let pinned = &pinned;
+        let mut trait_path = options.trait_path.clone().unwrap_or_else(|| trait_name.clone().into());
+        //Target can implement trait only for specified arguments, which replace trait's parameters
+        let (trait_arguments, substitutions) = match pinned {
+            Some(pinned) => {
+                let arguments = match pinned.segments.last() {
+                    Some(segment) if segment.ident != trait_name => { push_error(&mut failures, diagnostic::error_spanned(pinned, Code::PinnedTraitMismatch, format!("Expected arguments of trait '{}'", trait_name))); continue 'targets; },
+                    Some(syn::PathSegment { arguments: syn::PathArguments::AngleBracketed(arguments), .. }) => syn::AngleBracketedGenericArguments {
+                        colon2_token: Some(Default::default()),
+                        ..arguments.clone()
+                    },
+                    _ => { push_error(&mut failures, diagnostic::error_spanned(pinned, Code::PinnedTraitArgumentsRequired, format!("Specify arguments of trait '{}', e.g. `for {}<Type>`", trait_name, trait_name))); continue 'targets; },
+                };
+                match target::Substitution::new(&input.generics, pinned) {
+                    Ok(substitutions) => (Some(arguments), substitutions),
+                    Err(error) => { push_error(&mut failures, error); continue 'targets; },
+                }
+            },
+            None => (trait_arguments.clone(), Vec::new()),
+        };
+        if let (Some(segment), Some(arguments)) = (trait_path.segments.last_mut(), &trait_arguments) {
+            if segment.arguments.is_empty() || pinned.is_some() {
+                segment.arguments = syn::PathArguments::AngleBracketed(arguments.clone());
+            }
+        }
+        let dyn_type = keyword.and_then(|keyword| keyword.dyn_type(&trait_path));
+        if let (Some(keyword), Some(_)) = (keyword, &dyn_type) {
+            let compatible = *dyn_compatible.get_or_insert_with(|| match strategy::dyn_incompatible_items(&input, *keyword) {
+                Some(error) => {
+                    push_error(&mut failures, error);
+                    false
+                },
+                None => true,
+            });
+            if !compatible {
+                continue 'targets;
+            }
+        }
+        let args = dyn_type.as_ref().unwrap_or(args);
+        if let (None, Some(arguments)) = (keyword, parenthesized_arguments(args)) {
+            push_error(&mut failures, diagnostic::error_spanned(arguments, Code::ParenthesizedArguments, format!("Unsupported parenthesized arguments within target, as `Fn` traits cannot be forwarded to, wrap closure into type (e.g. `struct Callback(Box<dyn Fn(u32) -> u32>)`), which implements trait '{}', and use it as target instead", trait_name)));
+            continue 'targets;
+        }
+        match args {
+            _ if keyword.is_some() => (),
+            syn::Type::Path(ref typ) if typ.qself.is_some() => { push_error(&mut failures, diagnostic::error_spanned(typ, Code::QualifiedTarget, "Qualified path cannot be used as target, specify type it refers to")); continue 'targets; },
+            syn::Type::Path(_) | syn::Type::Slice(_) | syn::Type::TraitObject(_) | syn::Type::Reference(_) | syn::Type::Macro(_) => (),
+            syn::Type::Tuple(ref tuple) if !tuple.elems.is_empty() => (),
+            ref other => {
+                let kind = match other {
+                    syn::Type::Array(_) => "array",
+                    syn::Type::BareFn(_) => "function pointer",
+                    syn::Type::ImplTrait(_) => "`impl Trait` type",
+                    syn::Type::Infer(_) => "inferred type",
+                    syn::Type::Never(_) => "never type",
+                    syn::Type::Ptr(_) => "raw pointer",
+                    syn::Type::Tuple(_) => "unit type",
+                    _ => "type",
+                };
+                push_error(&mut failures, diagnostic::error_spanned(other, Code::UnsupportedTarget, format!("Unsupported {} as target, expected type path (e.g. `Wrapper` or `Box<T: {}>`), reference, slice, tuple or trait object", kind, trait_name)));
+                continue 'targets;
+            },
+        }
+
+        if let Some(error) = bare_trait_object(&written.typ, &input) {
+            push_error(&mut failures, error);
+            continue 'targets;
+        }
+
+        let strategy = Strategy::new(target, options);
+        if !cfg!(feature = "std") && matches!(strategy, Strategy::Mutex) {
+            push_error(&mut failures, diagnostic::error_spanned(&target.typ, Code::StdFeatureRequired, format!("Forwarding through Mutex for '{}' requires `std` feature of auto-trait, as it locks `std::sync::Mutex`", diagnostic::display(&args))));
+            continue 'targets;
+        }
+        let mut generics = generics.clone();
+        if let Some(keyword) = keyword {
+            //Moving out of `Box` requires `Sized`
+            let sized = matches!(keyword, Keyword::Box) && input.items.iter().zip(method_options.iter()).any(|(item, method_options)| match item {
+                syn::TraitItem::Fn(method) => Receiver::new(&method.sig) == Receiver::Owned && method_options.skip.is_none() && method_options.body_for(&written.typ).is_none(),
+                _ => false,
+            });
+            let bound = match keyword {
+                Keyword::Fn => match strategy::fn_bound(&input, &options.core_path()) {
+                    Ok(bound) => bound,
+                    Err(error) => { push_error(&mut failures, error); continue 'targets; },
+                },
+                _ => syn::parse_quote!(#trait_path),
+            };
+            keyword.bind(&mut generics, bound, sized, &options.core_path());
+        }
+        //Elements of tuple, which are plain parameters, are implied to be bound by the trait
+        if let (Strategy::FanOut(_), syn::Type::Tuple(tuple)) = (&strategy, args) {
+            for param in generics.type_params_mut() {
+                let is_element = tuple.elems.iter().any(|elem| matches!(elem, syn::Type::Path(elem) if elem.qself.is_none() && elem.path.is_ident(&param.ident)));
+                if is_element && param.bounds.is_empty() {
+                    param.bounds.push(syn::parse_quote!(#trait_path));
+                }
+            }
+        }
+        //Bounds to the trait are written without trait's arguments
+        if let Some(ref arguments) = trait_arguments {
+            let predicates = generics.where_clause.iter_mut().flat_map(|where_clause| where_clause.predicates.iter_mut()).filter_map(|predicate| match predicate {
+                syn::WherePredicate::Type(predicate) => Some(&mut predicate.bounds),
+                _ => None,
+            });
+            let params = generics.params.iter_mut().filter_map(|param| match param {
+                syn::GenericParam::Type(param) => Some(&mut param.bounds),
+                _ => None,
+            });
+            for bound in params.chain(predicates).flat_map(|bounds| bounds.iter_mut()) {
+                if let syn::TypeParamBound::Trait(bound) = bound {
+                    if let Some(segment) = bound.path.segments.last_mut() {
+                        if segment.ident == trait_name && segment.arguments.is_empty() {
+                            segment.arguments = syn::PathArguments::AngleBracketed(arguments.clone());
+                        }
+                    }
+                }
+            }
+        }
+        if options.stub.is_some() && reference.is_none() {
+            let conflict = match (keyword, inner, &options.test) {
+                (Some(_), _, _) => Some("keyword target"),
+                (_, Some(_), _) => Some("explicitly specified type to forward to"),
+                (_, _, Some(_)) => Some("`test` option"),
+                _ => None,
+            };
+            if let Some(conflict) = conflict {
+                push_error(&mut failures, diagnostic::error_spanned(args, Code::StubConflict, format!("`stub` option cannot be used with {}", conflict)));
+                continue 'targets;
+            }
+        }
+        if let (Some(forward), Some(_), None) = (&options.forward, inner, reference) {
+            push_error(&mut failures, diagnostic::error_spanned(forward, Code::ForwardConflict, "`forward` option cannot be used with explicitly specified type to forward to"));
+            continue 'targets;
+        }
+
+        let deref_type = match options.forward.clone() {
+            _ if matches!(strategy, Strategy::Match | Strategy::FanOut(_)) || inner.is_some() => None,
+            Some(forward) => {
+                let forward = renames.iter().find(|rename| !rename.lifetime && rename.from == forward).map(|rename| rename.to.clone()).unwrap_or(forward);
+                match generics.type_params().find(|param| param.ident == forward) {
+                    Some(_) => Some(forward),
+                    None => { push_error(&mut failures, diagnostic::error_spanned(&forward, Code::ForwardNotParameter, format!("'{}' is not a type parameter of target", forward))); continue 'targets; },
+                }
+            },
+            None => {
+                let mut deref_type = None;
+                let predicates = generics.where_clause.iter().flat_map(|where_clause| where_clause.predicates.iter()).filter_map(|predicate| match predicate {
+                    syn::WherePredicate::Type(predicate) => match predicate.bounded_ty {
+                        syn::Type::Path(ref typ) => typ.path.get_ident().filter(|ident| generics.type_params().any(|param| param.ident == **ident)).map(|ident| (ident, &predicate.bounds)),
+                        _ => None,
+                    },
+                    _ => None,
+                });
+                for (ident, bounds) in generics.type_params().map(|param| (&param.ident, &param.bounds)).chain(predicates) {
+                    for bound in bounds.iter() {
+                        if let syn::TypeParamBound::Trait(bound) = bound {
+                            if bound.path == trait_path || bound.path.segments.last().map(|segment| segment.ident == trait_name).unwrap_or(false) {
+                                match deref_type.replace(ident.clone()) {
+                                    Some(previous) if previous != *ident => {
+                                        let mut error = diagnostic::error_spanned(ident, Code::MultipleBounds, format!("Parameters '{}' and '{}' are both bound by trait '{}', select parameter to forward to with `forward = {}` option", previous, ident, trait_name, previous));
+                                        error.combine(diagnostic::note_spanned(&previous, format!("'{}' is bound by trait '{}' as well", previous, trait_name)));
+                                        push_error(&mut failures, error);
+                                        continue 'targets;
+                                    },
+                                    _ => (),
+                                }
+                            }
+                        }
+                    }
+                }
+                deref_type
+            },
+        };
+
+        //Parameter bound by the trait is forwarded to, otherwise implementation would call itself
+        let is_bound_for_target = deref_type.is_some() || options.generics.is_some() || options.bounds.iter().any(|predicate| match predicate {
+            syn::WherePredicate::Type(predicate) => predicate.bounds.iter().any(|bound| matches!(bound, syn::TypeParamBound::Trait(bound) if bound.path.segments.last().map(|segment| segment.ident == trait_name).unwrap_or(false))),
+            _ => false,
+        });
+        if let (None, None, false, Some(param)) = (keyword, inner, is_bound_for_target, generics.type_params().next()) {
+            if !matches!(strategy, Strategy::Match | Strategy::Stub(_) | Strategy::FanOut(_)) {
+                if let Some(error) = mismatched_bound(&generics, &trait_name) {
+                    push_error(&mut failures, error);
+                    continue 'targets;
+                }
+                push_error(&mut failures, diagnostic::error_spanned(&param.ident, Code::UnboundTarget, format!("None of parameters of '{}' is bound by trait '{}', bind exactly one parameter to forward to, e.g. `{}: {}`, or specify type to forward to with `=> <type>`", diagnostic::display(&args), trait_name, param.ident, trait_name)));
+                continue 'targets;
+            }
+        }
+
+        //Parameter, which is not required to be `Sized`, is forwarded to only by reference
+        let strategy = match (strategy, &deref_type) {
+            (Strategy::Deref, Some(deref_type)) if is_maybe_unsized(&generics, deref_type) => Strategy::Unsized,
+            (strategy, _) => strategy,
+        };
+
+        //Type, which associated items are forwarded to
+        let core = options.core_path();
+        let item_type = match (inner, &deref_type, &strategy) {
+            (Some(inner), _, _) => Some(quote! {
+                #inner
+            }),
+            (None, Some(deref_type), _) => Some(quote! {
+                #deref_type
+            }),
+            (None, None, Strategy::Deref) if options.via.is_some() => {
+                let via = &options.via;
+                Some(quote::quote_spanned! {proc_macro2::Span::call_site().located_at(syn::spanned::Spanned::span(args))=>
+                    <#args as #via>::Target
+                })
+            },
+            (None, None, Strategy::Deref) if options.deref.is_none() => Some(quote::quote_spanned! {proc_macro2::Span::call_site().located_at(syn::spanned::Spanned::span(args))=>
+                <#args as #core::ops::Deref>::Target
+            }),
+            _ => None,
+        };
+
+        //Qualified by type to forward to, as parameter's other bounds can have methods with the same name, otherwise type is inferred from the argument
+        let deref_name = match (&item_type, &strategy) {
+            (Some(item_type), _) => quote! {
+                <#item_type as #trait_path>
+            },
+            (None, Strategy::Pointer(_)) => quote! {
+                <<#args as #core::ops::Deref>::Target as #trait_path>
+            },
+            (None, _) => quote! {
+                <_ as #trait_path>
+            },
+        };
+
+        //Owned `self` is converted into parameter bound by the trait, which is named to avoid ambiguity of `Into`
+        let forward_target = match (inner, &deref_type) {
+            (None, Some(deref_type)) => Target {
+                inner: Some(syn::parse_quote!(#deref_type)),
+                ..target.clone()
+            },
+            _ => target.clone(),
+        };
+
+        //`TransparentWrapper` is generic over wrapped type, which cannot be inferred from the trait alone
+        if let (Some(span), None, None, Strategy::Deref) = (options.transparent, &forward_target.inner, &options.via, &strategy) {
+            push_error(&mut failures, diagnostic::error(span, Code::TransparentWithoutTarget, format!("`via = transparent` requires type to forward to for '{}', specify type it wraps with `=> <type>`, e.g. `#[auto_trait({} => Inner, via = transparent)]`", diagnostic::display(&args), diagnostic::display(&args))));
+            continue 'targets;
+        }
+
+        //Methods forwarded by helper of `shared` option require target to be converted into value to forward to
+        let shares = helper.is_some() && options.shared;
+        if shares {
+            if let Err(error) = shared::check(&strategy, args, item_type.as_ref(), options) {
+                push_error(&mut failures, error);
+                continue 'targets;
+            }
+        }
+
+        //Items are written into single stream each, rather than collected and interpolated, as large traits have hundreds of them
+        let mut items = proc_macro2::TokenStream::new();
+        let mut methods = proc_macro2::TokenStream::new();
+        let mut forwarded = Vec::new();
+        //Receivers of methods forwarded by helper, which target is to be converted for
+        let mut receivers = Vec::new();
+        //Errors, which are reported for every method at once
+        let mut errors = None;
+        //Items left with their default implementation, which are reported with `strict` option
+        let mut defaulted = Vec::new();
+
+        'items: for ((item, method_options), analyzed) in input.items.iter().zip(method_options.iter()).zip(analyzed.iter()) {
+            match item {
+                syn::TraitItem::Fn(_) if method_options.skip.is_some() => continue,
+                syn::TraitItem::Fn(ref method) => {
+                    let analyzed = match analyzed {
+                        Some(analyzed) => analyzed,
+                        None => continue,
+                    };
+                    if let Some(filter) = options.excluded_by(method) {
+                        if method.default.is_none() {
+                            let error = diagnostic::error_spanned(&method.sig, Code::FilteredWithoutDefault, format!("Method '{}' is excluded by `{}` filter, but has no default implementation", method.sig.ident, filter));
+                            push_error(&mut errors, error);
+                        } else if filter != "only" && filter != "except" {
+                            defaulted.push(format!("'{}' is excluded by `{}` filter", method.sig.ident, filter));
+                        }
+                        continue;
+                    }
+
+                    if let (Some((flag, span)), Some(keyword)) = (reference, keyword) {
+                        let receiver = Receiver::new(&method.sig);
+                        let compatible = match receiver {
+                            Receiver::Ref | Receiver::None => true,
+                            Receiver::Mut => *keyword == Keyword::Mut,
+                            Receiver::Owned | Receiver::Pin { .. } => false,
+                        };
+                        if !compatible {
+                            if method.default.is_none() {
+                                let error = diagnostic::error(*span, Code::ReferenceIncompatible, format!("Cannot forward {} '{}' for reference to '{}', required by `{}` option, provide default implementation", receiver.describe(), method.sig.ident, diagnostic::display(&inner), flag));
+                                push_error(&mut errors, error);
+                            } else {
+                                defaulted.push(format!("{} '{}' cannot be forwarded for reference, required by `{}` option", receiver.describe(), method.sig.ident, flag));
+                            }
+                            continue;
+                        }
+                    }
+
+                    let overridden = method_options.body_for(&written.typ).is_some();
+                    let (sig, body) = match method_options.body_for(&written.typ) {
+                        Some(body) => (&method.sig, quote!(#body)),
+                        None => {
+                            //Function without receiver is called on type, which cannot be inferred, so it is never called on the trait itself
+                            let callee = match (Receiver::new(&method.sig), &deref_type, &item_type) {
+                                (Receiver::None, None, Some(item_type)) if inner.is_none() => quote! {
+                                    <#item_type as #trait_path>
+                                },
+                                (Receiver::None, None, None) if inner.is_none() && matches!(strategy, Strategy::Deref | Strategy::Unsized | Strategy::Field(_)) => match method.default {
+                                    Some(_) => {
+                                        defaulted.push(format!("function without receiver '{}' has no type to forward to", method.sig.ident));
+                                        continue;
+                                    },
+                                    None => { push_error(&mut errors, diagnostic::error_spanned(&method.sig, Code::RecursiveFunction, format!("Cannot forward function without receiver '{}' for '{}', as it would call itself, specify type to forward to with `=> <type>` or provide default implementation", method.sig.ident, diagnostic::display(&args)))); continue; },
+                                },
+                                _ => deref_name.clone(),
+                            };
+                            //Parameters gated by `#[cfg]` are passed only by body, gated the same way
+                            let mut bodies = proc_macro2::TokenStream::new();
+                            let shares = shares && shared::is_shared(method, method_options, options);
+                            for (predicate, variant) in analyzed.variants.iter() {
+                                let body = match strategy.body(&trait_path, &forward_target, &callee, variant, options, method_options) {
+                                    //Body is written by helper, once strategy is known to forward method
+                                    Ok(Forward::Body(body)) => {
+                                        let body = match shares {
+                                            true => shared::call(&trait_name, &variant.sig),
+                                            false => body,
+                                        };
+                                        //Restated trait's methods can be written by other macro than its targets, e.g. forwarder generated by `AutoWrapper`
+                                        match (emit_trait, variant.sig.receiver()) {
+                                            (false, Some(receiver)) => strategy::resolve_self(body, receiver.self_token.span),
+                                            _ => body,
+                                        }
+                                    },
+                                    Ok(Forward::Default(reason)) => {
+                                        defaulted.push(format!("'{}' is not forwarded, as {}", method.sig.ident, reason));
+                                        continue 'items;
+                                    },
+                                    Err(error) => { push_error(&mut errors, error); continue 'items; },
+                                };
+                                let body = match method_options.map_return.as_ref().or(options.map_return.as_ref()) {
+                                    Some(map_return) if !matches!(strategy, Strategy::Stub(_)) => quote::quote_spanned! {syn::spanned::Spanned::span(map_return)=>
+                                        #map_return(#body)
+                                    },
+                                    _ => body,
+                                };
+                                let body = match options.instrument {
+                                    Some(args::Instrument::OnCall(ref on_call)) if !matches!(strategy, Strategy::Stub(_)) => {
+                                        let trait_name = syn::ext::IdentExt::unraw(&trait_name).to_string();
+                                        let name = syn::ext::IdentExt::unraw(&method.sig.ident).to_string();
+                                        quote::quote_spanned! {syn::spanned::Spanned::span(on_call)=>
+                                            #on_call(#trait_name, #name);
+                                            #body
+                                        }
+                                    },
+                                    _ => body,
+                                };
+                                //Hooks are passed name of the method, while result of forwarded call is returned as it is
+                                //Unit isn't bound, as binding of `()` is reported by `unit_bindings` lint
+                                let body = match options.hooks {
+                                    Some(ref hooks) if !matches!(strategy, Strategy::Stub(_)) && strategy::is_unit(&method.sig.output) => {
+                                        let name = syn::ext::IdentExt::unraw(&method.sig.ident).to_string();
+                                        quote::quote_spanned! {syn::spanned::Spanned::span(hooks)=>
+                                            #hooks::before(#name);
+                                            {
+                                                #body
+                                            };
+                                            #hooks::after(#name);
+                                        }
+                                    },
+                                    Some(ref hooks) if !matches!(strategy, Strategy::Stub(_)) => {
+                                        let name = syn::ext::IdentExt::unraw(&method.sig.ident).to_string();
+                                        let result = hygienic("__result", syn::spanned::Spanned::span(hooks));
+                                        quote::quote_spanned! {syn::spanned::Spanned::span(hooks)=>
+                                            #hooks::before(#name);
+                                            let #result = {
+                                                #body
+                                            };
+                                            #hooks::after(#name);
+                                            #result
+                                        }
+                                    },
+                                    _ => body,
+                                };
+                                match predicate {
+                                    Some(predicate) => bodies.extend(quote! {
+                                        #[cfg(#predicate)]
+                                        {
+                                            #body
+                                        }
+                                    }),
+                                    None => bodies.extend(body),
+                                }
+                            }
+                            if method.default.is_some() {
+                                diagnostic::warning(method.sig.ident.span(), || format!("Default implementation of method '{}' is overridden for '{}', add `#[auto_trait(skip)]` to keep it", method.sig.ident, diagnostic::display(&args)));
+                            }
+                            forwarded.push(method);
+                            if shares && !receivers.contains(&Receiver::new(&method.sig)) {
+                                receivers.push(Receiver::new(&method.sig));
+                            }
+                            (&analyzed.sig, quote! {
+                                {
+                                    #bodies
+                                }
+                            })
+                        },
+                    };
+
+                    //Forwarding call is trivial, hence it is hinted to be inlined across crates, as direct call would be
+                    let inline = match (options.inline, analyzed.inline) {
+                        (_, true) => None,
+                        (Some(inline), false) => Some(inline.attribute()),
+                        (None, false) if overridden || matches!(strategy, Strategy::Stub(_)) => None,
+                        (None, false) => Some(args::Inline::Hint.attribute()),
+                    };
+                    //Attribute wraps body of async method as well, as it is applied to the method, rather than to forwarded call
+                    let instrument = match (&options.instrument, &strategy) {
+                        (_, Strategy::Stub(_)) => None,
+                        (Some(args::Instrument::Tracing(ref tracing)), _) => Some(match sig.receiver() {
+                            Some(_) => quote!(#[#tracing::instrument(skip(self))]),
+                            None => quote!(#[#tracing::instrument]),
+                        }),
+                        _ => None,
+                    };
+                    //Blanket implementation leaves every method to be specialized by implementation for specific wrapper
+                    let defaultness = options.specialization.as_ref().map(|_| quote!(default));
+                    let attrs = &analyzed.attrs;
+                    methods.extend(quote! {
+                        #(#attrs)*
+                        #inline
+                        #instrument
+                        #defaultness #sig #body
+                    });
+                },
+                syn::TraitItem::Const(_) | syn::TraitItem::Type(_) if method_options.skip.is_some() => continue,
+                syn::TraitItem::Const(item) => {
+                    let name = &item.ident;
+                    let typ = &item.ty;
+                    let value = match (&method_options.value, &item_type) {
+                        (Some(value), _) => quote! {
+                            #value
+                        },
+                        (None, Some(item_type)) => quote! {
+                            <#item_type as #trait_path>::#name
+                        },
+                        (None, None) if item.default.is_some() => {
+                            defaulted.push(format!("associated const '{}' has no type to forward to", name));
+                            continue;
+                        },
+                        (None, None) => { push_error(&mut errors, diagnostic::error_spanned(item, Code::ConstWithoutTarget, format!("Cannot forward associated const '{}' for '{}', specify type to forward to with `=> <type>` or override it with `#[auto_trait(value = <expr>)]`", name, diagnostic::display(&args)))); continue; },
+                    };
+                    let allow = allow_deprecated(&item.attrs);
+                    items.extend(quote! {
+                        #allow
+                        const #name: #typ = #value;
+                    });
+                },
+                syn::TraitItem::Type(item) => {
+                    let name = &item.ident;
+                    let (_, ty_generics, where_clause) = item.generics.split_for_impl();
+                    let generics = &item.generics;
+                    let typ = match item_type {
+                        Some(ref item_type) => quote! {
+                            <#item_type as #trait_path>::#name #ty_generics
+                        },
+                        None if item.default.is_some() => {
+                            defaulted.push(format!("associated type '{}' has no type to forward to", name));
+                            continue;
+                        },
+                        None => { push_error(&mut errors, diagnostic::error_spanned(item, Code::TypeWithoutTarget, format!("Cannot forward associated type '{}' for '{}', specify type to forward to with `=> <type>`", name, diagnostic::display(&args)))); continue; },
+                    };
+                    let allow = allow_deprecated(&item.attrs);
+                    items.extend(quote! {
+                        #allow
+                        type #name #generics = #typ #where_clause;
+                    });
+                },
+                //Items defined by macro are left with their default implementation
+                syn::TraitItem::Macro(_) if method_options.skip.is_some() => continue,
+                syn::TraitItem::Macro(item) => {
+                    let path = &item.mac.path;
+                    push_error(&mut errors, diagnostic::error_spanned(item, Code::MacroItem, format!("Cannot forward items defined by macro invocation `{}!`, as it is not expanded yet, define them within trait or skip it with `#[auto_trait(skip)]`, if they have default implementation", diagnostic::display(&path))));
+                    continue;
+                },
+                unsupported => { push_error(&mut errors, diagnostic::error_spanned(unsupported, Code::UnsupportedItem, "Unsupported item within trait, expected method, associated const, type or macro invocation")); continue; },
+
+            }
+        }
+
+        if let (Some(span), false) = (options.strict, defaulted.is_empty()) {
+            let error = diagnostic::error(span, Code::Strict, format!("Items of trait '{}' are left with their default implementation for '{}', which is disallowed by `strict` option: {}", trait_name, diagnostic::display(&args), defaulted.join("; ")));
+            push_error(&mut errors, error);
+        }
+
+        if let Some(errors) = errors {
+            push_error(&mut failures, errors);
+            continue 'targets;
+        }
+
+        //Methods taking reference are forwarded through `Deref`, which must agree with type to forward to
+        let derefs = forwarded.iter().any(|method| match Receiver::new(&method.sig) {
+            Receiver::Ref => options.deref.is_none(),
+            Receiver::Mut => options.deref_mut.is_none(),
+            _ => false,
+        });
+        if let (Some(inner), Strategy::Deref, true) = (inner, &strategy, derefs) {
+            let core = options.core_path();
+            generics.make_where_clause().predicates.push(syn::parse_quote_spanned! {syn::spanned::Spanned::span(args)=>
+                #args: #core::ops::Deref<Target = #inner>
+            });
+        }
+        //Blanket implementation applies to any type, hence it requires `DerefMut`, whenever it forwards methods taking `&mut self`
+        let derefs_mut = options.deref_mut.is_none() && forwarded.iter().any(|method| Receiver::new(&method.sig) == Receiver::Mut);
+        if let (Some(_), Strategy::Deref, true) = (&options.specialization, &strategy, derefs_mut) {
+            let core = options.core_path();
+            generics.make_where_clause().predicates.push(syn::parse_quote_spanned! {syn::spanned::Spanned::span(args)=>
+                #args: #core::ops::DerefMut
+            });
+        }
+        //Methods taking `self` by value are forwarded through `Into`, which is required only when there are such methods
+        let converts = options.into.is_none() && forwarded.iter().any(|method| Receiver::new(&method.sig) == Receiver::Owned);
+        if let (Some(inner), Strategy::Deref, true) = (&forward_target.inner, &strategy, converts) {
+            let core = options.core_path();
+            generics.make_where_clause().predicates.push(syn::parse_quote_spanned! {syn::spanned::Spanned::span(args)=>
+                #args: #core::convert::Into<#inner>
+            });
+        }
+
+        //Functions without receiver, which return `Self`, construct it from type to forward to
+        let constructs = forwarded.iter().any(|method| Receiver::new(&method.sig) == Receiver::None && strategy::construct(&method.sig.output).is_some());
+        if let (Some(inner), true) = (&forward_target.inner, constructs) {
+            let core = options.core_path();
+            generics.make_where_clause().predicates.push(syn::parse_quote_spanned! {syn::spanned::Spanned::span(args)=>
+                #args: #core::convert::From<#inner>
+            });
+        }
+
+        //Trait of `via` option is required to agree with type to forward to, as `TransparentWrapper` is to wrap it
+        let delegates = (options.via.is_some() || options.transparent.is_some()) && matches!(strategy, Strategy::Deref | Strategy::Unsized);
+        if let (Some(inner), true) = (&forward_target.inner, delegates) {
+            let bound = match options.via {
+                Some(ref via) => quote!(#via<Target = #inner>),
+                None => quote!(::bytemuck::TransparentWrapper<#inner>),
+            };
+            generics.make_where_clause().predicates.push(syn::parse_quote_spanned! {syn::spanned::Spanned::span(args)=>
+                #args: #bound
+            });
+        }
+
+        for bound in options.bounds.iter() {
+            match target::Rename::apply_to(&renames, bound) {
+                Ok(bound) => generics.make_where_clause().predicates.push(bound),
+                Err(error) => { push_error(&mut failures, error); continue 'targets; },
+            }
+        }
+
+        if pinned.is_some() {
+            if let Some(ref where_clause) = input.generics.where_clause {
+                for predicate in where_clause.predicates.iter() {
+                    match target::Substitution::apply_to(&substitutions, predicate) {
+                        Ok(predicate) => generics.make_where_clause().predicates.push(predicate),
+                        Err(error) => { push_error(&mut failures, error); continue 'targets; },
+                    }
+                }
+            }
+        } else if !input.generics.params.is_empty() {
+            let where_clause = generics.where_clause.take();
+            let trait_generics = syn::Generics {
+                params: input.generics.params.clone(),
+                ..syn::Generics::default()
+            };
+            generics = target::merge(trait_generics, generics);
+            generics.where_clause = where_clause;
+            if let Some(ref where_clause) = input.generics.where_clause {
+                generics.make_where_clause().predicates.extend(where_clause.predicates.iter().cloned());
+            }
+        }
+
+        let inherent = match (options.inherent, reference) {
+            (Some(span), None) => {
+                let conflict = match (keyword, pinned) {
+                    (Some(_), _) => Some("keyword target"),
+                    (None, None) if !input.generics.params.is_empty() => Some("generic trait, unless its arguments are specified for target"),
+                    _ => None,
+                };
+                if let Some(conflict) = conflict {
+                    push_error(&mut failures, diagnostic::error(span, Code::InherentConflict, format!("`inherent` option cannot be used with {}", conflict)));
+                    continue 'targets;
+                }
+                inherent::methods(&trait_path, &trait_name, &input.vis, &input.items)
+            },
+            _ => Vec::new(),
+        };
+        let inherent = inherent.into_iter().map(|method| target::Substitution::apply(&substitutions, method)).collect::<Vec<_>>();
+        if let Some(ref overridden) = options.generics {
+            generics = overridden.clone();
+        }
+        //Umbrella trait has nothing to forward, hence target itself is required to implement its supertraits, extending trait's blanket implementation to it
+        //Generic target implements it only when supertraits are implemented, while concrete target is reported at target by implementation itself
+        let mut supertraits = Vec::new();
+        for bound in umbrella_supertraits(&input) {
+            match target::Substitution::apply_to(&substitutions, bound) {
+                Ok(bound) => supertraits.push(bound),
+                Err(error) => { push_error(&mut failures, error); continue 'targets; },
+            }
+        }
+        if !supertraits.is_empty() && !generics.params.is_empty() {
+            generics.make_where_clause().predicates.push(syn::parse_quote_spanned! {syn::spanned::Spanned::span(args)=>
+                #args: #(#supertraits)+*
+            });
+        }
+        let items = target::Substitution::apply(&substitutions, items);
+        let methods = target::Substitution::apply(&substitutions, methods);
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let fallible = match (&options.try_trait, reference) {
+            (Some(try_trait), None) if input.generics.params.is_empty() => match fallible::implementation(try_trait, args, &generics, &deref_name, &strategy, options, &try_methods) {
+                Some(Ok(fallible)) => Some(fallible),
+                Some(Err(error)) => { push_error(&mut failures, error); continue 'targets; },
+                None => None,
+            },
+            _ => None,
+        };
+        let conversion = match (&item_type, receivers.is_empty()) {
+            (Some(item_type), false) => match shared::conversions(&trait_path, &forward_target, &strategy, options, &receivers) {
+                Ok(conversions) => {
+                    let module = shared::module(&trait_name);
+                    Some(quote! {
+                        #[automatically_derived]
+                        impl #impl_generics #module::__AutoTraitConvert for #args #where_clause {
+                            type Target = #item_type;
+
+                            #(
+                                #conversions
+                            )*
+                        }
+                    })
+                },
+                Err(error) => { push_error(&mut failures, error); continue 'targets; },
+            },
+            _ => None,
+        };
+        let inherent = match inherent.is_empty() {
+            true => None,
+            false => Some(quote! {
+                #[automatically_derived]
+                impl #impl_generics #args #where_clause {
+                    #(
+                        #inherent
+                    )*
+                }
+            }),
+        };
+        let doc_note = match options.doc_note {
+            true => {
+                let note = match (&strategy, inner, &deref_type) {
+                    (Strategy::Stub(_), _, _) => format!("Automatically generated by auto_trait: stub implementation of {} for {}.", trait_name, diagnostic::display(&args)),
+                    (_, Some(inner), _) => format!("Automatically generated by auto_trait: forwards {} for {} to {} via Into.", trait_name, diagnostic::display(&args), diagnostic::display(&inner)),
+                    (strategy, None, Some(deref_type)) => format!("Automatically generated by auto_trait: forwards {} for {} to {} via {}.", trait_name, diagnostic::display(&args), deref_type, strategy.name()),
+                    (strategy, None, None) => format!("Automatically generated by auto_trait: forwards {} for {} to inner value via {}.", trait_name, diagnostic::display(&args), strategy.name()),
+                };
+                Some(quote! {
+                    #[doc = #note]
+                })
+            },
+            false => None,
+        };
+        let cfg = options.cfg_attribute();
+        let unsafety = match (input.unsafety, options.unsafe_impl) {
+            (Some(_), Some(span)) => Some(span),
+            (Some(unsafety), None) => { push_error(&mut failures, diagnostic::error_spanned(unsafety, Code::UnsafeRequired, format!("Trait '{}' is unsafe, add `unsafe` option to acknowledge that forwarding implementation for '{}' upholds its contract", trait_name, diagnostic::display(&args)))); continue 'targets; },
+            (None, Some(span)) => { push_error(&mut failures, diagnostic::error(span, Code::UnsafeUnneeded, format!("Trait '{}' is not unsafe, remove `unsafe` option", trait_name))); continue 'targets; },
+            (None, None) => None,
+        };
+
+        let const_token = options.const_impl.map(|span| syn::Token![const](span));
+
+        //Concrete type, which is forwarded to, is reported at target, rather than by every forwarded call
+        //`Deref::Target` is asserted only when methods taking reference are forwarded through it
+        let assertion = match (&item_type, &strategy) {
+            (Some(item_type), strategy) if generics.params.is_empty() && !matches!(strategy, Strategy::Stub(_)) && (inner.is_some() || derefs || delegates) => Some(quote::quote_spanned! {proc_macro2::Span::call_site().located_at(syn::spanned::Spanned::span(args))=>
+                const _: fn() = || {
+                    fn assert_impl<T: #trait_path + ?#core::marker::Sized>() {}
+                    assert_impl::<#item_type>();
+                };
+            }),
+            _ => None,
+        };
+
+        //Unsafe implementation is spanned by `unsafe` option, which acknowledges it, so that `unsafe_code` lint reports it as implementation written by hand,
+        //rather than ignoring it within expansion of macro
+        let mut body = proc_macro2::Group::new(proc_macro2::Delimiter::Brace, quote!(#items #methods));
+        if let Some(span) = unsafety {
+            body.set_span(span);
+        }
+        let unsafety = unsafety.map(|span| syn::Token![unsafe](span));
+
+        //Implementation is scoped, so that generated code can have private helpers
+        let result = quote! {
+            #cfg
+            const _: () = {
+                #assertion
+                #doc_note
+                #[automatically_derived]
+                #unsafety impl #impl_generics #const_token #trait_path for #args #where_clause #body
+                #conversion
+                #inherent
+                #fallible
+            };
+        };
+
+        if options.debug || std::env::var_os("AUTO_TRAIT_DEBUG").is_some() {
+            eprintln!("auto_trait: {} for {}:\n{}", trait_name, quote!(#args), result);
+        }
+
+        if options.dry_run {
+            continue;
+        }
+
+        match options.module {
+            Some(ref module) => {
+                let name = quote::format_ident!("{}_{}", module, snake_case(&trait_name));
+                match modules.iter_mut().find(|(module, _)| *module == name) {
+                    Some((_, impls)) => impls.push(result),
+                    None => modules.push((name, vec![result])),
+                }
+            },
+            None => impls.push(result),
+        }
+
+        if let (Some(ref test), None) = (&options.test, reference) {
+            impls.push(equivalence::tests(test, &trait_path, &trait_name, index, &forwarded, cfg.as_ref(), &options.core_path()));
+        }
+    }
+
+    let mut result = match (emit_trait, constness) {
+        (true, Some(constness)) => {
+            let mut result = emit_const(&input, constness);
+            result.extend(export);
+            result
+        },
+        (true, None) => quote! {
+            #input
+            #export
+        },
+        (false, _) => proc_macro2::TokenStream::new(),
+    };
+    result.extend(newtypes);
+    result.extend(enums);
+    result.extend(impls.drain(..));
+    for (name, impls) in modules {
+        result.extend(quote! {
+            mod #name {
+                use super::*;
+
+                #(
+                    #impls
+                )*
+            }
+        });
+    }
+    (result, failures)
+}
+
+///Adds error to those reported at once
+fn push_error(errors: &mut Option<syn::Error>, error: syn::Error) {
+    match errors {
+        Some(errors) => errors.combine(error),
+        None => *errors = Some(error),
+    }
+}
+
+///Returns parenthesized arguments, e.g. of `Fn(u32) -> u32`, at any nesting level of the type
+fn parenthesized_arguments(typ: &syn::Type) -> Option<&syn::ParenthesizedGenericArguments> {
+    fn in_path(path: &syn::Path) -> Option<&syn::ParenthesizedGenericArguments> {
+        path.segments.iter().find_map(|segment| match segment.arguments {
+            syn::PathArguments::Parenthesized(ref arguments) => Some(arguments),
+            syn::PathArguments::AngleBracketed(ref arguments) => arguments.args.iter().find_map(|argument| match argument {
+                syn::GenericArgument::Type(typ) => parenthesized_arguments(typ),
+                _ => None,
+            }),
+            syn::PathArguments::None => None,
+        })
+    }
+
+    match typ {
+        syn::Type::Path(typ) => in_path(&typ.path),
+        syn::Type::TraitObject(typ) => typ.bounds.iter().find_map(|bound| match bound {
+            syn::TypeParamBound::Trait(bound) => in_path(&bound.path),
+            _ => None,
+        }),
+        syn::Type::Reference(typ) => parenthesized_arguments(&typ.elem),
+        syn::Type::Slice(typ) => parenthesized_arguments(&typ.elem),
+        syn::Type::Array(typ) => parenthesized_arguments(&typ.elem),
+        syn::Type::Ptr(typ) => parenthesized_arguments(&typ.elem),
+        syn::Type::Paren(typ) => parenthesized_arguments(&typ.elem),
+        syn::Type::Group(typ) => parenthesized_arguments(&typ.elem),
+        syn::Type::Tuple(typ) => typ.elems.iter().find_map(parenthesized_arguments),
+        _ => None,
+    }
+}
+
+///Returns path of target's bound to the trait, which specifies trait's arguments
+fn bound_with_arguments(generics: &syn::Generics, trait_name: &syn::Ident) -> Option<syn::Path> {
+    let predicates = generics.where_clause.iter().flat_map(|where_clause| where_clause.predicates.iter()).filter_map(|predicate| match predicate {
+        syn::WherePredicate::Type(predicate) => Some(&predicate.bounds),
+        _ => None,
+    });
+    let mut bounds = generics.type_params().map(|param| &param.bounds).chain(predicates).flat_map(|bounds| bounds.iter());
+    bounds.find_map(|bound| match bound {
+        syn::TypeParamBound::Trait(bound) => match bound.path.segments.last() {
+            Some(segment) if segment.ident == *trait_name && !segment.arguments.is_empty() => Some(bound.path.clone()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+///Returns error for type named after the trait or its supertrait, which is trait object written without `dyn`, e.g. `Box<Lolka>` of edition 2015
+///
+///Such type cannot be named without `dyn` in edition 2018 or later, hence it is reported before compiler does so within generated implementation.
+fn bare_trait_object(typ: &syn::Type, input: &syn::ItemTrait) -> Option<syn::Error> {
+    let supertraits = input.supertraits.iter().filter_map(|bound| match bound {
+        syn::TypeParamBound::Trait(bound) => bound.path.segments.last().map(|segment| &segment.ident),
+        _ => None,
+    }).collect::<Vec<_>>();
+
+    let mut idents = Vec::new();
+    plain_types(typ, &mut idents);
+    idents.into_iter().find(|ident| **ident == input.ident || supertraits.contains(ident)).map(|ident| match *ident == input.ident {
+        true => diagnostic::error_spanned(ident, Code::BareTraitObject, format!("Trait object '{}' is written without `dyn`, write `dyn {}` to forward to trait object, or bind parameter to forward to, e.g. `T: {}`", ident, ident, ident)),
+        false => diagnostic::error_spanned(ident, Code::BareTraitObject, format!("Trait object '{}' is written without `dyn`, write `dyn {}` instead", ident, ident)),
+    })
+}
+
+///Collects types written as plain identifiers within type, e.g. `Lolka` of `Box<Lolka>`
+fn plain_types<'a>(typ: &'a syn::Type, idents: &mut Vec<&'a syn::Ident>) {
+    match typ {
+        syn::Type::Path(typ) if typ.qself.is_none() => match typ.path.get_ident() {
+            Some(ident) => idents.push(ident),
+            None => for segment in typ.path.segments.iter() {
+                if let syn::PathArguments::AngleBracketed(ref args) = segment.arguments {
+                    for arg in args.args.iter() {
+                        if let syn::GenericArgument::Type(ref typ) = arg {
+                            plain_types(typ, idents);
+                        }
+                    }
+                }
+            },
+        },
+        syn::Type::Reference(typ) => plain_types(&typ.elem, idents),
+        syn::Type::Ptr(typ) => plain_types(&typ.elem, idents),
+        syn::Type::Slice(typ) => plain_types(&typ.elem, idents),
+        syn::Type::Array(typ) => plain_types(&typ.elem, idents),
+        syn::Type::Paren(typ) => plain_types(&typ.elem, idents),
+        syn::Type::Group(typ) => plain_types(&typ.elem, idents),
+        syn::Type::Tuple(typ) => typ.elems.iter().for_each(|typ| plain_types(typ, idents)),
+        _ => (),
+    }
+}
+
+///Returns error for target's bound, which looks like the trait, but doesn't match its name
+///
+///Bound is considered to be misspelled, when its name differs from trait's name by case, suffix or couple of characters,
+///while qualified bound outside of `std`, `core` and `alloc` can be trait's re-export.
+fn mismatched_bound(generics: &syn::Generics, trait_name: &syn::Ident) -> Option<syn::Error> {
+    let predicates = generics.where_clause.iter().flat_map(|where_clause| where_clause.predicates.iter()).filter_map(|predicate| match predicate {
+        syn::WherePredicate::Type(predicate) => Some(&predicate.bounds),
+        _ => None,
+    });
+    let bounds = generics.type_params().map(|param| &param.bounds).chain(predicates).flat_map(|bounds| bounds.iter()).filter_map(|bound| match bound {
+        syn::TypeParamBound::Trait(bound) if matches!(bound.modifier, syn::TraitBoundModifier::None) => bound.path.segments.last().map(|segment| (&bound.path, &segment.ident)),
+        _ => None,
+    }).filter(|(_, ident)| *ident != trait_name).collect::<Vec<_>>();
+
+    let expected = trait_name.to_string().to_lowercase();
+    let misspelled = bounds.iter().find(|(_, ident)| {
+        let name = ident.to_string().to_lowercase();
+        let (shorter, longer) = match name.len() < expected.len() {
+            true => (&name, &expected),
+            false => (&expected, &name),
+        };
+        (shorter.len() >= 3 && longer.ends_with(shorter.as_str())) || edit_distance(&name, &expected) <= core::cmp::max(1, expected.len() / 3)
+    });
+    if let Some((path, ident)) = misspelled {
+        return Some(diagnostic::error_spanned(path, Code::MisspelledBound, format!("Bound '{}' doesn't match trait '{}', did you mean `{}`?", ident, trait_name, trait_name)));
+    }
+
+    let reexport = bounds.iter().find(|(path, _)| path.segments.len() > 1 && !path.segments.first().map(|segment| segment.ident == "std" || segment.ident == "core" || segment.ident == "alloc").unwrap_or(false));
+    reexport.map(|(path, _)| {
+        let name = diagnostic::display(path);
+        diagnostic::error_spanned(path, Code::MismatchedBound, format!("Bound `{}` does not textually match trait '{}', use `trait_path = {}` option if it is re-export of the trait", name, trait_name, name))
+    })
+}
+
+///Returns supertraits of trait without items of its own, which are to be implemented by target
+fn umbrella_supertraits(input: &syn::ItemTrait) -> Vec<&syn::TraitBound> {
+    if !input.items.is_empty() {
+        return Vec::new();
+    }
+
+    input.supertraits.iter().filter_map(|bound| match bound {
+        syn::TypeParamBound::Trait(bound) if matches!(bound.modifier, syn::TraitBoundModifier::None) => Some(bound),
+        _ => None,
+    }).collect()
+}
+
+///Returns whether type parameter has `?Sized` bound
+fn is_maybe_unsized(generics: &syn::Generics, ident: &syn::Ident) -> bool {
+    let predicates = generics.where_clause.iter().flat_map(|where_clause| where_clause.predicates.iter()).filter_map(|predicate| match predicate {
+        syn::WherePredicate::Type(predicate) => match predicate.bounded_ty {
+            syn::Type::Path(ref typ) if typ.path.is_ident(ident) => Some(&predicate.bounds),
+            _ => None,
+        },
+        _ => None,
+    });
+    let mut bounds = generics.type_params().filter(|param| param.ident == *ident).map(|param| &param.bounds).chain(predicates).flat_map(|bounds| bounds.iter());
+    bounds.any(|bound| matches!(bound, syn::TypeParamBound::Trait(bound) if matches!(bound.modifier, syn::TraitBoundModifier::Maybe(_))))
+}
+
+///Returns path to `alloc` crate, which keyword targets refer to, `::std` unless `std` feature is disabled
+///
+///Crate without `std` is to declare `extern crate alloc;`, as `alloc` is not in its prelude.
+fn alloc_path() -> proc_macro2::TokenStream {
+    match cfg!(feature = "std") {
+        true => extern_crate("std", proc_macro2::Span::call_site()),
+        false => extern_crate("alloc", proc_macro2::Span::call_site()),
+    }
+}
+
+///Returns absolute path to extern crate, e.g. `::core`, which refers to it within crate of any edition
+///
+///Leading `::` refers to crate root within crate of edition 2015, hence it is spanned by the macro, which is of later edition.
+pub(crate) fn extern_crate(name: &str, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+    let name = syn::Ident::new(name, span);
+    let mut colon = proc_macro2::Punct::new(':', proc_macro2::Spacing::Joint);
+    colon.set_span(proc_macro2::Span::mixed_site().located_at(span));
+    let mut colon2 = proc_macro2::Punct::new(':', proc_macro2::Spacing::Alone);
+    colon2.set_span(proc_macro2::Span::mixed_site().located_at(span));
+    quote!(#colon #colon2 #name)
+}
+
+///Returns identifier of binding, which is introduced by generated code located at `span`
+///
+///Binding is resolved at definition site of the macro, hence it neither shadows nor is shadowed by user's identifier with the same name,
+///while local variables of generated code are reached only through interpolation of the returned identifier.
+fn hygienic(name: &str, span: proc_macro2::Span) -> syn::Ident {
+    syn::Ident::new(name, proc_macro2::Span::mixed_site().located_at(span))
+}
+
+///Converts trait's name to snake case, e.g. `LolkaMut` to `lolka_mut`
+fn snake_case(ident: &syn::Ident) -> String {
+    let mut result = String::new();
+    //Raw identifier is named without its prefix, as it is joined with module's name
+    for (idx, ch) in syn::ext::IdentExt::unraw(ident).to_string().chars().enumerate() {
+        if ch.is_uppercase() {
+            if idx > 0 && !result.ends_with('_') {
+                result.push('_');
+            }
+            result.extend(ch.to_lowercase());
+        } else {
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::macros::auto_trait as expand;
+
+    use quote::quote;
+
+    ///Returns implementation within scope of generated item
+    fn scoped_impl(item: syn::Item) -> Option<syn::ItemImpl> {
+        let item = match item {
+            syn::Item::Const(item) => item,
+            _ => return None,
+        };
+
+        match *item.expr {
+            syn::Expr::Block(block) => block.block.stmts.into_iter().find_map(|stmt| match stmt {
+                syn::Stmt::Item(syn::Item::Impl(item)) => Some(item),
+                _ => None,
+            }),
+            _ => None,
+        }
+    }
+
+    fn expand_impl(args: proc_macro2::TokenStream, input: proc_macro2::TokenStream) -> syn::ItemImpl {
+        let output: syn::File = syn::parse2(expand(args, input)).expect("valid output");
+        output.items.into_iter().find_map(scoped_impl).expect("to generate impl")
+    }
+
+    fn method_attrs(item: &syn::ItemImpl) -> Vec<String> {
+        item.items.iter().map(|item| match item {
+            syn::ImplItem::Fn(method) => {
+                let attrs = &method.attrs;
+                quote!(#(#attrs)*).to_string()
+            },
+            _ => String::new(),
+        }).collect()
+    }
+
+    #[test]
+    fn should_annotate_methods_with_inline() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+
+                #[inline(never)]
+                fn lolka_mut(&mut self) -> u32;
+            }
+        };
+
+        let item = expand_impl(quote!(Box<T: Lolka>, inline), input.clone());
+        assert_eq!(method_attrs(&item), ["# [inline]", "# [inline (never)]"]);
+
+        let item = expand_impl(quote!(Box<T: Lolka>, inline(always)), input.clone());
+        assert_eq!(method_attrs(&item), ["# [inline (always)]", "# [inline (never)]"]);
+
+        let item = expand_impl(quote!(Box<T: Lolka>, inline(never)), input.clone());
+        assert_eq!(method_attrs(&item), ["# [inline (never)]", "# [inline (never)]"]);
+
+        //Forwarding methods are hinted by default, so that they are inlined across crates
+        let item = expand_impl(quote!(Box<T: Lolka>), input);
+        assert_eq!(method_attrs(&item), ["# [inline]", "# [inline (never)]"]);
+    }
+
+    #[test]
+    fn should_require_conversion_only_for_owned_methods() {
+        let predicates = |input| {
+            let item = expand_impl(quote!(Box<T: Lolka>), input);
+            let where_clause = item.generics.where_clause;
+            quote!(#where_clause).to_string()
+        };
+
+        let owned = predicates(quote! {
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+                fn lolka_self(self) -> u32;
+            }
+        });
+        assert!(owned.contains(":: core :: convert :: Into < T >"), "{}", owned);
+
+        let borrowed = predicates(quote! {
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+                #[auto_trait(skip)]
+                fn lolka_skip(self) -> u32 where Self: Sized {
+                    0
+                }
+            }
+        });
+        assert!(!borrowed.contains("Into"), "{}", borrowed);
+    }
+
+    #[test]
+    fn should_keep_absolute_path_of_target() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let self_type = |args| {
+            let item = expand_impl(args, input.clone());
+            let typ = item.self_ty;
+            quote!(#typ).to_string()
+        };
+        assert_eq!(self_type(quote!(::std::boxed::Box<T: Lolka>)), ":: std :: boxed :: Box < T >");
+        assert_eq!(self_type(quote!(::std::boxed::Box::<T: Lolka>)), ":: std :: boxed :: Box < T >");
+        assert_eq!(self_type(quote!(::my_crate::Wrapper)), ":: my_crate :: Wrapper");
+    }
+
+    #[test]
+    fn should_translate_cfg_attr_into_cfg() {
+        let input = quote! {
+            #[cfg_attr(feature = "alloc", auto_trait(Box<T: Lolka>), doc = "Lolka")]
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let output: syn::File = syn::parse2(expand(quote!(Rc<T: Lolka>), input)).expect("valid output");
+        let attrs = output.items.iter().map(|item| match item {
+            syn::Item::Trait(item) => &item.attrs,
+            syn::Item::Const(item) => &item.attrs,
+            _ => unreachable!(),
+        }).map(|attrs| quote!(#(#attrs)*).to_string()).collect::<Vec<_>>();
+        assert_eq!(attrs, [r#"# [cfg_attr (feature = "alloc" , doc = "Lolka")]"#, "", r#"# [cfg (feature = "alloc")]"#]);
+    }
+
+    #[test]
+    fn should_apply_target_options_only_to_target() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let output: syn::File = syn::parse2(expand(quote!(Box<T: Lolka>; inline(always), Rc<T: Lolka>, inline), input)).expect("valid output");
+        let attrs = output.items.into_iter().filter_map(scoped_impl).map(|item| method_attrs(&item)).collect::<Vec<_>>();
+        assert_eq!(attrs, [["# [inline (always)]"], ["# [inline]"]]);
+    }
+
+    #[test]
+    fn should_not_copy_docs_to_impl_methods() {
+        let input = quote! {
+            pub trait Lolka {
+                ///Lolka
+                #[doc(alias = "kek")]
+                #[must_use]
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let item = expand_impl(quote!(Box<T: Lolka>), input);
+        assert_eq!(method_attrs(&item), ["# [must_use] # [inline]"]);
+    }
+
+    #[test]
+    fn should_mark_impl_automatically_derived() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let item = expand_impl(quote!(Box<T: Lolka>), input);
+        let attrs = &item.attrs;
+        assert_eq!(quote!(#(#attrs)*).to_string(), "# [automatically_derived]");
+    }
+
+    #[test]
+    fn should_remove_only_auto_trait_attributes() {
+        let input = quote! {
+            #[auto_trait(Rc<T: Lolka>)]
+            #[doc = "Lolka"]
+            #[auto_trait(Arc<T: Lolka>)]
+            #[serde(rename = "kek")]
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let output: syn::File = syn::parse2(expand(quote!(Box<T: Lolka>), input)).expect("valid output");
+        let attrs = output.items.iter().find_map(|item| match item {
+            syn::Item::Trait(item) => Some(&item.attrs),
+            _ => None,
+        }).expect("to emit trait");
+        assert_eq!(quote!(#(#attrs)*).to_string(), r#"# [doc = "Lolka"] # [serde (rename = "kek")]"#);
+        assert_eq!(output.items.into_iter().filter_map(scoped_impl).count(), 3);
+    }
+
+    #[test]
+    fn should_emit_trait_and_valid_impls_alongside_errors() {
+        let input = quote! {
+            #[auto_trait(Rc; unknown_option)]
+            #[auto_trait(for<T> std::vec::Vec<T>, Arc<T: Lolka>)]
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let output: syn::File = syn::parse2(expand(quote!(Box<T: Lolka>), input)).expect("valid output");
+        let errors = output.items.iter().filter(|item| matches!(item, syn::Item::Macro(item) if item.mac.path.segments.last().map(|segment| segment.ident == "compile_error").unwrap_or(false))).count();
+        assert_eq!(errors, 2);
+        let trait_item = output.items.iter().find_map(|item| match item {
+            syn::Item::Trait(item) => Some(item),
+            _ => None,
+        }).expect("to emit trait");
+        assert!(trait_item.attrs.is_empty());
+        let targets = output.items.into_iter().filter_map(scoped_impl).map(|item| {
+            let typ = item.self_ty;
+            quote!(#typ).to_string()
+        }).collect::<Vec<_>>();
+        assert_eq!(targets, ["Box < T >", "Arc < T >"]);
+    }
+
+    #[test]
+    fn should_preserve_order_of_remaining_attributes() {
+        let input = quote! {
+            #[doc = "First"]
+            #[auto_trait(Rc<T: Lolka>)]
+            #[cfg_attr(test, auto_trait(Arc<T: Lolka>), allow(dead_code))]
+            #[doc = "Second"]
+            #[auto_trait(Arc<T: Lolka>)]
+            #[rustfmt::skip]
+            #[auto_trait(&)]
+            #[allow(clippy::all)]
+            #[doc = "Third"]
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let output: syn::File = syn::parse2(expand(quote!(Box<T: Lolka>), input)).expect("valid output");
+        let attrs = output.items.iter().find_map(|item| match item {
+            syn::Item::Trait(item) => Some(&item.attrs),
+            _ => None,
+        }).expect("to emit trait");
+        assert_eq!(quote!(#(#attrs)*).to_string(), quote! {
+            #[doc = "First"]
+            #[cfg_attr(test, allow(dead_code))]
+            #[doc = "Second"]
+            #[rustfmt::skip]
+            #[allow(clippy::all)]
+            #[doc = "Third"]
+        }.to_string());
+    }
+
+    #[test]
+    fn should_document_impl_with_doc_note() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let doc = |args| {
+            let item = expand_impl(args, input.clone());
+            item.attrs.iter().find_map(|attr| match attr.meta {
+                syn::Meta::NameValue(ref meta) if meta.path.is_ident("doc") => match meta.value {
+                    syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(ref doc), .. }) => Some(doc.value()),
+                    _ => None,
+                },
+                _ => None,
+            })
+        };
+        assert_eq!(doc(quote!(Box<T: Lolka>, doc_note)).as_deref(), Some("Automatically generated by auto_trait: forwards Lolka for Box<T> to T via Deref."));
+        assert_eq!(doc(quote!(self::Wrapper => u32, doc_note)).as_deref(), Some("Automatically generated by auto_trait: forwards Lolka for self::Wrapper to u32 via Into."));
+        assert_eq!(doc(quote!(std::sync::Mutex<T: Lolka>, doc_note)).as_deref(), Some("Automatically generated by auto_trait: forwards Lolka for std::sync::Mutex<T> to T via Mutex."));
+        assert_eq!(doc(quote!(self::NullBackend, stub, doc_note)).as_deref(), Some("Automatically generated by auto_trait: stub implementation of Lolka for self::NullBackend."));
+        assert_eq!(doc(quote!(Box<T: Lolka>)), None);
+    }
+
+    #[test]
+    fn should_select_same_strategy_for_every_spelling_of_builtin_target() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let strategy = |args: proc_macro2::TokenStream| {
+            let item = expand_impl(args.clone(), input.clone());
+            let doc = item.attrs.iter().find_map(|attr| match attr.meta {
+                syn::Meta::NameValue(ref meta) if meta.path.is_ident("doc") => match meta.value {
+                    syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(ref doc), .. }) => Some(doc.value()),
+                    _ => None,
+                },
+                _ => None,
+            }).unwrap_or_else(|| panic!("doc note of {}", args));
+            doc.rsplit(" via ").next().map(str::to_owned)
+        };
+        let spellings = [
+            (vec![quote!(Box<str>), quote!(std::boxed::Box<str>), quote!(alloc::boxed::Box<str>), quote!(::alloc::boxed::Box<str>), quote!(crate::prelude::Box<str>)], "unsized type."),
+            (vec![quote!(Rc<T: Lolka>), quote!(std::rc::Rc<T: Lolka>), quote!(alloc::rc::Rc<T: Lolka>), quote!(::alloc::rc::Rc<T: Lolka>), quote!(crate::prelude::Rc<T: Lolka>)], "Deref."),
+            (vec![quote!(Mutex<T: Lolka>), quote!(std::sync::Mutex<T: Lolka>), quote!(::std::sync::Mutex<T: Lolka>), quote!(crate::prelude::Mutex<T: Lolka>)], "Mutex."),
+            (vec![quote!(RefCell<T: Lolka>), quote!(core::cell::RefCell<T: Lolka>), quote!(std::cell::RefCell<T: Lolka>), quote!(alloc::rc::Rc<::core::cell::RefCell<T: Lolka>>)], "RefCell."),
+            (vec![quote!(Weak<T: Lolka>), quote!(std::rc::Weak<T: Lolka>), quote!(alloc::rc::Weak<T: Lolka>), quote!(alloc::sync::Weak<T: Lolka>)], "Weak."),
+        ];
+        for (targets, expected) in spellings.iter() {
+            for target in targets {
+                assert_eq!(strategy(quote!(#target, doc_note)).as_deref(), Some(*expected), "{}", target);
+            }
+        }
+
+        //User's type of the same name is forwarded through `Deref`
+        assert_eq!(strategy(quote!(crate::sync::Mutex<T: Lolka>, doc_note, no_builtin)).as_deref(), Some("Deref."));
+        assert_eq!(strategy(quote!(crate::Weak<T: Lolka>; no_builtin, doc_note)).as_deref(), Some("Deref."));
+
+        //Keyword is the same, whether it is written by itself or by its path within `std` or `alloc`
+        for keywords in [[quote!(Box), quote!(std::boxed::Box), quote!(alloc::boxed::Box), quote!(::alloc::boxed::Box)], [quote!(Rc), quote!(std::rc::Rc), quote!(alloc::rc::Rc), quote!(::std::rc::Rc)], [quote!(Arc), quote!(std::sync::Arc), quote!(alloc::sync::Arc), quote!(::alloc::sync::Arc)], [quote!(Box<dyn>), quote!(std::boxed::Box<dyn>), quote!(alloc::boxed::Box<dyn>), quote!(::alloc::boxed::Box<dyn>)]] {
+            let expected = expand(keywords[0].clone(), input.clone()).to_string();
+            for keyword in keywords.iter() {
+                assert_eq!(expand(keyword.clone(), input.clone()).to_string(), expected, "{}", keyword);
+            }
+        }
+    }
+
+    #[test]
+    fn should_emit_only_trait_and_errors_in_dry_run() {
+        let input = quote! {
+            #[auto_trait(for<T> std::vec::Vec<T>, dry_run)]
+            pub trait Lolka {
+                fn lolka_ref(&self) -> u32;
+            }
+        };
+
+        let output: syn::File = syn::parse2(expand(quote!(Box<T: Lolka>, Wrapper => u32, dry_run), input)).expect("valid output");
+        assert!(output.items.iter().all(|item| !matches!(item, syn::Item::Const(_))));
+        let errors = output.items.iter().filter(|item| matches!(item, syn::Item::Macro(item) if item.mac.path.segments.last().map(|segment| segment.ident == "compile_error").unwrap_or(false))).count();
+        assert_eq!(errors, 1);
+        assert!(output.items.iter().any(|item| matches!(item, syn::Item::Trait(_))));
+    }
+
+    #[test]
+    fn should_gate_implementations_by_test_target() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+            }
+        };
+
+        let output: syn::File = syn::parse2(expand(quote!(Wrapper => u32, test_target = Mock), input)).expect("valid output");
+        let cfgs = output.items.iter().filter_map(|item| match item {
+            syn::Item::Const(item) => item.attrs.iter().find(|attr| attr.path().is_ident("cfg")).map(|attr| quote!(#attr).to_string()),
+            _ => None,
+        }).collect::<Vec<_>>();
+        assert_eq!(cfgs, ["# [cfg (not (test))]", "# [cfg (test)]"]);
+        let test_impl = output.items.into_iter().filter_map(scoped_impl).nth(1).expect("test implementation");
+        let test_impl = quote!(#test_impl).to_string();
+        assert!(test_impl.contains("< Mock as Lolka > :: lolka (:: core :: convert :: AsRef :: < Mock > :: as_ref (self) ,)"), "{}", test_impl);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn should_instrument_forwarding_methods() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+                async fn lolka_async(&self) -> u32;
+                fn lolka_new() -> u32;
+            }
+        };
+
+        let output: syn::File = syn::parse2(expand(quote!(self::Wrapper => u32, instrument = my::tracing), input)).expect("valid output");
+        let item = output.items.into_iter().find_map(scoped_impl).expect("implementation");
+        assert_eq!(method_attrs(&item), ["# [my :: tracing :: instrument (skip (self))]", "# [my :: tracing :: instrument (skip (self))]", "# [my :: tracing :: instrument]"]);
+        let item = quote!(#item).to_string();
+        assert!(item.contains(":: core :: ops :: Deref :: deref (self) ,) . await"), "{}", item);
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    #[test]
+    fn should_require_tracing_feature_to_instrument() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+            }
+        };
+
+        let output = expand(quote!(Box<T: Lolka>, instrument), input).to_string();
+        assert!(output.contains("[auto_trait::tracing_feature_required]"), "{}", output);
+    }
+
+    #[cfg(not(feature = "const-impl"))]
+    #[test]
+    fn should_require_const_impl_feature() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+            }
+        };
+
+        let output = expand(quote!(Wrapper => u32, field = 0, const_impl), input).to_string();
+        assert!(output.contains("[auto_trait::const_impl_feature_required]"), "{}", output);
+    }
+
+    #[cfg(feature = "const-impl")]
+    #[test]
+    fn should_emit_const_impl() {
+        let input = quote! {
+            pub const unsafe trait Lolka {
+                fn lolka(&self) -> u32;
+                fn lolka_mut(&mut self) -> u32;
+            }
+        };
+
+        //Output cannot be parsed, as syn doesn't parse `const trait`
+        let output = expand(quote!(const_impl, unsafe, Wrapper => u32; field = 0, Rc<T: Lolka>), input).to_string();
+        assert!(output.starts_with("pub const unsafe trait Lolka"), "{}", output);
+        assert!(output.contains("unsafe impl const Lolka for Wrapper"), "{}", output);
+        assert!(output.contains("[auto_trait::const_incompatible] Cannot forward `&self` method 'lolka' for 'Rc < T >' in const implementation through Deref"), "{}", output);
+    }
+
+    #[cfg(not(feature = "specialization"))]
+    #[test]
+    fn should_require_specialization_feature() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+            }
+        };
+
+        let output = expand(quote!(specialization = Meters), input).to_string();
+        assert!(output.contains("[auto_trait::specialization_feature_required]"), "{}", output);
+    }
+
+    #[cfg(feature = "specialization")]
+    #[test]
+    fn should_emit_blanket_implementation_with_default_methods() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+            }
+        };
+
+        let output = expand(quote!(specialization = Meters), input.clone()).to_string();
+        assert!(output.contains("impl < __AutoTrait : ? Sized > Lolka for __AutoTrait where __AutoTrait : :: core :: ops :: Deref < Target = Meters >"), "{}", output);
+        assert!(output.contains("default fn lolka (& self) -> u32"), "{}", output);
+
+        let output = expand(quote!(Wrapper, specialization = Meters), input).to_string();
+        assert!(output.contains("[auto_trait::specialization_conflict]"), "{}", output);
+    }
+
+    #[test]
+    fn should_forward_shared_methods_through_helper() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+                #[auto_trait(call = kek)]
+                fn kek(&self) -> u32;
+            }
+        };
+
+        let output = expand(quote!(Wrapper => u32, shared), input).to_string();
+        assert!(output.contains("mod __lolka_forward"), "{}", output);
+        assert!(output.contains("fn lolka (& self) -> u32 { __lolka_forward :: __AutoTraitForward :: lolka (self ,) }"), "{}", output);
+        assert!(output.contains("impl __lolka_forward :: __AutoTraitConvert for Wrapper"), "{}", output);
+        assert!(output.contains("type Target = u32 ;"), "{}", output);
+        //Method, which calls other function, is forwarded by target itself
+        assert!(output.contains("kek (:: core :: ops :: Deref :: deref (self) ,)"), "{}", output);
+    }
+
+    #[test]
+    fn should_move_field_out_of_newtype() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+                fn into_lolka(self) -> u32 where Self: Sized;
+            }
+        };
+
+        let output = expand(quote!(newtype = Logged), input.clone()).to_string();
+        assert!(output.contains("(& self . 0 ,)"), "{}", output);
+        assert!(output.contains("(self . 0 ,)"), "{}", output);
+        assert!(!output.contains("Logged :: into_inner (self)"), "{}", output);
+
+        //Conversion, which is specified explicitly, is still called
+        let output = expand(quote!(newtype = Logged, into = convert), input).to_string();
+        assert!(output.contains("(convert (self) ,)"), "{}", output);
+    }
+
+    #[test]
+    fn should_prefix_error_with_identifier() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+            }
+        };
+
+        let output = expand(quote!(for<T> Box<T>), input).to_string();
+        assert!(output.contains("[auto_trait::unbound_target] None of parameters"));
+    }
+
+    #[test]
+    fn should_emit_unsafe_impl_only_for_unsafe_trait() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+                fn lolka_mut(&mut self) -> u32;
+            }
+        };
+
+        for args in [quote!(Box<T: Lolka>, Wrapper => u32), quote!(Mutex<T: Lolka>, RefCell<T: Lolka>, lock_mut), quote!(Weak<T: Lolka>, upgrade = default), quote!(newtype = Logged), quote!(Named => u32, field = value, shared), quote!(Wrapper, stub)] {
+            let output = expand(args, input.clone());
+            assert!(!crate::strategy::contains_ident(output.clone(), "unsafe"), "{}", output);
+            assert!(!crate::strategy::contains_ident(output.clone(), "unsafe_code"), "{}", output);
+        }
+
+        let input = quote! {
+            pub unsafe trait Lolka {
+                fn lolka(&self) -> u32;
+            }
+        };
+
+        let output = expand(quote!(Box<T: Lolka>, unsafe), input).to_string();
+        assert!(output.contains("unsafe impl < T : Lolka > Lolka for Box < T >"), "{}", output);
+        assert_eq!(output.matches("unsafe").count(), 2, "{}", output);
+    }
+
+    #[test]
+    fn should_refer_to_core_by_crate_path() {
+        let input = quote! {
+            pub trait Lolka {
+                #[auto_trait(cache)]
+                fn lolka(&self) -> u32;
+            }
+        };
+
+        for args in [quote!(&, &mut, Box, Rc, Arc, fn), quote!(Box<T: Lolka>, Wrapper => u32, test(with = make_wrapper)), quote!(newtype = Logged), quote!(enum = AnyLolka, Wrapper => u32, Box<u32>), quote!(cache = Cached)] {
+            for crate_path in [quote!(crate_path = "my_core", #args), quote!(#args, crate_path = "my_core")] {
+                let output = expand(crate_path, input.clone());
+                assert!(crate::strategy::contains_ident(output.clone(), "my_core"), "{}", output);
+                assert!(!crate::strategy::contains_ident(output.clone(), "core"), "{}", output);
+            }
+        }
+
+        let output = crate::macros::auto_wrapper_derive(quote! {
+            #[auto_wrapper(forwarder = forward_to_wrapper, crate_path = "my_core")]
+            pub struct Wrapper<T: Clone>(T);
+        });
+        assert!(output.to_string().contains("crate_path = \"my_core\""), "{}", output);
+        assert!(!crate::strategy::contains_ident(output.clone(), "core"), "{}", output);
+
+        let output = crate::macros::auto_trait_std(quote!(fmt::Display, fmt::Debug for Wrapper => u32, crate_path = "my_core"));
+        assert!(crate::strategy::contains_ident(output.clone(), "my_core"), "{}", output);
+        assert!(!crate::strategy::contains_ident(output.clone(), "core"), "{}", output);
+    }
+
+
+    #[test]
+    fn should_qualify_forwarded_calls_by_type() {
+        let input = quote! {
+            pub trait Lolka {
+                fn lolka(&self) -> u32;
+                fn lolka_mut(&mut self) -> u32;
+            }
+        };
+
+        for args in [quote!(&mut, Box, Box<dyn>), quote!(Wrapper, Box<u32>), quote!(enum = AnyLolka, Wrapper => u32, Box<u32>), quote!(Either, dispatch = match), quote!(Mutex<T: Lolka>, lock_mut), quote!(Wrapper, via = Delegate), quote!(Named, field = value)] {
+            let output = expand(args, input.clone()).to_string();
+            assert!(!output.contains("compile_error"), "{}", output);
+            assert!(!output.contains("Lolka :: lolka"), "{}", output);
+        }
+
+        let output = expand(quote!(enum = AnyLolka, Wrapper => u32, Box<u32>), input).to_string();
+        assert!(output.contains("Self :: Wrapper (__inner) => < Wrapper as Lolka > :: lolka (__inner ,)"), "{}", output);
+    }
+
+    #[test]
+    fn should_display_tokens_without_spacing() {
+        use crate::diagnostic::display;
+
+        assert_eq!(display(quote!(Box<T>)), "Box<T>");
+        assert_eq!(display(quote!(::std::vec::Vec<Vec<T>>)), "::std::vec::Vec<Vec<T>>");
+        assert_eq!(display(quote!(Tagged<'static, T: Lolka + Send>)), "Tagged<'static, T: Lolka + Send>");
+        assert_eq!(display(quote!(&'a mut [u8; 3])), "&'a mut [u8; 3]");
+        assert_eq!(display(quote!((A, *const B))), "(A, *const B)");
+        assert_eq!(display(quote!(<T as Lolka>::Output)), "<T as Lolka>::Output");
+        assert_eq!(display(quote!(wrapper!(u32))), "wrapper!(u32)");
+        assert_eq!(display(quote!(Box<dyn Fn(u32) -> u32>)), "Box<dyn Fn(u32) -> u32>");
+        assert_eq!(display(quote!(for<'a> &'a (dyn Lolka + 'a))), "for<'a> &'a (dyn Lolka + 'a)");
+        assert_eq!(display(quote!(Box<T> where T: Iterator<Item = u32>)), "Box<T> where T: Iterator<Item = u32>");
+        assert_eq!(display(quote!(Vec<T>::new)), "Vec<T>::new");
+    }
+}
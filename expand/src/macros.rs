@@ -0,0 +1,279 @@
+//!Entry points of `auto-trait` macros
+//!
+//!Each function takes tokens passed to the macro and returns tokens it expands into, with errors embedded as `compile_error!`,
+//!so that item is still emitted alongside them and its uses don't report errors.
+//!Unlike [expand](../fn.expand.html), failures of separate targets don't prevent implementations for the remaining ones.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::args::{is_attribute, Args};
+use crate::diagnostic::{self, Code};
+use crate::{export, foreign, generate, parse_trait, std_traits, wrapper};
+
+///Expands `#[auto_trait(args)]` attribute of `input` trait
+pub fn auto_trait(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input: syn::ItemTrait = match parse_trait(input.clone(), "auto_trait") {
+        Ok(input) => input,
+        //Item is still emitted, so that its uses don't report errors
+        Err(error) => {
+            let mut result = input;
+            result.extend(diagnostic::to_compile_error(&error));
+            return result;
+        },
+    };
+    match syn::parse2(args) {
+        Ok(args) => generate(args, input, true),
+        //Trait is still emitted with remaining attributes, so that its uses don't report errors
+        Err(error) => {
+            let mut result = generate(Args::default(), input, true);
+            result.extend(diagnostic::to_compile_error(&error));
+            result
+        },
+    }
+}
+
+///Expands `#[auto_trait_default(args)]` attribute of `input` trait
+pub fn auto_trait_default(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut input = match parse_trait(input.clone(), "auto_trait_default") {
+        Ok(input) => input,
+        Err(error) => {
+            let mut result = input;
+            result.extend(diagnostic::to_compile_error(&error));
+            return result;
+        },
+    };
+
+    //First `auto_trait` attribute is expanded instead, picking defaults the same way as when they are written after it
+    let idx = match input.attrs.iter().position(|attr| is_attribute(attr.path(), "auto_trait")) {
+        Some(idx) => idx,
+        None => return diagnostic::to_compile_error(&diagnostic::error_spanned(&input.ident, Code::DefaultWithoutAttribute, "`auto_trait_default` requires trait to have `#[auto_trait(...)]` attribute")),
+    };
+    let attr = input.attrs.remove(idx);
+    input.attrs.push(syn::parse_quote!(#[auto_trait_default(#args)]));
+
+    let args = match Args::from_meta(&attr.meta) {
+        Ok(args) => args,
+        Err(error) => return diagnostic::to_compile_error(&error),
+    };
+    generate(args, input, true)
+}
+
+///Expands `#[derive(AutoTrait)]` of `input` type
+pub fn auto_trait_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse2::<syn::DeriveInput>(input) {
+        Ok(input) => input,
+        Err(error) => return diagnostic::to_compile_error(&error),
+    };
+    match export::derive(&input) {
+        Ok(result) => result,
+        Err(error) => diagnostic::to_compile_error(&error),
+    }
+}
+
+///Expands `#[auto_trait_register(args)]` attribute of `input` type
+pub fn auto_trait_register(args: TokenStream, input: TokenStream) -> TokenStream {
+    //Type is emitted as it is written, whether its implementations are generated or not
+    let mut result = input.clone();
+    match syn::parse2::<syn::DeriveInput>(input).and_then(|item| export::register(args, &item)) {
+        Ok(implementations) => result.extend(implementations),
+        Err(error) => result.extend(diagnostic::to_compile_error(&error)),
+    }
+    result
+}
+
+///Expands `#[derive(AutoWrapper)]` of `input` struct
+pub fn auto_wrapper_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse2::<syn::DeriveInput>(input) {
+        Ok(input) => input,
+        Err(error) => return diagnostic::to_compile_error(&error),
+    };
+    match wrapper::derive(&input) {
+        Ok(result) => result,
+        Err(error) => diagnostic::to_compile_error(&error),
+    }
+}
+
+///Expands `auto_wrap!(input)`
+pub fn auto_wrap(input: TokenStream) -> TokenStream {
+    let input = match syn::parse2::<wrapper::Wrap>(input) {
+        Ok(input) => input,
+        Err(error) => return diagnostic::to_compile_error(&error),
+    };
+    match wrapper::wrap(input) {
+        Ok(result) => result,
+        Err(error) => diagnostic::to_compile_error(&error),
+    }
+}
+
+///Expands `__forward!(input)`, emitted by trait exported with `#[auto_trait(export)]`
+pub fn forward(input: TokenStream) -> TokenStream {
+    match syn::parse2::<export::Forward>(input) {
+        Ok(export::Forward { args, input }) => generate(args, input, false),
+        Err(error) => diagnostic::to_compile_error(&error),
+    }
+}
+
+///Expands `#[complete(args)]` attribute of `input` implementation
+pub fn complete(args: TokenStream, input: TokenStream) -> TokenStream {
+    let implementation = match syn::parse2::<syn::ItemImpl>(input.clone()) {
+        Ok(implementation) => implementation,
+        Err(error) => {
+            let mut result = input;
+            result.extend(diagnostic::to_compile_error(&error));
+            return result;
+        },
+    };
+    match export::complete(args, &implementation) {
+        Ok(result) => result,
+        //Implementation is still emitted, so that its uses don't report errors
+        Err(error) => {
+            let mut result = quote!(#implementation);
+            result.extend(diagnostic::to_compile_error(&error));
+            result
+        },
+    }
+}
+
+///Expands `__complete!(input)`, emitted by trait exported with `#[auto_trait(export)]`
+pub fn completion(input: TokenStream) -> TokenStream {
+    match syn::parse2::<export::Complete>(input) {
+        Ok(input) => export::completion(input),
+        Err(error) => diagnostic::to_compile_error(&error),
+    }
+}
+
+///Expands `auto_trait_impl!(input)`
+pub fn auto_trait_impl(input: TokenStream) -> TokenStream {
+    match syn::parse2::<foreign::ForeignImpl>(input) {
+        Ok(input) => implement_foreign(input),
+        Err(error) => diagnostic::to_compile_error(&error),
+    }
+}
+
+///Expands `auto_trait_std!(input)`
+pub fn auto_trait_std(input: TokenStream) -> TokenStream {
+    let std_traits::StdImpl { traits, args } = match syn::parse2(input) {
+        Ok(input) => input,
+        Err(error) => return diagnostic::to_compile_error(&error),
+    };
+
+    //Signatures refer to `core` by `crate_path` of targets, if any, while errors of targets are reported once they are parsed for each trait
+    let core = match syn::parse2::<Args>(args.clone()) {
+        Ok(args) => args.options.core_path(),
+        Err(_) => crate::extern_crate("core", proc_macro2::Span::call_site()),
+    };
+    let mut result = TokenStream::new();
+    for path in traits {
+        let methods = match std_traits::methods(&path, &core) {
+            Ok(methods) => methods,
+            Err(error) => {
+                result.extend(diagnostic::to_compile_error(&error));
+                continue;
+            },
+        };
+        let name = match path.segments.last() {
+            Some(segment) => &segment.ident,
+            None => continue,
+        };
+
+        //Restated the same way as written for `auto_trait_impl`, while `Self` stands for each trait
+        let args = replace_self(args.clone(), name);
+        match syn::parse2::<foreign::ForeignImpl>(quote!(impl #path for #args #methods)) {
+            Ok(input) => result.extend(implement_foreign(input)),
+            Err(error) => return diagnostic::to_compile_error(&error),
+        }
+    }
+
+    result
+}
+
+///Generates implementations of foreign trait within its own scope
+fn implement_foreign(input: foreign::ForeignImpl) -> TokenStream {
+    let foreign::ForeignImpl { path, args, input } = input;
+    let result = generate(args, input, false);
+
+    //Trait is imported, so that generated implementation can refer to it by name
+    let import = match path.get_ident() {
+        Some(_) => TokenStream::new(),
+        None => quote! {
+            use #path;
+        },
+    };
+
+    quote! {
+        const _: () = {
+            #import
+            #result
+        };
+    }
+}
+
+///Expands `#[auto_trait_all(args)]` attribute of `input` module
+pub fn auto_trait_all(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut input = match syn::parse2::<syn::ItemMod>(input) {
+        Ok(input) => input,
+        Err(error) => return diagnostic::to_compile_error(&error),
+    };
+    let items = match input.content {
+        Some((_, ref mut items)) => items,
+        None => return diagnostic::to_compile_error(&diagnostic::error_spanned(&input, Code::ModuleWithoutContent, "Module must be declared with its content, e.g. `mod traits { ... }`")),
+    };
+
+    for item in items.iter_mut() {
+        let mut trait_item = match item {
+            syn::Item::Trait(trait_item) => trait_item.clone(),
+            _ => continue,
+        };
+
+        let mut skip = false;
+        let mut error = None;
+        trait_item.attrs.retain(|attr| {
+            if !is_attribute(attr.path(), "auto_trait_all") {
+                return true;
+            }
+
+            match attr.parse_args::<syn::Ident>() {
+                Ok(ident) if ident == "skip" => skip = true,
+                _ => error = Some(diagnostic::error_spanned(attr, Code::InvalidAutoTraitAll, "Expected `#[auto_trait_all(skip)]` to opt out of module's attribute")),
+            }
+            false
+        });
+        if let Some(error) = error {
+            return diagnostic::to_compile_error(&error);
+        }
+
+        if skip {
+            *item = syn::Item::Trait(trait_item);
+            continue;
+        }
+
+        let args = replace_self(args.clone(), &trait_item.ident);
+        let args: Args = match syn::parse2(args) {
+            Ok(args) => args,
+            Err(error) => return diagnostic::to_compile_error(&error),
+        };
+
+        *item = syn::Item::Verbatim(generate(args, trait_item, true));
+    }
+
+    quote!(#input)
+}
+
+///Replaces `Self` with trait's name
+fn replace_self(tokens: TokenStream, name: &syn::Ident) -> TokenStream {
+    tokens.into_iter().map(|token| match token {
+        proc_macro2::TokenTree::Ident(ref ident) if ident == "Self" => {
+            //Name is cloned rather than created from string, which would panic for raw identifier
+            let mut name = name.clone();
+            name.set_span(ident.span());
+            proc_macro2::TokenTree::Ident(name)
+        },
+        proc_macro2::TokenTree::Group(group) => {
+            let mut replaced = proc_macro2::Group::new(group.delimiter(), replace_self(group.stream(), name));
+            replaced.set_span(group.span());
+            proc_macro2::TokenTree::Group(replaced)
+        },
+        token => token,
+    }).collect()
+}
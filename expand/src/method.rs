@@ -0,0 +1,373 @@
+//!Method attributes parsing
+//!
+//!Trait methods and associated items can be annotated with `#[auto_trait(...)]` to adjust how they are forwarded.
+//!These attributes are consumed by the macro and never emitted.
+
+use syn::parse::ParseStream;
+use syn::Token;
+
+use crate::args::{is_attribute, CfgAttr};
+use crate::diagnostic::{self, Code};
+use crate::strategy::{contains_ident, Receiver};
+
+///Replaces patterns of method's arguments with plain identifiers, so that arguments can be passed to forwarded call
+///
+///Identifier patterns are stripped of `ref` and `mut`, while other patterns are replaced with `__arg<index>`,
+///which is hygienic, so that it never collides with user's parameter of the same name.
+///Receiver is stripped of `mut` as well, as forwarded call never mutates it.
+pub fn normalize_args(sig: &syn::Signature) -> syn::Signature {
+    let mut sig = sig.clone();
+    for (idx, arg) in sig.inputs.iter_mut().enumerate() {
+        if let syn::FnArg::Receiver(receiver) = arg {
+            //`mut` of `&mut self` is part of its type
+            if receiver.reference.is_none() {
+                receiver.mutability = None;
+            }
+        }
+        if let syn::FnArg::Typed(arg) = arg {
+            let ident = match *arg.pat {
+                syn::Pat::Ident(ref pat) if pat.subpat.is_none() => pat.ident.clone(),
+                _ => crate::hygienic(&format!("__arg{}", idx), syn::spanned::Spanned::span(&arg.pat)),
+            };
+            *arg.pat = syn::Pat::Ident(syn::PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident,
+                subpat: None,
+            });
+        }
+    }
+
+    sig
+}
+
+///Returns variants of signature for every combination of parameters, gated by `#[cfg]`, with predicate enabling the variant
+///
+///Signature without gated parameters is returned as it is, without predicate.
+pub fn cfg_variants(sig: &syn::Signature) -> Vec<(Option<proc_macro2::TokenStream>, syn::Signature)> {
+    let gated = sig.inputs.iter().enumerate().filter_map(|(idx, arg)| match arg {
+        syn::FnArg::Typed(arg) => {
+            let predicates = arg.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).filter_map(|attr| attr.meta.require_list().ok()).map(|list| &list.tokens).collect::<Vec<_>>();
+            match predicates.is_empty() {
+                true => None,
+                false => Some((idx, quote::quote!(all(#(#predicates),*)))),
+            }
+        },
+        syn::FnArg::Receiver(_) => None,
+    }).collect::<Vec<_>>();
+
+    if gated.is_empty() {
+        return vec![(None, sig.clone())];
+    }
+
+    (0..1usize << gated.len()).map(|mask| {
+        let is_enabled = |bit: usize| mask & (1 << bit) != 0;
+        let predicates = gated.iter().enumerate().map(|(bit, (_, predicate))| match is_enabled(bit) {
+            true => quote::quote!(#predicate),
+            false => quote::quote!(not(#predicate)),
+        });
+        let predicate = quote::quote!(all(#(#predicates),*));
+
+        let mut variant = sig.clone();
+        variant.inputs = sig.inputs.iter().enumerate().filter(|(idx, _)| match gated.iter().position(|(gated, _)| gated == idx) {
+            Some(bit) => is_enabled(bit),
+            None => true,
+        }).map(|(_, arg)| arg.clone()).collect();
+
+        (Some(predicate), variant)
+    }).collect()
+}
+
+///Returns `#[allow(deprecated)]` for deprecated item, so that its deprecation is reported where it is used, rather than by forwarding to it
+pub fn allow_deprecated(attrs: &[syn::Attribute]) -> Option<syn::Attribute> {
+    match attrs.iter().any(|attr| attr.path().is_ident("deprecated")) {
+        true => Some(syn::parse_quote!(#[allow(deprecated)])),
+        false => None,
+    }
+}
+
+///Method of the trait, analyzed once for every target, so that each target only renders its forwarding body
+pub struct Analyzed {
+    ///Signature of implementation, with arguments replaced by plain identifiers
+    pub sig: syn::Signature,
+    ///Method for every combination of `#[cfg]` on its parameters, along with predicate enabling it
+    pub variants: Vec<(Option<proc_macro2::TokenStream>, syn::TraitItemFn)>,
+    ///Attributes of implementation, which repeats neither documentation nor deprecation of the trait
+    pub attrs: Vec<syn::Attribute>,
+    ///Whether method is annotated with `#[inline]` already
+    pub inline: bool,
+}
+
+impl Analyzed {
+    ///Analyzes method, which options are already extracted
+    pub fn new(method: &syn::TraitItemFn) -> Self {
+        let sig = normalize_args(&method.sig);
+        let variants = cfg_variants(&sig).into_iter().map(|(predicate, sig)| (predicate, syn::TraitItemFn {
+            sig,
+            ..method.clone()
+        })).collect();
+        //Documentation belongs to the trait, so it is not repeated by implementation
+        let mut attrs = method.attrs.iter().filter(|attr| !attr.path().is_ident("doc") && !attr.path().is_ident("deprecated")).cloned().collect::<Vec<_>>();
+        attrs.extend(allow_deprecated(&method.attrs));
+        let inline = attrs.iter().any(|attr| attr.path().is_ident("inline"));
+
+        Self {
+            sig,
+            variants,
+            attrs,
+            inline,
+        }
+    }
+}
+
+///Returns description of the method, if its result cannot be cached
+///
+///Result is stored by wrapper, which is generic only over wrapped value, hence its type cannot borrow or refer to `Self`.
+fn cache_incompatibility(sig: &syn::Signature) -> Option<&'static str> {
+    fn is_borrowed(tokens: proc_macro2::TokenStream) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Punct(punct) => punct.as_char() == '&' || punct.as_char() == '\'',
+            proc_macro2::TokenTree::Group(group) => is_borrowed(group.stream()),
+            _ => false,
+        })
+    }
+
+    if Receiver::new(sig) != Receiver::Ref {
+        return Some("method without `&self` receiver");
+    }
+    if sig.inputs.len() > 1 {
+        return Some("method with arguments");
+    }
+    if !sig.generics.params.is_empty() {
+        return Some("generic method");
+    }
+    if sig.asyncness.is_some() {
+        return Some("async method");
+    }
+    match sig.output {
+        syn::ReturnType::Default => Some("method returning `()`"),
+        syn::ReturnType::Type(_, ref typ) => {
+            let typ = quote::quote!(#typ);
+            if contains_ident(typ.clone(), "Self") || contains_ident(typ.clone(), "impl") {
+                Some("method returning `Self` or `impl Trait`")
+            } else if is_borrowed(typ) {
+                Some("method returning borrowed value")
+            } else {
+                None
+            }
+        },
+    }
+}
+
+///Options of `auto_trait` attribute on trait method or associated item
+#[derive(Default)]
+pub struct MethodOptions {
+    ///Span of `skip` option, if item is to be omitted from generated implementations
+    pub skip: Option<proc_macro2::Span>,
+    ///Body to use instead of forwarding call, for every target
+    pub body: Option<syn::Block>,
+    ///Bodies to use instead of forwarding call, for specific targets
+    pub target_bodies: Vec<(syn::Type, syn::Block)>,
+    ///Function to call instead of forwarded method
+    pub call: Option<syn::Path>,
+    ///Value of associated const to use instead of forwarding
+    pub value: Option<syn::Expr>,
+    ///Function to pass result of forwarded call to
+    pub map_return: Option<syn::Path>,
+    ///Span of `cache` option, if method's result is cached by wrapper, generated by `cache` option of attribute
+    pub cache: Option<proc_macro2::Span>,
+}
+
+impl MethodOptions {
+    ///Parses and removes `auto_trait` attributes of the method
+    pub fn extract(method: &mut syn::TraitItemFn) -> syn::Result<Self> {
+        let options = Self::parse_attrs(&mut method.attrs)?;
+
+        if let Some(ref value) = options.value {
+            return Err(diagnostic::error_spanned(value, Code::ValueNotConst, "`value` option can be used only on associated const"));
+        }
+
+        if let Some(skip) = options.skip {
+            if method.default.is_none() {
+                return Err(diagnostic::error(skip, Code::SkipWithoutDefault, format!("Cannot skip method '{}' without default implementation", method.sig.ident)));
+            }
+
+            if options.body.is_some() || !options.target_bodies.is_empty() {
+                return Err(diagnostic::error(skip, Code::SkipConflict, format!("Cannot skip method '{}' with body override", method.sig.ident)));
+            }
+
+            if options.call.is_some() {
+                return Err(diagnostic::error(skip, Code::SkipConflict, format!("Cannot skip method '{}' with `call` option", method.sig.ident)));
+            }
+
+            if options.map_return.is_some() {
+                return Err(diagnostic::error(skip, Code::SkipConflict, format!("Cannot skip method '{}' with `map_return` option", method.sig.ident)));
+            }
+        }
+
+        if let (Some(call), Some(_)) = (&options.call, &options.body) {
+            return Err(diagnostic::error_spanned(call, Code::CallWithBody, format!("Cannot use `call` option for method '{}' with body override for every target", method.sig.ident)));
+        }
+
+        if let Some(cache) = options.cache {
+            if let Some(reason) = cache_incompatibility(&method.sig) {
+                return Err(diagnostic::error(cache, Code::CacheIncompatible, format!("Cannot cache {} '{}', only `&self` methods without arguments and generics, which return owned value, are cached", reason, method.sig.ident)));
+            }
+            if options.skip.is_some() {
+                return Err(diagnostic::error(cache, Code::SkipConflict, format!("Cannot skip method '{}' with `cache` option", method.sig.ident)));
+            }
+        }
+
+        Ok(options)
+    }
+
+    ///Parses and removes `auto_trait` attributes of associated const, type or macro invocation
+    ///
+    ///Macro invocation can be only skipped, as items it defines are unknown.
+    pub fn extract_item(item: &mut syn::TraitItem) -> syn::Result<Self> {
+        let (attrs, kind, name, has_default) = match item {
+            syn::TraitItem::Const(item) => (&mut item.attrs, "const", &item.ident, item.default.is_some()),
+            syn::TraitItem::Type(item) => (&mut item.attrs, "type", &item.ident, item.default.is_some()),
+            syn::TraitItem::Macro(item) => match item.mac.path.segments.last() {
+                Some(segment) => (&mut item.attrs, "macro", &segment.ident, true),
+                None => return Ok(Self::default()),
+            },
+            _ => return Ok(Self::default()),
+        };
+        let options = Self::parse_attrs(attrs)?;
+
+        if let Some(ref body) = options.body.as_ref().or_else(|| options.target_bodies.first().map(|(_, body)| body)) {
+            return Err(diagnostic::error_spanned(body, Code::BodyNotMethod, "`body` option can be used only on methods"));
+        }
+        if let Some(ref call) = options.call {
+            return Err(diagnostic::error_spanned(call, Code::CallNotMethod, "`call` option can be used only on methods"));
+        }
+        if let Some(ref map_return) = options.map_return {
+            return Err(diagnostic::error_spanned(map_return, Code::MapReturnNotMethod, "`map_return` option can be used only on methods"));
+        }
+        if let Some(cache) = options.cache {
+            return Err(diagnostic::error(cache, Code::CacheNotMethod, "`cache` option can be used only on methods"));
+        }
+        if let (Some(ref value), "type" | "macro") = (&options.value, kind) {
+            return Err(diagnostic::error_spanned(value, Code::ValueNotConst, "`value` option can be used only on associated const"));
+        }
+
+        if let Some(skip) = options.skip {
+            if !has_default {
+                return Err(diagnostic::error(skip, Code::SkipWithoutDefault, format!("Cannot skip associated {} '{}' without default", kind, name)));
+            }
+
+            if options.value.is_some() {
+                return Err(diagnostic::error(skip, Code::SkipConflict, format!("Cannot skip associated {} '{}' with `value` option", kind, name)));
+            }
+        }
+
+        Ok(options)
+    }
+
+    fn parse_attrs(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Self> {
+        let mut options = Self::default();
+        let mut result = Ok(());
+
+        //Attributes are removed regardless of errors, so that they are not left on emitted trait
+        attrs.retain(|attr| {
+            if let Some(cfg_attr) = CfgAttr::parse(attr) {
+                if cfg_attr.contains_auto_trait() {
+                    if result.is_ok() {
+                        result = Err(diagnostic::error_spanned(attr, Code::CfgAttrMethodOptions, "Method options cannot be wrapped into `cfg_attr`, use `body(<target>)` override or separate trait instead"));
+                    }
+                    return false;
+                }
+            }
+
+            if !is_attribute(attr.path(), "auto_trait") {
+                return true;
+            }
+
+            if result.is_ok() {
+                result = attr.parse_args_with(|input: ParseStream| options.parse_options(input));
+            }
+            false
+        });
+        result?;
+
+        Ok(options)
+    }
+
+    ///Returns body override for the specified target
+    pub fn body_for(&self, typ: &syn::Type) -> Option<&syn::Block> {
+        match self.target_bodies.iter().find(|(target, _)| target == typ) {
+            Some((_, body)) => Some(body),
+            None => self.body.as_ref(),
+        }
+    }
+
+    fn parse_options(&mut self, input: ParseStream) -> syn::Result<()> {
+        while !input.is_empty() {
+            self.parse_option(input)?;
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(())
+    }
+
+    fn parse_option(&mut self, input: ParseStream) -> syn::Result<()> {
+        let name: syn::Ident = input.parse()?;
+
+        if name == "skip" {
+            self.skip = Some(name.span());
+            Ok(())
+        } else if name == "body" {
+            if input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in input);
+                let typ: syn::Type = content.parse()?;
+                input.parse::<Token![=]>()?;
+                if self.target_bodies.iter().any(|(target, _)| *target == typ) {
+                    return Err(diagnostic::error_spanned(&typ, Code::DuplicateBody, "Duplicate body override for target"));
+                }
+                self.target_bodies.push((typ, input.parse()?));
+            } else {
+                input.parse::<Token![=]>()?;
+                if self.body.is_some() {
+                    return Err(diagnostic::error_spanned(&name, Code::DuplicateBody, "Duplicate body override"));
+                }
+                self.body = Some(input.parse()?);
+            }
+            Ok(())
+        } else if name == "call" {
+            input.parse::<Token![=]>()?;
+            if self.call.is_some() {
+                return Err(diagnostic::error_spanned(&name, Code::DuplicateMethodOption, "Duplicate `call` option"));
+            }
+            self.call = Some(input.parse().map_err(|error| diagnostic::error(error.span(), Code::CallNotPath, "`call` option must be a path to function"))?);
+            Ok(())
+        } else if name == "value" {
+            input.parse::<Token![=]>()?;
+            if self.value.is_some() {
+                return Err(diagnostic::error_spanned(&name, Code::DuplicateMethodOption, "Duplicate `value` option"));
+            }
+            self.value = Some(input.parse()?);
+            Ok(())
+        } else if name == "cache" {
+            if self.cache.replace(name.span()).is_some() {
+                return Err(diagnostic::error_spanned(&name, Code::DuplicateMethodOption, "Duplicate `cache` option"));
+            }
+            Ok(())
+        } else if name == "map_return" {
+            input.parse::<Token![=]>()?;
+            if self.map_return.is_some() {
+                return Err(diagnostic::error_spanned(&name, Code::DuplicateMethodOption, "Duplicate `map_return` option"));
+            }
+            self.map_return = Some(input.parse().map_err(|error| diagnostic::error(error.span(), Code::MapReturnNotPath, "`map_return` option must be a path to function"))?);
+            Ok(())
+        } else {
+            Err(diagnostic::error_spanned(&name, Code::UnknownMethodOption, format!("Unknown method option '{}'", name)))
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//!Generation of helper traits for `shared` option, which write method bodies once for every target
+//!
+//!Targets implement only conversion into value to forward to, while methods are forwarded by helper, implemented for every such conversion.
+//!Helpers are defined within hidden module, so that their methods are never ambiguous with methods of the trait.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::args::{Instrument, Options};
+use crate::method::{allow_deprecated, Analyzed, MethodOptions};
+use crate::strategy::{contains_ident, Forward, Receiver, Strategy};
+use crate::target::{Keyword, Target};
+use crate::diagnostic::{self, Code};
+
+///Returns name of hidden module, which defines helpers of the trait
+pub fn module(trait_name: &syn::Ident) -> syn::Ident {
+    format_ident!("__{}_forward", crate::snake_case(trait_name))
+}
+
+///Returns whether method's signature can be restated by helper, which is generic over target
+///
+///Signature referring to `Self`, except for bounds of `Self` itself, would refer to helper's implementor instead of target.
+pub fn is_shareable(sig: &syn::Signature) -> bool {
+    let inputs = sig.inputs.iter().filter(|arg| matches!(arg, syn::FnArg::Typed(_)));
+    let output = &sig.output;
+    let params = &sig.generics.params;
+    let predicates = sig.generics.where_clause.iter().flat_map(|where_clause| where_clause.predicates.iter()).map(|predicate| match predicate {
+        syn::WherePredicate::Type(syn::PredicateType { bounded_ty: syn::Type::Path(typ), bounds, .. }) if typ.qself.is_none() && typ.path.is_ident("Self") => quote!(#bounds),
+        predicate => quote!(#predicate),
+    });
+    matches!(Receiver::new(sig), Receiver::Ref | Receiver::Mut | Receiver::Owned) && sig.asyncness.is_none() && !contains_ident(quote!(#(#inputs)* #output #params #(#predicates)*), "Self")
+}
+
+///Returns whether method is forwarded by helper for target with `options`
+///
+///Method, which body is altered by options, is forwarded by target itself.
+pub fn is_shared(method: &syn::TraitItemFn, method_options: &MethodOptions, options: &Options) -> bool {
+    let altered = method_options.call.is_some() || method_options.map_return.is_some() || options.map_return.is_some() || options.hooks.is_some() || matches!(options.instrument, Some(Instrument::OnCall(_)));
+    options.shared && !altered && is_shareable(&method.sig)
+}
+
+///Generates helper traits of `input`, forwarding its methods, which can be shared
+pub fn definition(input: &syn::ItemTrait, analyzed: &[Option<Analyzed>], core: &TokenStream) -> TokenStream {
+    let trait_name = &input.ident;
+    let module = module(trait_name);
+    let methods = input.items.iter().zip(analyzed.iter()).filter_map(|(item, analyzed)| match (item, analyzed) {
+        (syn::TraitItem::Fn(method), Some(analyzed)) if is_shareable(&method.sig) => Some((method, analyzed)),
+        _ => None,
+    }).map(|(method, analyzed)| {
+        let receiver = Receiver::new(&method.sig);
+        let conversion = match receiver {
+            Receiver::Ref => quote!(__AutoTraitConvert::convert_ref(self)),
+            Receiver::Mut => quote!(__AutoTraitConvert::convert_mut(self)),
+            _ => quote!(__AutoTraitConvert::convert_owned(self)),
+        };
+        let mut sig = analyzed.sig.clone();
+        if receiver == Receiver::Owned {
+            let where_clause = sig.generics.make_where_clause();
+            where_clause.predicates.push(syn::parse_quote!(Self: #core::marker::Sized));
+            where_clause.predicates.push(syn::parse_quote!(<Self as __AutoTraitConvert>::Target: #core::marker::Sized));
+        }
+        let name = &sig.ident;
+        //Parameters gated by `#[cfg]` are passed only by body, gated the same way
+        let bodies = analyzed.variants.iter().map(|(predicate, variant)| {
+            let args = variant.sig.inputs.iter().filter_map(|arg| match arg {
+                syn::FnArg::Typed(arg) => Some(&arg.pat),
+                syn::FnArg::Receiver(_) => None,
+            });
+            let body = quote! {
+                <<Self as __AutoTraitConvert>::Target as super::#trait_name>::#name(#conversion, #(#args,)*)
+            };
+            match predicate {
+                Some(predicate) => quote! {
+                    #[cfg(#predicate)]
+                    {
+                        #body
+                    }
+                },
+                None => body,
+            }
+        });
+        let cfgs = method.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+        let allow = allow_deprecated(&method.attrs);
+        quote! {
+            #(#cfgs)*
+            #allow
+            #[inline]
+            #sig {
+                #(#bodies)*
+            }
+        }
+    });
+
+    //Conversions are implemented only for receivers of forwarded methods, hence others are never called
+    let message = format!("{} doesn't forward methods with this receiver", trait_name);
+    quote! {
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        mod #module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            pub(super) trait __AutoTraitConvert {
+                type Target: ?#core::marker::Sized + super::#trait_name;
+
+                fn convert_ref(&self) -> &Self::Target {
+                    #core::unreachable!(#message)
+                }
+
+                fn convert_mut(&mut self) -> &mut Self::Target {
+                    #core::unreachable!(#message)
+                }
+
+                fn convert_owned(self) -> Self::Target where Self: #core::marker::Sized, Self::Target: #core::marker::Sized {
+                    #core::unreachable!(#message)
+                }
+            }
+
+            pub(super) trait __AutoTraitForward: __AutoTraitConvert {
+                #(
+                    #methods
+                )*
+            }
+
+            impl<__AutoTrait: ?#core::marker::Sized + __AutoTraitConvert> __AutoTraitForward for __AutoTrait {}
+        }
+    }
+}
+
+///Returns body of method, which calls helper instead of forwarding by itself
+pub fn call(trait_name: &syn::Ident, sig: &syn::Signature) -> TokenStream {
+    let module = module(trait_name);
+    let name = &sig.ident;
+    let args = sig.inputs.iter().filter_map(|arg| match arg {
+        syn::FnArg::Typed(arg) => Some(&arg.pat),
+        syn::FnArg::Receiver(_) => None,
+    });
+    quote! {
+        #module::__AutoTraitForward::#name(self, #(#args,)*)
+    }
+}
+
+///Checks whether target can be converted into value to forward to by `strategy`
+pub fn check(strategy: &Strategy, typ: &syn::Type, item_type: Option<&TokenStream>, options: &Options) -> syn::Result<()> {
+    let span = Span::call_site().located_at(syn::spanned::Spanned::span(typ));
+    let supported = matches!(strategy, Strategy::Deref | Strategy::Unsized | Strategy::Field(_) | Strategy::Guard { .. } | Strategy::Pointer(Keyword::Ref | Keyword::Mut | Keyword::Box | Keyword::Rc | Keyword::Arc));
+    if !supported {
+        return Err(diagnostic::error(span, Code::SharedIncompatible, format!("`shared` option cannot be used for '{}' forwarding through {}, which doesn't convert it into value to forward to, forward it by attribute without `shared` option", diagnostic::display(&typ), strategy.name())));
+    }
+    if let Some(span) = options.const_impl {
+        return Err(diagnostic::error(span, Code::SharedConflict, "`shared` option cannot be used with `const_impl`, as helper forwarding methods is not const"));
+    }
+    match item_type {
+        Some(_) => Ok(()),
+        None => Err(diagnostic::error(span, Code::SharedIncompatible, format!("`shared` option requires type to forward to for '{}', specify it with `=> <type>`", diagnostic::display(&typ)))),
+    }
+}
+
+///Returns conversions of target, which helper forwards methods with `receivers` through
+pub fn conversions(trait_path: &syn::Path, target: &Target, strategy: &Strategy, options: &Options, receivers: &[Receiver]) -> syn::Result<Vec<TokenStream>> {
+    let core = options.core_path();
+    let method_options = MethodOptions {
+        call: Some(syn::parse_quote!(#core::convert::identity)),
+        ..MethodOptions::default()
+    };
+    let mut conversions = Vec::new();
+    for (receiver, method) in [(Receiver::Ref, quote!(fn convert_ref(&self) -> &Self::Target;)), (Receiver::Mut, quote!(fn convert_mut(&mut self) -> &mut Self::Target;)), (Receiver::Owned, quote!(fn convert_owned(self) -> Self::Target where Self: #core::marker::Sized, Self::Target: #core::marker::Sized;))] {
+        if !receivers.contains(&receiver) {
+            continue;
+        }
+        let method: syn::TraitItemFn = syn::parse2(method)?;
+        //Conversion is forwarded as method, which calls identity function instead of trait's method
+        let body = match strategy.body(trait_path, target, &TokenStream::new(), &method, options, &method_options)? {
+            Forward::Body(body) => body,
+            Forward::Default(_) => continue,
+        };
+        let sig = &method.sig;
+        conversions.push(quote! {
+            #[inline]
+            #sig {
+                #body
+            }
+        });
+    }
+    Ok(conversions)
+}
@@ -0,0 +1,109 @@
+//!Bundled signatures of `std` traits for `auto_trait_std`
+//!
+//!Signatures are restated with absolute paths, so that they don't depend on imports at the place of use,
+//!and include provided methods, which implementations commonly specialize, e.g. `read_exact`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::Token;
+
+use crate::diagnostic::{self, Code};
+
+///Paths of supported traits, without crate's name
+const TRAITS: &[&str] = &["io::Read", "io::Write", "io::Seek", "io::BufRead", "fmt::Display", "fmt::Debug", "iter::Iterator", "hash::Hasher"];
+
+///Input of `auto_trait_std` macro: `<traits> for <targets>`
+pub struct StdImpl {
+    ///Paths to traits
+    pub traits: Vec<syn::Path>,
+    ///Targets and options, which are parsed for each trait
+    pub args: TokenStream,
+}
+
+impl Parse for StdImpl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let traits = Punctuated::<syn::Path, Token![,]>::parse_separated_nonempty(input)?;
+        input.parse::<Token![for]>()?;
+
+        Ok(Self {
+            traits: traits.into_iter().collect(),
+            args: input.parse()?,
+        })
+    }
+}
+
+///Returns methods of the trait within braces, or error listing supported traits
+///
+///Trait is matched by its path, which may omit leading segments, e.g. `Read`, `io::Read` or `std::io::Read`,
+///while `core` and `alloc` stand for `std`. Signatures refer to `core` crate by `core`, which honours `crate_path`.
+pub fn methods(path: &syn::Path, core: &TokenStream) -> syn::Result<TokenStream> {
+    let mut segments = path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>();
+    if segments.len() > 1 && (segments[0] == "std" || segments[0] == "core" || segments[0] == "alloc") {
+        segments.remove(0);
+    }
+    let name = segments.join("::");
+    let known = TRAITS.iter().find(|known| **known == name || known.ends_with(&format!("::{}", name))).filter(|_| path.segments.iter().all(|segment| segment.arguments.is_empty()));
+
+    if let Some(known) = known.filter(|known| known.starts_with("io::") && !cfg!(feature = "std")) {
+        return Err(diagnostic::error_spanned(path, Code::StdFeatureRequired, format!("Trait `std::{}` requires `std` feature of auto-trait", known)));
+    }
+
+    let std = crate::extern_crate("std", proc_macro2::Span::call_site());
+    Ok(match known {
+        Some(&"io::Read") => quote!({
+            fn read(&mut self, buf: &mut [u8]) -> #std::io::Result<usize>;
+            fn read_vectored(&mut self, bufs: &mut [#std::io::IoSliceMut<'_>]) -> #std::io::Result<usize>;
+            fn read_to_end(&mut self, buf: &mut #std::vec::Vec<u8>) -> #std::io::Result<usize>;
+            fn read_to_string(&mut self, buf: &mut #std::string::String) -> #std::io::Result<usize>;
+            fn read_exact(&mut self, buf: &mut [u8]) -> #std::io::Result<()>;
+        }),
+        Some(&"io::Write") => quote!({
+            fn write(&mut self, buf: &[u8]) -> #std::io::Result<usize>;
+            fn write_vectored(&mut self, bufs: &[#std::io::IoSlice<'_>]) -> #std::io::Result<usize>;
+            fn flush(&mut self) -> #std::io::Result<()>;
+            fn write_all(&mut self, buf: &[u8]) -> #std::io::Result<()>;
+            fn write_fmt(&mut self, fmt: #core::fmt::Arguments<'_>) -> #std::io::Result<()>;
+        }),
+        Some(&"io::Seek") => quote!({
+            fn seek(&mut self, pos: #std::io::SeekFrom) -> #std::io::Result<u64>;
+            fn rewind(&mut self) -> #std::io::Result<()>;
+            fn stream_position(&mut self) -> #std::io::Result<u64>;
+        }),
+        Some(&"io::BufRead") => quote!({
+            fn fill_buf(&mut self) -> #std::io::Result<&[u8]>;
+            fn consume(&mut self, amt: usize);
+            fn read_until(&mut self, byte: u8, buf: &mut #std::vec::Vec<u8>) -> #std::io::Result<usize>;
+            fn read_line(&mut self, buf: &mut #std::string::String) -> #std::io::Result<usize>;
+        }),
+        Some(&"fmt::Display") | Some(&"fmt::Debug") => quote!({
+            fn fmt(&self, fmt: &mut #core::fmt::Formatter<'_>) -> #core::fmt::Result;
+        }),
+        Some(&"iter::Iterator") => quote!({
+            type Item;
+            fn next(&mut self) -> #core::option::Option<Self::Item>;
+            fn size_hint(&self) -> (usize, #core::option::Option<usize>);
+        }),
+        Some(&"hash::Hasher") => quote!({
+            fn finish(&self) -> u64;
+            fn write(&mut self, bytes: &[u8]);
+            fn write_u8(&mut self, i: u8);
+            fn write_u16(&mut self, i: u16);
+            fn write_u32(&mut self, i: u32);
+            fn write_u64(&mut self, i: u64);
+            fn write_u128(&mut self, i: u128);
+            fn write_usize(&mut self, i: usize);
+            fn write_i8(&mut self, i: i8);
+            fn write_i16(&mut self, i: i16);
+            fn write_i32(&mut self, i: i32);
+            fn write_i64(&mut self, i: i64);
+            fn write_i128(&mut self, i: i128);
+            fn write_isize(&mut self, i: isize);
+        }),
+        _ => {
+            let supported = TRAITS.iter().map(|known| format!("`std::{}`", known)).collect::<Vec<_>>().join(", ");
+            return Err(diagnostic::error_spanned(path, Code::UnknownStdTrait, format!("Trait `{}` has no bundled signatures, supported traits are {}, use `auto_trait_impl!` to restate methods of other traits", diagnostic::display(&path).replace(' ', ""), supported)));
+        },
+    })
+}
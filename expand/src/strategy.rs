@@ -0,0 +1,891 @@
+//!Forwarding strategies
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+use crate::args::{Dispatch, Options, Stub, Upgrade};
+use crate::diagnostic::{self, Code};
+use crate::method::MethodOptions;
+use crate::target::{Keyword, Target};
+
+///Kind of method's receiver
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Receiver {
+    ///`&self`
+    Ref,
+    ///`&mut self`
+    Mut,
+    ///`self`
+    Owned,
+    ///`self: Pin<&Self>` or `self: Pin<&mut Self>`
+    Pin {
+        ///Whether pinned reference is mutable
+        mutable: bool,
+    },
+    ///No receiver
+    None,
+}
+
+impl Receiver {
+    ///Determines receiver kind of the method
+    pub fn new(sig: &syn::Signature) -> Self {
+        match sig.receiver() {
+            Some(arg) => match (&arg.reference, &arg.mutability) {
+                (Some(_), Some(_)) => Receiver::Mut,
+                (Some(_), None) => Receiver::Ref,
+                (None, _) if arg.colon_token.is_some() => match pinned_self(&arg.ty) {
+                    Some(mutable) => Receiver::Pin {
+                        mutable,
+                    },
+                    None => Receiver::Owned,
+                },
+                (None, _) => Receiver::Owned,
+            },
+            None => Receiver::None,
+        }
+    }
+
+    ///Returns description of method with such receiver
+    pub fn describe(self) -> &'static str {
+        match self {
+            Receiver::Ref => "`&self` method",
+            Receiver::Mut => "`&mut self` method",
+            Receiver::Owned => "method taking `self` by value",
+            Receiver::Pin { mutable: false } => "`self: Pin<&Self>` method",
+            Receiver::Pin { mutable: true } => "`self: Pin<&mut Self>` method",
+            Receiver::None => "function without receiver",
+        }
+    }
+}
+
+///Way to reach forwarding target from `self`
+pub enum Strategy {
+    ///Converts using `Deref`, `DerefMut` or `Into` depending on receiver
+    Deref,
+    ///Matches on variants
+    Match,
+    ///Locks `Mutex`, possibly behind smart pointer
+    Mutex,
+    ///Borrows `RefCell`, possibly behind smart pointer
+    RefCell,
+    ///Upgrades `Weak` pointer
+    Weak,
+    ///Dereferences lock guard, `DerefMut` is available only for `mutable` guard
+    Guard {
+        ///Whether guard provides exclusive access
+        mutable: bool,
+    },
+    ///Converts by reference only, as target is unsized or points to unsized type
+    Unsized,
+    ///Blanket implementation for keyword target, provided receiver is compatible with pointer
+    Pointer(Keyword),
+    ///Implementation, which doesn't forward, but panics on use
+    Stub(Stub),
+    ///Calls method on every element of tuple with specified arity, in order
+    FanOut(usize),
+    ///Borrows or moves out field of target
+    Field(syn::Member),
+}
+
+impl Strategy {
+    ///Selects strategy for specified target type
+    pub fn new(target: &Target, options: &Options) -> Self {
+        if let Some(keyword) = target.keyword {
+            return Strategy::Pointer(keyword);
+        }
+
+        if let Some(stub) = options.stub {
+            return Strategy::Stub(stub);
+        }
+
+        if let Some(Dispatch::Match(_)) = options.dispatch {
+            return Strategy::Match;
+        }
+
+        if let Some(ref field) = options.field {
+            return Strategy::Field(field.clone());
+        }
+
+        let typ = &target.typ;
+
+        if let syn::Type::Tuple(ref tuple) = typ {
+            return Strategy::FanOut(tuple.elems.len());
+        }
+
+        if is_unsized(typ) || (!options.no_builtin && pointee(typ).map(is_unsized).unwrap_or(false)) {
+            return Strategy::Unsized;
+        }
+
+        //Built-in targets are recognized by last segment of their path, so that `Rc`, `std::rc::Rc`, `alloc::rc::Rc` and re-export are the same,
+        //unless target is user's type of the same name
+        if options.no_builtin {
+            return Strategy::Deref;
+        }
+
+        match cell_ident(typ) {
+            Some(ident) if ident == "Mutex" => Strategy::Mutex,
+            Some(ident) if ident == "RefCell" => Strategy::RefCell,
+            Some(ident) if ident == "Weak" => Strategy::Weak,
+            Some(ident) if is_target(typ, ident) && MUTABLE_GUARDS.iter().any(|guard| ident == guard) => Strategy::Guard {
+                mutable: true,
+            },
+            Some(ident) if is_target(typ, ident) && SHARED_GUARDS.iter().any(|guard| ident == guard) => Strategy::Guard {
+                mutable: false,
+            },
+            _ => Strategy::Deref,
+        }
+    }
+
+    ///Returns name of the strategy, as used in diagnostics
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::Deref => "Deref",
+            Strategy::Match => "match",
+            Strategy::Mutex => "Mutex",
+            Strategy::RefCell => "RefCell",
+            Strategy::Weak => "Weak",
+            Strategy::Guard { .. } => "guard",
+            Strategy::Unsized => "unsized type",
+            Strategy::Pointer(keyword) => keyword.name(),
+            Strategy::Stub(_) => "stub",
+            Strategy::FanOut(_) => "tuple",
+            Strategy::Field(_) => "field",
+        }
+    }
+
+    ///Generates body of the forwarding method
+    ///
+    ///Returns `Forward::Default` if method is to be left with its default implementation.
+    ///
+    ///`callee` is type, which method is called on.
+    pub fn body(&self, trait_path: &syn::Path, target: &Target, callee: &TokenStream, method: &syn::TraitItemFn, options: &Options, method_options: &MethodOptions) -> syn::Result<Forward> {
+        let inner = target.inner.as_ref();
+        //Generated code points at the target, so that its errors are reported for it, rather than for whole attribute
+        let span = Span::call_site().located_at(target.typ.span());
+        let method_name = &method.sig.ident;
+        //User's `crate_path` keeps its own span, as it can be resolved only there, e.g. `$crate`
+        let core = match options.crate_path {
+            Some(_) => options.core_path(),
+            None => crate::extern_crate("core", span),
+        };
+        let function = match method_options.call {
+            Some(ref call) => quote_spanned! {span=>
+                #call
+            },
+            None => quote_spanned! {span=>
+                #callee::#method_name
+            },
+        };
+        //Forwarded future is awaited by async method, so that it returns the same output
+        let awaited = method.sig.asyncness.map(|_| quote_spanned! {span=>
+            .await
+        });
+        let receiver = Receiver::new(&method.sig);
+        //Arguments are interpolated as they are, so that they keep spans of the method's parameters
+        let method_args = method.sig.inputs.iter().filter_map(|arg| match arg {
+            syn::FnArg::Typed(arg) => Some(&arg.pat),
+            syn::FnArg::Receiver(_) => None,
+        }).collect::<Vec<_>>();
+
+        let trait_name = trait_path.segments.last().map(|segment| &segment.ident);
+        //Error points at target, which requested forwarding, along with method, which cannot be forwarded
+        let method_error = |code: Code, message: String| {
+            let mut error = diagnostic::error(span, code, message);
+            error.combine(diagnostic::note_spanned(&method.sig, format!("`{}::{}` is declared here", diagnostic::display(&trait_name), method_name)));
+            error
+        };
+        let unsupported = || method_error(Code::UnsupportedReceiver, format!("Cannot forward {} '{}' through {}", receiver.describe(), method_name, self.name()));
+
+        let target_type = &target.typ;
+        let target = diagnostic::display(target_type);
+        //Message of generated panic, which is customized by `panic_msg` option
+        //Method is named by the trait, as the error points at target
+        let owned_unavailable = |via: &str| {
+            let target = match self {
+                Strategy::Pointer(keyword) => format!("`{}` target", keyword.name()),
+                _ => format!("'{}'", target),
+            };
+            let mut error = diagnostic::error(span, Code::OwnedUnavailable, format!("Cannot forward `{}::{}`, taking `self` by value, for {}, as there is no owned value to convert into through {}, consider `no_owned` option or default implementation of the method", diagnostic::display(&trait_name), method_name, target, via));
+            error.combine(diagnostic::note_spanned(&method.sig, format!("`{}::{}` takes `self` by value", diagnostic::display(&trait_name), method_name)));
+            error
+        };
+        let panic_message = options.panic_msg.as_ref().map(|message| message.value().replace("{trait}", &diagnostic::display(trait_name)).replace("{method}", &method_name.to_string()).replace("{target}", &target));
+
+        //Const implementation can call only const functions, which are used by field access, `match` and user's conversions
+        if options.const_impl.is_some() {
+            let conversion = match receiver {
+                Receiver::Ref => options.deref.is_some(),
+                Receiver::Mut => options.deref_mut.is_some(),
+                Receiver::Owned => options.into.is_some(),
+                Receiver::Pin { .. } => false,
+                Receiver::None => true,
+            };
+            let const_callable = match self {
+                Strategy::Field(_) | Strategy::Match => true,
+                Strategy::Deref | Strategy::Unsized => conversion,
+                _ => false,
+            };
+            if !const_callable {
+                return Err(method_error(Code::ConstIncompatible, format!("Cannot forward {} '{}' for '{}' in const implementation through {}, which is not const-callable, forward to field with `field = <field>` option or use const function with `deref`, `deref_mut` or `into` option", receiver.describe(), method_name, target, self.name())));
+            }
+            if method.sig.asyncness.is_some() {
+                return Err(method_error(Code::ConstIncompatible, format!("Cannot forward async method '{}' for '{}' in const implementation", method_name, target)));
+            }
+        }
+
+        if let Strategy::Stub(stub) = self {
+            let is_unit = is_unit(&method.sig.output);
+            let message = panic_message.unwrap_or_else(|| format!("{} does not support {}::{}", target, diagnostic::display(&trait_name), method_name));
+            let body = match stub {
+                Stub::Noop if is_unit => quote_spanned! {span=>},
+                _ => quote_spanned! {span=>
+                    #core::unimplemented!("{}", #message)
+                },
+            };
+            //Arguments are consumed to silence unused variables lint
+            return Ok(Forward::Body(quote_spanned! {span=>
+                let _ = (#(#method_args,)*);
+                #body
+            }));
+        }
+
+        //Function is called by the trait's only required method, see `fn_bound`, while others keep their default implementation
+        if let Strategy::Pointer(Keyword::Fn) = self {
+            return match method.default {
+                Some(_) => Ok(Forward::Default("function is called only by required method".to_owned())),
+                None => Ok(Forward::Body(quote_spanned! {span=>
+                    (self)(#(#method_args),*)
+                })),
+            };
+        }
+
+        //Results of elements cannot be combined, hence only methods returning unit are fanned out
+        if let Strategy::FanOut(arity) = self {
+            let reason = match receiver {
+                Receiver::None | Receiver::Pin { .. } => Some(receiver.describe()),
+                _ if !is_unit(&method.sig.output) => Some("method returning value"),
+                _ => None,
+            };
+            if let Some(reason) = reason {
+                return match method.default {
+                    Some(_) => Ok(Forward::Default(format!("{} cannot be fanned out to elements of tuple", reason))),
+                    None => Err(method_error(Code::FanOutIncompatible, format!("Cannot fan out {} '{}' to elements of tuple '{}', as only methods with receiver returning `()` are called on every element, provide default implementation", reason, method_name, target))),
+                };
+            }
+
+            //Arguments are cloned for every element, except the last one, which receives them as they are
+            let calls = (0..*arity).map(|idx| {
+                let element = syn::Index {
+                    index: idx as u32,
+                    span,
+                };
+                let receiver = match receiver {
+                    Receiver::Ref => quote_spanned! {span=>
+                        &self.#element
+                    },
+                    Receiver::Mut => quote_spanned! {span=>
+                        &mut self.#element
+                    },
+                    _ => quote_spanned! {span=>
+                        self.#element
+                    },
+                };
+                let args = method_args.iter().map(|arg| match idx + 1 == *arity {
+                    true => quote!(#arg),
+                    false => quote_spanned! {span=>
+                        #core::clone::Clone::clone(&#arg)
+                    },
+                });
+                quote_spanned! {span=>
+                    #function(#receiver, #(#args,)*)#awaited;
+                }
+            });
+            return Ok(Forward::Body(quote_spanned! {span=>
+                #(#calls)*
+            }));
+        }
+
+        if let Strategy::Pointer(keyword @ (Keyword::DynBox | Keyword::DynRef)) = self {
+            let incompatibility = match (keyword, receiver) {
+                (Keyword::DynRef, Receiver::Mut) => Some((receiver.describe(), "for `&dyn` target")),
+                _ => dyn_incompatibility(&method.sig, receiver).map(|reason| (reason, "through trait object")),
+            };
+            if let Some((reason, target)) = incompatibility {
+                return match method.default {
+                    Some(_) => Ok(Forward::Default(format!("{} cannot be forwarded {}", reason, target))),
+                    None => Err(method_error(Code::DynIncompatibleMethod, format!("Cannot forward {} '{}' {}, provide default implementation", reason, method_name, target))),
+                };
+            }
+        }
+
+        if let (Strategy::Unsized, true) = (self, requires_sized(&method.sig)) {
+            return match method.default {
+                Some(_) => Ok(Forward::Default("method requiring `Self: Sized` cannot be forwarded to unsized type".to_owned())),
+                None => Err(method_error(Code::UnsizedIncompatible, format!("Cannot forward method requiring `Self: Sized` '{}' to unsized type, provide default implementation", method_name))),
+            };
+        }
+
+        //Value borrowed from lock guard or upgraded pointer cannot outlive the call, e.g. boxed future capturing receiver
+        if matches!(self, Strategy::Mutex | Strategy::RefCell | Strategy::Weak) && borrows_receiver(&method.sig) {
+            return match method.default {
+                Some(_) => Ok(Forward::Default(format!("return value, which borrows receiver, cannot outlive temporary created by {}", self.name()))),
+                None => Err(method_error(Code::TemporaryBorrow, format!("Cannot forward {} '{}' through {}, as its return value borrows receiver, which is reached only through temporary, that is dropped at the end of the call, provide default implementation", receiver.describe(), method_name, self.name()))),
+            };
+        }
+
+        //Function without receiver constructs type to forward to, which is converted into `Self` by `From`
+        let constructed = match (receiver, construct(&method.sig.output)) {
+            (Receiver::None, Some(construct)) if matches!(self, Strategy::Deref | Strategy::Guard { .. } | Strategy::Unsized | Strategy::Pointer(_) | Strategy::Field(_)) => match inner {
+                Some(inner) => Some((construct, inner)),
+                None => return match method.default {
+                    Some(_) => Ok(Forward::Default("function returning `Self` has no type to construct from".to_owned())),
+                    None => Err(method_error(Code::ConstructorWithoutTarget, format!("Cannot forward function returning `Self` '{}' for '{}' without type to construct from, specify it with `=> <type>`, e.g. `#[auto_trait({} => Inner)]`, or provide default implementation", method_name, target, target))),
+                },
+            },
+            _ => None,
+        };
+
+        let receiver = match (self, receiver) {
+            (Strategy::Deref, Receiver::None) | (Strategy::Guard { .. }, Receiver::None) | (Strategy::Unsized, Receiver::None) | (Strategy::Pointer(_), Receiver::None) | (Strategy::Field(_), Receiver::None) => quote_spanned! {span=>},
+            (_, Receiver::None) => return Err(unsupported()),
+            //Pinned field is reached through projection, generated by `pin-project` for the target
+            (_, Receiver::Pin { mutable }) => match (&options.pin_project, mutable) {
+                (Some(field), true) => quote_spanned! {span=>
+                    self.project().#field,
+                },
+                (Some(field), false) => quote_spanned! {span=>
+                    self.project_ref().#field,
+                },
+                (None, _) => match method.default {
+                    Some(_) => return Ok(Forward::Default(format!("{} cannot be forwarded without projection", receiver.describe()))),
+                    None => return Err(method_error(Code::PinWithoutProjection, format!("Cannot forward {} '{}' for '{}' without projection, derive `pin_project` for the target and name its pinned field with `pin_project(<field>)` option, or provide default implementation", receiver.describe(), method_name, target))),
+                },
+            },
+            (Strategy::Deref, Receiver::Ref) | (Strategy::Unsized, Receiver::Ref) => match options.deref {
+                Some(ref deref) => quote_spanned! {span=>
+                    #deref(self),
+                },
+                None => quote_spanned! {span=>
+                    #core::ops::Deref::deref(self),
+                },
+            },
+            (Strategy::Deref, Receiver::Mut) | (Strategy::Unsized, Receiver::Mut) => match options.deref_mut {
+                Some(ref deref_mut) => quote_spanned! {span=>
+                    #deref_mut(self),
+                },
+                None => quote_spanned! {span=>
+                    #core::ops::DerefMut::deref_mut(self),
+                },
+            },
+            (Strategy::Field(field), Receiver::Ref) => quote_spanned! {span=>
+                &self.#field,
+            },
+            (Strategy::Field(field), Receiver::Mut) => quote_spanned! {span=>
+                &mut self.#field,
+            },
+            (Strategy::Field(field), Receiver::Owned) => quote_spanned! {span=>
+                self.#field,
+            },
+            (Strategy::Guard { .. }, Receiver::Ref) => quote_spanned! {span=>
+                #core::ops::Deref::deref(self),
+            },
+            (Strategy::Guard { mutable: true }, Receiver::Mut) => quote_spanned! {span=>
+                #core::ops::DerefMut::deref_mut(self),
+            },
+            (Strategy::Deref, Receiver::Owned) => match (inner, &method_options.call) {
+                _ if options.into.is_some() => {
+                    let into = &options.into;
+                    quote_spanned! {span=>
+                        #into(self),
+                    }
+                },
+                //Shared pointer cannot be converted into value it points to
+                (Some(inner), _) if !options.no_builtin && is_shared_pointer(target_type) && pointee(target_type) == Some(inner) => match method.default {
+                    Some(_) => return Ok(Forward::Default(format!("{} cannot be moved out of shared pointer", receiver.describe()))),
+                    None => return Err(owned_unavailable(&last_segment(target_type).map(|segment| segment.ident.to_string()).unwrap_or_default())),
+                },
+                (Some(inner), _) => quote_spanned! {span=>
+                    #core::convert::Into::<#inner>::into(self),
+                },
+                //Function's argument names type to convert into
+                (None, Some(_)) => quote_spanned! {span=>
+                    #core::convert::Into::into(self),
+                },
+                //Conversion is ambiguous, whenever target implements `Into` for several types
+                (None, None) => match method.default {
+                    Some(_) => return Ok(Forward::Default("owned `self` has no type to convert into".to_owned())),
+                    None => return Err(method_error(Code::OwnedWithoutTarget, format!("Cannot forward {} '{}' for '{}' without type to convert into, specify it with `=> <type>`, e.g. `#[auto_trait({} => Inner)]`, or provide default implementation", receiver.describe(), method_name, target, target))),
+                },
+            },
+            (Strategy::Unsized, _) => match method.default {
+                Some(_) => return Ok(Forward::Default(format!("{} cannot be forwarded to unsized type", receiver.describe()))),
+                None if receiver == Receiver::Owned => return Err(owned_unavailable(self.name())),
+                None => return Err(method_error(Code::UnsizedIncompatible, format!("Cannot forward {} '{}' to unsized type, provide default implementation", receiver.describe(), method_name))),
+            },
+            (Strategy::Guard { .. }, _) => match method.default {
+                Some(_) => return Ok(Forward::Default(format!("{} cannot be forwarded through guard", receiver.describe()))),
+                None if receiver == Receiver::Owned => return Err(owned_unavailable(self.name())),
+                None => return Err(method_error(Code::GuardIncompatible, format!("Cannot forward {} '{}' through guard, provide default implementation", receiver.describe(), method_name))),
+            },
+            (Strategy::Pointer(_), Receiver::Ref) => quote_spanned! {span=>
+                #core::ops::Deref::deref(self),
+            },
+            (Strategy::Pointer(Keyword::Mut), Receiver::Mut) | (Strategy::Pointer(Keyword::Box), Receiver::Mut) | (Strategy::Pointer(Keyword::DynBox), Receiver::Mut) => quote_spanned! {span=>
+                #core::ops::DerefMut::deref_mut(self),
+            },
+            (Strategy::Pointer(Keyword::Box), Receiver::Owned) => quote_spanned! {span=>
+                *self,
+            },
+            (Strategy::Pointer(_), Receiver::Owned) => return Err(owned_unavailable(self.name())),
+            (Strategy::Pointer(keyword), _) => {
+                return Err(method_error(Code::KeywordIncompatible, format!("Cannot forward {} '{}' for `{}` target", receiver.describe(), method_name, keyword.name())));
+            },
+            (Strategy::Match, _) => {
+                let variants = match options.dispatch {
+                    Some(Dispatch::Match(ref variants)) => variants.as_slice(),
+                    None => &[],
+                };
+                let method_args = &method_args;
+                let awaited = &awaited;
+                let binding = crate::hygienic("__inner", span);
+                let arms = variants.iter().map(|(variant, typ)| {
+                    //Type of variant's value is inferred from it, unless it is known
+                    let function = match (&method_options.call, typ) {
+                        (Some(call), _) => quote_spanned! {span=>
+                            #call
+                        },
+                        (None, Some(typ)) => quote_spanned! {span=>
+                            <#typ as #trait_path>::#method_name
+                        },
+                        (None, None) => quote_spanned! {span=>
+                            <_ as #trait_path>::#method_name
+                        },
+                    };
+                    quote_spanned! {span=>
+                        Self::#variant(#binding) => #function(#binding, #(#method_args,)*)#awaited,
+                    }
+                });
+                return Ok(Forward::Body(quote_spanned! {span=>
+                    match self {
+                        #(#arms)*
+                    }
+                }));
+            },
+            (Strategy::Mutex, Receiver::Ref) => match panic_message {
+                Some(message) => quote_spanned! {span=>
+                    &*#core::result::Result::expect(::std::sync::Mutex::lock(self), #message),
+                },
+                None => quote_spanned! {span=>
+                    &*#core::result::Result::unwrap(::std::sync::Mutex::lock(self)),
+                },
+            },
+            (Strategy::Mutex, Receiver::Mut) if options.lock_mut => match panic_message {
+                Some(message) => quote_spanned! {span=>
+                    &mut *#core::result::Result::expect(::std::sync::Mutex::lock(self), #message),
+                },
+                None => quote_spanned! {span=>
+                    &mut *#core::result::Result::unwrap(::std::sync::Mutex::lock(self)),
+                },
+            },
+            (Strategy::Mutex, Receiver::Mut) => {
+                return Err(method_error(Code::MutexLockRequired, format!("Cannot forward `&mut self` method '{}' through Mutex, use `lock_mut` option to forward it by locking", method_name)));
+            },
+            (Strategy::RefCell, Receiver::Ref) => match panic_message {
+                Some(message) => quote_spanned! {span=>
+                    &*#core::result::Result::expect(#core::cell::RefCell::try_borrow(self), #message),
+                },
+                None => quote_spanned! {span=>
+                    &*#core::cell::RefCell::borrow(self),
+                },
+            },
+            (Strategy::RefCell, Receiver::Mut) => match panic_message {
+                Some(message) => quote_spanned! {span=>
+                    &mut *#core::result::Result::expect(#core::cell::RefCell::try_borrow_mut(self), #message),
+                },
+                None => quote_spanned! {span=>
+                    &mut *#core::cell::RefCell::borrow_mut(self),
+                },
+            },
+            (Strategy::Weak, Receiver::Ref) => match options.upgrade.unwrap_or_default() {
+                Upgrade::Panic => {
+                    let message = panic_message.unwrap_or_else(|| "Weak pointer is dropped".to_owned());
+                    quote_spanned! {span=>
+                        &*#core::option::Option::expect(Self::upgrade(self), #message),
+                    }
+                },
+                Upgrade::Default => {
+                    let strong = crate::hygienic("__strong", span);
+                    return Ok(Forward::Body(quote_spanned! {span=>
+                        match Self::upgrade(self) {
+                            #core::option::Option::Some(#strong) => #function(&*#strong, #(#method_args,)*)#awaited,
+                            #core::option::Option::None => #core::default::Default::default(),
+                        }
+                    }));
+                },
+            },
+            (Strategy::Mutex, Receiver::Owned) | (Strategy::RefCell, Receiver::Owned) | (Strategy::Weak, Receiver::Owned) => return Err(owned_unavailable(self.name())),
+            (Strategy::Weak, _) | (Strategy::Stub(_), _) | (Strategy::FanOut(_), _) => return Err(unsupported()),
+        };
+
+        let call = quote_spanned! {span=>
+            #function(#receiver #(#method_args,)*)#awaited
+        };
+        Ok(Forward::Body(match constructed {
+            None => call,
+            Some((Construct::Plain, inner)) => quote_spanned! {span=>
+                <Self as #core::convert::From<#inner>>::from(#call)
+            },
+            Some((Construct::Option, inner)) => quote_spanned! {span=>
+                #core::option::Option::map(#call, <Self as #core::convert::From<#inner>>::from)
+            },
+            Some((Construct::Result, inner)) => quote_spanned! {span=>
+                #core::result::Result::map(#call, <Self as #core::convert::From<#inner>>::from)
+            },
+        }))
+    }
+}
+
+///Outcome of forwarding method
+pub enum Forward {
+    ///Body of forwarding method
+    Body(TokenStream),
+    ///Method is left with its default implementation for the described reason
+    Default(String),
+}
+
+///Resolves `self` value within tokens at `receiver`, keeping its location
+///
+///`self` is hygienic, hence body of method, which is restated by other macro than the one writing target, refers to receiver only then.
+pub fn resolve_self(tokens: TokenStream, receiver: proc_macro2::Span) -> TokenStream {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut result = TokenStream::new();
+    while let Some(token) = tokens.next() {
+        let token = match token {
+            //Path starting with `self::` refers to module
+            proc_macro2::TokenTree::Ident(mut ident) if ident == "self" && !matches!(tokens.peek(), Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == ':') => {
+                ident.set_span(receiver.located_at(ident.span()));
+                proc_macro2::TokenTree::Ident(ident)
+            },
+            proc_macro2::TokenTree::Group(group) => {
+                let mut resolved = proc_macro2::Group::new(group.delimiter(), resolve_self(group.stream(), receiver));
+                resolved.set_span(group.span());
+                proc_macro2::TokenTree::Group(resolved)
+            },
+            token => token,
+        };
+        result.extend(Some(token));
+    }
+    result
+}
+
+///Way of wrapping value constructed by function without receiver into `Self`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Construct {
+    ///Function returns `Self`
+    Plain,
+    ///Function returns `Option<Self>`
+    Option,
+    ///Function returns `Result<Self, E>`
+    Result,
+}
+
+///Returns how output of function constructs `Self`, if it does so
+pub fn construct(output: &syn::ReturnType) -> Option<Construct> {
+    let typ = match output {
+        syn::ReturnType::Type(_, ref typ) => &**typ,
+        syn::ReturnType::Default => return None,
+    };
+    let is_self = |typ: &syn::Type| matches!(typ, syn::Type::Path(typ) if typ.qself.is_none() && typ.path.is_ident("Self"));
+    if is_self(typ) {
+        return Some(Construct::Plain);
+    }
+
+    let segment = last_segment(typ)?;
+    let args = match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref args) => &args.args,
+        _ => return None,
+    };
+    match args.first() {
+        Some(syn::GenericArgument::Type(value)) if is_self(value) => (),
+        _ => return None,
+    }
+    match (args.len(), args.iter().nth(1)) {
+        (1, _) if segment.ident == "Option" => Some(Construct::Option),
+        //Error is passed as it is, hence it cannot refer to `Self`
+        (2, Some(error)) if segment.ident == "Result" && !contains_ident(quote!(#error), "Self") => Some(Construct::Result),
+        _ => None,
+    }
+}
+
+///Returns whether method returns `()`, either implicitly or explicitly
+pub fn is_unit(output: &syn::ReturnType) -> bool {
+    match output {
+        syn::ReturnType::Default => true,
+        syn::ReturnType::Type(_, ref typ) => matches!(**typ, syn::Type::Tuple(ref tuple) if tuple.elems.is_empty()),
+    }
+}
+
+const POINTERS: &[&str] = &["Box", "Rc", "Arc"];
+const SHARED_POINTERS: &[&str] = &["Rc", "Arc"];
+const MUTABLE_GUARDS: &[&str] = &["MutexGuard", "RwLockWriteGuard", "RefMut"];
+const SHARED_GUARDS: &[&str] = &["RwLockReadGuard", "Ref"];
+
+fn last_segment(typ: &syn::Type) -> Option<&syn::PathSegment> {
+    match typ {
+        syn::Type::Path(typ) => typ.path.segments.last(),
+        _ => None,
+    }
+}
+
+fn single_type_argument(segment: &syn::PathSegment) -> Option<&syn::Type> {
+    match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => match args.args.first() {
+            Some(syn::GenericArgument::Type(typ)) => Some(typ),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+///Returns type behind smart pointer
+fn pointee(typ: &syn::Type) -> Option<&syn::Type> {
+    let segment = last_segment(typ)?;
+    if POINTERS.iter().any(|pointer| segment.ident == pointer) {
+        single_type_argument(segment)
+    } else {
+        None
+    }
+}
+
+///Returns whether target is `Rc` or `Arc`, which never give away value they point to
+fn is_shared_pointer(typ: &syn::Type) -> bool {
+    last_segment(typ).map(|segment| SHARED_POINTERS.iter().any(|pointer| segment.ident == pointer)).unwrap_or(false)
+}
+
+///Returns identifier of the cell type, either target itself or behind smart pointer.
+fn cell_ident(typ: &syn::Type) -> Option<&syn::Ident> {
+    match pointee(typ).and_then(last_segment) {
+        Some(inner) => Some(&inner.ident),
+        None => last_segment(typ).map(|segment| &segment.ident),
+    }
+}
+
+///Returns whether target is dynamically sized type: `str`, slice or trait object.
+fn is_unsized(typ: &syn::Type) -> bool {
+    match typ {
+        syn::Type::Slice(_) | syn::Type::TraitObject(_) => true,
+        syn::Type::Path(typ) => typ.qself.is_none() && typ.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+///Returns whether `ident` names target itself, rather than type behind smart pointer.
+fn is_target(typ: &syn::Type, ident: &syn::Ident) -> bool {
+    last_segment(typ).map(|segment| segment.ident == *ident).unwrap_or(false)
+}
+
+///Returns mutability of pinned reference, if receiver's type is `Pin<&Self>` or `Pin<&mut Self>`
+fn pinned_self(typ: &syn::Type) -> Option<bool> {
+    let segment = match typ {
+        syn::Type::Path(typ) if typ.qself.is_none() => typ.path.segments.last()?,
+        _ => return None,
+    };
+    let arguments = match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref arguments) if segment.ident == "Pin" && arguments.args.len() == 1 => arguments,
+        _ => return None,
+    };
+    match arguments.args.first() {
+        Some(syn::GenericArgument::Type(syn::Type::Reference(reference))) if matches!(*reference.elem, syn::Type::Path(ref elem) if elem.qself.is_none() && elem.path.is_ident("Self")) => Some(reference.mutability.is_some()),
+        _ => None,
+    }
+}
+
+///Returns whether method's return type borrows receiver, i.e. refers to its lifetime or elides it
+pub fn borrows_receiver(sig: &syn::Signature) -> bool {
+    let lifetime = match sig.receiver().and_then(|receiver| receiver.reference.as_ref()) {
+        Some((_, lifetime)) => lifetime.as_ref().map(|lifetime| &lifetime.ident),
+        None => return false,
+    };
+    match sig.output {
+        syn::ReturnType::Type(_, ref typ) => borrows_lifetime(quote!(#typ), lifetime),
+        syn::ReturnType::Default => false,
+    }
+}
+
+///Returns whether tokens of type refer to `lifetime`, or to elided lifetime, if it's `None`
+fn borrows_lifetime(tokens: TokenStream, lifetime: Option<&syn::Ident>) -> bool {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut previous = None;
+    while let Some(token) = tokens.next() {
+        let borrows = match token {
+            proc_macro2::TokenTree::Punct(ref punct) if punct.as_char() == '\'' => match (tokens.peek(), lifetime) {
+                (Some(proc_macro2::TokenTree::Ident(ident)), Some(lifetime)) => ident == lifetime,
+                (Some(proc_macro2::TokenTree::Ident(ident)), None) => ident == "_",
+                _ => false,
+            },
+            //Reference without lifetime borrows receiver, as it's the only reference among inputs, which output cannot refer to otherwise
+            proc_macro2::TokenTree::Punct(ref punct) if punct.as_char() == '&' && lifetime.is_none() => !matches!(tokens.peek(), Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == '\''),
+            //Arguments of `Fn` traits have their own elided lifetimes
+            proc_macro2::TokenTree::Group(ref group) if group.delimiter() == proc_macro2::Delimiter::Parenthesis && matches!(previous, Some(proc_macro2::TokenTree::Ident(ref ident)) if ident == "Fn" || ident == "FnMut" || ident == "FnOnce") => false,
+            proc_macro2::TokenTree::Group(ref group) => borrows_lifetime(group.stream(), lifetime),
+            _ => false,
+        };
+        if borrows {
+            return true;
+        }
+        previous = Some(token);
+    }
+    false
+}
+
+///Returns whether method has `Self: Sized` bound
+fn requires_sized(sig: &syn::Signature) -> bool {
+    sig.generics.where_clause.iter().flat_map(|where_clause| where_clause.predicates.iter()).any(|predicate| match predicate {
+        syn::WherePredicate::Type(predicate) => matches!(predicate.bounded_ty, syn::Type::Path(ref typ) if typ.path.is_ident("Self")) && predicate.bounds.iter().any(|bound| match bound {
+            syn::TypeParamBound::Trait(bound) => bound.path.segments.last().map(|segment| segment.ident == "Sized").unwrap_or(false),
+            _ => false,
+        }),
+        _ => false,
+    })
+}
+
+///Returns description of the method, if it cannot be called on trait object
+fn dyn_incompatibility(sig: &syn::Signature, receiver: Receiver) -> Option<&'static str> {
+    match receiver {
+        Receiver::None | Receiver::Owned => return Some(receiver.describe()),
+        Receiver::Ref | Receiver::Mut | Receiver::Pin { .. } => (),
+    }
+
+    if requires_sized(sig) {
+        return Some("method requiring `Self: Sized`");
+    }
+
+    signature_incompatibility(sig)
+}
+
+///Returns description of the method's signature, if it prevents method from being called on trait object
+fn signature_incompatibility(sig: &syn::Signature) -> Option<&'static str> {
+    let arguments = sig.inputs.iter().filter_map(|arg| match arg {
+        syn::FnArg::Typed(arg) => Some(&arg.ty),
+        syn::FnArg::Receiver(_) => None,
+    });
+    if sig.generics.type_params().next().is_some() || sig.generics.const_params().next().is_some() || arguments.clone().any(|typ| contains_ident(quote!(#typ), "impl")) {
+        return Some("generic method");
+    }
+
+    if let syn::ReturnType::Type(_, ref typ) = sig.output {
+        if contains_ident(quote!(#typ), "Self") {
+            return Some("method returning `Self`");
+        }
+    }
+
+    if arguments.clone().any(|typ| contains_ident(quote!(#typ), "Self")) {
+        return Some("method taking `Self` argument");
+    }
+
+    None
+}
+
+///Returns error for every item, which makes trait incompatible with trait object, required by `keyword` target
+///
+///Methods requiring `Self: Sized` are excluded from trait object, hence they never make it incompatible.
+pub fn dyn_incompatible_items(input: &syn::ItemTrait, keyword: Keyword) -> Option<syn::Error> {
+    let trait_name = &input.ident;
+    let mut result: Option<syn::Error> = None;
+    let mut report = |tokens: &dyn quote::ToTokens, reason: String, hint: &str| {
+        let error = diagnostic::error_spanned(tokens, Code::DynIncompatibleTrait, format!("Trait '{}' is incompatible with trait object, required by `{}` target, due to {}, {}", trait_name, keyword.name(), reason, hint));
+        match result {
+            Some(ref mut result) => result.combine(error),
+            None => result = Some(error),
+        }
+    };
+
+    for bound in input.supertraits.iter() {
+        if let syn::TypeParamBound::Trait(bound) = bound {
+            if bound.path.segments.last().map(|segment| segment.ident == "Sized").unwrap_or(false) {
+                report(bound, "`Sized` supertrait".to_owned(), "remove it");
+            }
+        }
+    }
+
+    for item in input.items.iter() {
+        match item {
+            syn::TraitItem::Fn(method) if !requires_sized(&method.sig) => {
+                let reason = match Receiver::new(&method.sig) {
+                    Receiver::None => Some("function without receiver"),
+                    _ => signature_incompatibility(&method.sig),
+                };
+                if let Some(reason) = reason {
+                    report(&method.sig, format!("{} '{}'", reason, method.sig.ident), "add `where Self: Sized` to exclude it from trait object");
+                }
+            },
+            syn::TraitItem::Const(item) => report(item, format!("associated const '{}'", item.ident), "move it into separate trait"),
+            syn::TraitItem::Type(item) if !item.generics.params.is_empty() => report(item, format!("generic associated type '{}'", item.ident), "move it into separate trait"),
+            _ => (),
+        }
+    }
+
+    result
+}
+
+///Returns `Fn` bound of function, which implements the trait by its only required method, required by `fn` target
+///
+///Other methods are left with their default implementation, while function is called with arguments of required method.
+pub fn fn_bound(input: &syn::ItemTrait, core: &TokenStream) -> syn::Result<syn::TypeParamBound> {
+    let trait_name = &input.ident;
+    let error = |tokens: &dyn quote::ToTokens, reason: String| diagnostic::error_spanned(tokens, Code::FnIncompatibleTrait, format!("Trait '{}' cannot be implemented for function, required by `fn` target, due to {}", trait_name, reason));
+
+    let mut required = Vec::new();
+    for item in input.items.iter() {
+        match item {
+            syn::TraitItem::Fn(method) if method.default.is_none() => required.push(method),
+            syn::TraitItem::Const(item) if item.default.is_none() => return Err(error(item, format!("associated const '{}' without default value, which function cannot provide", item.ident))),
+            syn::TraitItem::Type(item) => return Err(error(item, format!("associated type '{}', which function cannot provide", item.ident))),
+            _ => (),
+        }
+    }
+
+    let method = match required.as_slice() {
+        [method] => *method,
+        [] => return Err(error(trait_name, "lack of required method, which calls function".to_owned())),
+        [_, rest @ ..] => {
+            let mut errors = rest.iter().map(|method| error(&method.sig, format!("second required method '{}', while function is called by only one, provide default implementation for other methods", method.sig.ident)));
+            let mut result = errors.next().expect("to have second method");
+            errors.for_each(|error| result.combine(error));
+            return Err(result);
+        },
+    };
+
+    let sig = &method.sig;
+    let receiver = Receiver::new(sig);
+    let reason = match receiver {
+        Receiver::Ref if sig.asyncness.is_some() => Some(("async method", "")),
+        Receiver::Ref if borrows_receiver(sig) => Some(("method", " returning value, which borrows receiver")),
+        Receiver::Ref => signature_incompatibility(sig).map(|reason| (reason, "")),
+        _ => Some((receiver.describe(), ", while function is called only by `&self` method")),
+    };
+    if let Some((reason, hint)) = reason {
+        return Err(error(sig, format!("{} '{}'{}", reason, sig.ident, hint)));
+    }
+
+    let inputs = sig.inputs.iter().filter_map(|arg| match arg {
+        syn::FnArg::Typed(arg) => Some(&arg.ty),
+        syn::FnArg::Receiver(_) => None,
+    });
+    let output = &sig.output;
+    let lifetimes = sig.generics.lifetimes().map(|param| &param.lifetime).collect::<Vec<_>>();
+    Ok(match lifetimes.is_empty() {
+        true => syn::parse_quote!(#core::ops::Fn(#(#inputs),*) #output),
+        false => syn::parse_quote!(for<#(#lifetimes),*> #core::ops::Fn(#(#inputs),*) #output),
+    })
+}
+
+///Returns whether tokens contain identifier `name` at any nesting level
+pub fn contains_ident(tokens: TokenStream, name: &str) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => ident == name,
+        proc_macro2::TokenTree::Group(group) => contains_ident(group.stream(), name),
+        _ => false,
+    })
+}
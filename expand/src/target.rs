@@ -0,0 +1,799 @@
+//!Target type parsing
+//!
+//!Target is written as regular type, but its generic arguments may additionally declare
+//!parameters of the generated implementation:
+//!
+//!- `T: Bound` declares type parameter `T` with specified bounds, which are kept verbatim (e.g. `T: Iterator<Item = u32> + Bound`);
+//!- `const N: usize` declares const parameter `N`;
+//!- Plain identifier names concrete type, while unbounded type parameter is declared with `for<...>` prefix or by where clause;
+//!- Any lifetime, except `'static` and `'_`, declares lifetime parameter.
+//!
+//!Declarations are recognized at any nesting level, e.g. `Box<Inner<T: Bound>>`, and within elements of tuple, e.g. `(A: Bound, B: Bound)`.
+//!
+//!Alternatively target can be one of keywords `&`, `&mut`, `Box`, `Rc` or `Arc`, which stand for
+//!blanket implementation over any type implementing the trait, or `Box<dyn>` and `&dyn`, which stand for
+//!pointers to trait object of the trait. Keyword `dyn` stands for both of them.
+//!Keyword `fn` stands for blanket implementation over any function, which is called by the trait's only required method.
+//!
+//!Type can be prefixed with `for<...>` to declare parameters explicitly, e.g. `for<'a> Cow<'a, u32>`,
+//!which are merged with parameters declared within type.
+//!
+//!Type can be followed by `for Trait<Args>`, which specifies arguments of generic trait for the target, e.g. `Wrapper for Convert<u32>`.
+//!
+//!Type can be followed by `=> Inner`, which explicitly specifies type to forward to, e.g. `Wrapper => u32`.
+//!
+//!Target can end with where clause, e.g. `Box<T> where T: Bound + Send`, which is added to the generated implementation.
+
+use quote::ToTokens;
+use syn::parse::discouraged::Speculative;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::Token;
+
+use crate::diagnostic::{self, Code};
+
+///Parsed target of `auto_trait` attribute
+#[derive(Clone)]
+pub struct Target {
+    ///Type for which trait is implemented, with parameter declarations replaced by parameter names
+    pub typ: syn::Type,
+    ///Parameters declared within type's generic arguments
+    pub generics: syn::Generics,
+    ///Explicitly specified type to forward to
+    pub inner: Option<syn::Type>,
+    ///Keyword, if target is blanket implementation
+    pub keyword: Option<Keyword>,
+    ///Path to the trait with arguments specified for target, e.g. `Convert<u32>`
+    pub trait_path: Option<syn::Path>,
+}
+
+///Keyword target, implemented for pointer to any type implementing the trait
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Keyword {
+    ///`&T`
+    Ref,
+    ///`&mut T`
+    Mut,
+    ///`Box<T>`
+    Box,
+    ///`Rc<T>`
+    Rc,
+    ///`Arc<T>`
+    Arc,
+    ///`Box<dyn Trait + '_>`
+    DynBox,
+    ///`&dyn Trait`
+    DynRef,
+    ///`F: Fn(Args) -> Output`
+    Fn,
+}
+
+impl Keyword {
+    ///Returns keyword as written in attribute
+    pub fn name(self) -> &'static str {
+        match self {
+            Keyword::Ref => "&",
+            Keyword::Mut => "&mut",
+            Keyword::Box => "Box",
+            Keyword::Rc => "Rc",
+            Keyword::Arc => "Arc",
+            Keyword::DynBox => "Box<dyn>",
+            Keyword::DynRef => "&dyn",
+            Keyword::Fn => "fn",
+        }
+    }
+
+    fn parse(input: ParseStream) -> Option<Self> {
+        let fork = input.fork();
+        let keyword = if fork.parse::<Token![&]>().is_ok() {
+            if fork.parse::<Option<Token![mut]>>().ok()?.is_some() {
+                Keyword::Mut
+            } else if fork.parse::<Option<Token![dyn]>>().ok()?.is_some() {
+                Keyword::DynRef
+            } else {
+                Keyword::Ref
+            }
+        } else if fork.parse::<Option<Token![fn]>>().ok()?.is_some() {
+            Keyword::Fn
+        } else {
+            let path = fork.call(syn::Path::parse_mod_style).ok()?;
+            match pointer_name(&path) {
+                Some(ident) if ident == "Box" && fork.peek(Token![<]) => {
+                    fork.parse::<Token![<]>().ok()?;
+                    fork.parse::<Token![dyn]>().ok()?;
+                    fork.parse::<Token![>]>().ok()?;
+                    Keyword::DynBox
+                },
+                Some(ident) if ident == "Box" => Keyword::Box,
+                Some(ident) if ident == "Rc" => Keyword::Rc,
+                Some(ident) if ident == "Arc" => Keyword::Arc,
+                _ => return None,
+            }
+        };
+
+        if fork.is_empty() || fork.peek(Token![,]) {
+            input.advance_to(&fork);
+            Some(keyword)
+        } else {
+            None
+        }
+    }
+
+    fn target(self) -> Target {
+        let mut generics = syn::Generics::default();
+        let param = syn::Ident::new(KEYWORD_PARAM, proc_macro2::Span::call_site());
+        let typ = match self {
+            //Trait object type is known only along with trait, see `dyn_type`
+            Keyword::DynBox | Keyword::DynRef => {
+                let lifetime = syn::Lifetime::new(KEYWORD_LIFETIME, proc_macro2::Span::call_site());
+                generics.params.push(syn::GenericParam::Lifetime(syn::LifetimeParam::new(lifetime)));
+                return Target {
+                    typ: syn::Type::Verbatim(proc_macro2::TokenStream::new()),
+                    generics,
+                    inner: None,
+                    keyword: Some(self),
+                    trait_path: None,
+                };
+            },
+            Keyword::Ref | Keyword::Mut => {
+                let lifetime = syn::Lifetime::new(KEYWORD_LIFETIME, proc_macro2::Span::call_site());
+                generics.params.push(syn::GenericParam::Lifetime(syn::LifetimeParam::new(lifetime.clone())));
+                match self {
+                    Keyword::Ref => syn::parse_quote!(&#lifetime #param),
+                    _ => syn::parse_quote!(&#lifetime mut #param),
+                }
+            },
+            Keyword::Box => {
+                let alloc = crate::alloc_path();
+                syn::parse_quote!(#alloc::boxed::Box<#param>)
+            },
+            Keyword::Rc => {
+                let alloc = crate::alloc_path();
+                syn::parse_quote!(#alloc::rc::Rc<#param>)
+            },
+            Keyword::Arc => {
+                let alloc = crate::alloc_path();
+                syn::parse_quote!(#alloc::sync::Arc<#param>)
+            },
+            Keyword::Fn => syn::parse_quote!(#param),
+        };
+        generics.params.push(syn::GenericParam::Type(param.into()));
+
+        Target {
+            typ,
+            generics,
+            inner: None,
+            keyword: Some(self),
+            trait_path: None,
+        }
+    }
+
+    ///Returns pointer to trait object of the trait, if keyword stands for it
+    pub fn dyn_type(self, trait_path: &syn::Path) -> Option<syn::Type> {
+        let lifetime = syn::Lifetime::new(KEYWORD_LIFETIME, proc_macro2::Span::call_site());
+        match self {
+            Keyword::DynBox => {
+                let alloc = crate::alloc_path();
+                Some(syn::parse_quote!(#alloc::boxed::Box<dyn #trait_path + #lifetime>))
+            },
+            Keyword::DynRef => Some(syn::parse_quote!(&#lifetime (dyn #trait_path + #lifetime))),
+            _ => None,
+        }
+    }
+
+    ///Bounds parameter of blanket implementation by `bound`, relaxing `Sized` bound unless `sized`
+    ///
+    ///Parameter is bound by the trait itself, except for function, which is bound by `Fn`.
+    pub fn bind(self, generics: &mut syn::Generics, bound: syn::TypeParamBound, sized: bool, core: &proc_macro2::TokenStream) {
+        for param in generics.type_params_mut() {
+            if param.ident == KEYWORD_PARAM {
+                param.bounds.push(bound.clone());
+                if !sized {
+                    param.bounds.push(syn::parse_quote!(?#core::marker::Sized));
+                }
+            }
+        }
+    }
+}
+
+//Hygiene of `Span::mixed_site` covers only local variables and labels, hence introduced parameters are named at call site,
+//while collision with trait's parameters is avoided by renaming, see `Target::rename`
+const KEYWORD_PARAM: &str = "__AutoTraitInner";
+const KEYWORD_LIFETIME: &str = "'__auto_trait";
+
+///Parameter of target renamed to avoid collision with trait's parameters
+pub struct Rename {
+    ///Name as written in target
+    pub from: syn::Ident,
+    ///Name used by generated implementation
+    pub to: syn::Ident,
+    ///Whether parameter is lifetime
+    pub lifetime: bool,
+}
+
+impl Rename {
+    ///Applies renames to tokens, replacing parameter names
+    pub fn apply(renames: &[Rename], tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if renames.is_empty() {
+            return tokens;
+        }
+        replace(tokens, &|ident, lifetime| renames.iter().find(|rename| rename.lifetime == lifetime && rename.from == *ident).map(|rename| {
+            let mut to = rename.to.clone();
+            to.set_span(ident.span());
+            quote::quote!(#to)
+        }))
+    }
+
+    ///Applies renames to syntax tree
+    pub fn apply_to<T: quote::ToTokens + Parse>(renames: &[Rename], value: &T) -> syn::Result<T> {
+        syn::parse2(Self::apply(renames, quote::quote!(#value)))
+    }
+}
+
+///Parameter of trait, replaced by argument specified for target
+pub struct Substitution {
+    ///Trait's parameter
+    pub param: syn::Ident,
+    ///Whether parameter is lifetime
+    pub lifetime: bool,
+    ///Argument, which is identifier without apostrophe for lifetime
+    pub value: proc_macro2::TokenStream,
+}
+
+impl Substitution {
+    ///Pairs trait's parameters with arguments of trait's path
+    pub fn new(generics: &syn::Generics, path: &syn::Path) -> syn::Result<Vec<Self>> {
+        let args = match path.segments.last().map(|segment| &segment.arguments) {
+            Some(syn::PathArguments::AngleBracketed(args)) => &args.args,
+            _ => return Ok(Vec::new()),
+        };
+        if args.len() != generics.params.len() {
+            return Err(diagnostic::error_spanned(args, Code::TraitArgumentsCount, format!("Trait has {} parameters, but {} arguments are specified", generics.params.len(), args.len())));
+        }
+
+        let mut result = Vec::new();
+        for (param, arg) in generics.params.iter().zip(args.iter()) {
+            let substitution = match (param, arg) {
+                (syn::GenericParam::Lifetime(param), syn::GenericArgument::Lifetime(lifetime)) if lifetime.ident != "_" => Self {
+                    param: param.lifetime.ident.clone(),
+                    lifetime: true,
+                    value: lifetime.ident.to_token_stream(),
+                },
+                (syn::GenericParam::Type(param), syn::GenericArgument::Type(typ)) => Self {
+                    param: param.ident.clone(),
+                    lifetime: false,
+                    value: typ.to_token_stream(),
+                },
+                (syn::GenericParam::Const(param), syn::GenericArgument::Type(_) | syn::GenericArgument::Const(_)) => Self {
+                    param: param.ident.clone(),
+                    lifetime: false,
+                    value: arg.to_token_stream(),
+                },
+                (param, arg) => return Err(diagnostic::error_spanned(arg, Code::TraitArgumentMismatch, format!("Argument doesn't match trait's parameter '{}'", diagnostic::display(param)))),
+            };
+            result.push(substitution);
+        }
+
+        Ok(result)
+    }
+
+    ///Replaces trait's parameters with arguments
+    pub fn apply(substitutions: &[Substitution], tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        //Tokens are rebuilt only when there is something to replace
+        if substitutions.is_empty() {
+            return tokens;
+        }
+        replace(tokens, &|ident, lifetime| substitutions.iter().find(|substitution| substitution.lifetime == lifetime && substitution.param == *ident).map(|substitution| substitution.value.clone()))
+    }
+
+    ///Replaces trait's parameters with arguments in syntax tree
+    pub fn apply_to<T: quote::ToTokens + Parse>(substitutions: &[Substitution], value: &T) -> syn::Result<T> {
+        syn::parse2(Self::apply(substitutions, quote::quote!(#value)))
+    }
+}
+
+///Replaces identifiers at any nesting level, telling whether identifier is name of lifetime
+fn replace(tokens: proc_macro2::TokenStream, replacement: &dyn Fn(&syn::Ident, bool) -> Option<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+    let mut result = proc_macro2::TokenStream::new();
+    let mut is_lifetime = false;
+    for token in tokens {
+        let next_is_lifetime = matches!(token, proc_macro2::TokenTree::Punct(ref punct) if punct.as_char() == '\'' && punct.spacing() == proc_macro2::Spacing::Joint);
+        match token {
+            proc_macro2::TokenTree::Ident(ident) => match replacement(&ident, is_lifetime) {
+                Some(tokens) => result.extend(tokens),
+                None => result.extend(core::iter::once(proc_macro2::TokenTree::Ident(ident))),
+            },
+            proc_macro2::TokenTree::Group(group) => {
+                let mut replaced = proc_macro2::Group::new(group.delimiter(), replace(group.stream(), replacement));
+                replaced.set_span(group.span());
+                result.extend(core::iter::once(proc_macro2::TokenTree::Group(replaced)));
+            },
+            token => result.extend(core::iter::once(token)),
+        }
+        is_lifetime = next_is_lifetime;
+    }
+
+    result
+}
+
+impl Target {
+    ///Renames parameters, which names are reserved by trait, returning target with renamed parameters
+    pub fn rename(&self, reserved: &syn::Generics) -> syn::Result<(Self, Vec<Rename>)> {
+        let mut renames = Vec::new();
+        for param in self.generics.params.iter() {
+            let (from, lifetime) = match param {
+                syn::GenericParam::Lifetime(param) => (&param.lifetime.ident, true),
+                syn::GenericParam::Type(param) => (&param.ident, false),
+                syn::GenericParam::Const(param) => (&param.ident, false),
+            };
+            if !reserved.params.iter().any(|reserved| param_name(reserved) == from && matches!(reserved, syn::GenericParam::Lifetime(_)) == lifetime) {
+                continue;
+            }
+
+            let to = match lifetime {
+                true => quote::format_ident!("__auto_trait_{}", from),
+                false => quote::format_ident!("__AutoTrait{}", from),
+            };
+            renames.push(Rename {
+                from: from.clone(),
+                to,
+                lifetime,
+            });
+        }
+
+        if renames.is_empty() {
+            return Ok((self.clone(), renames));
+        }
+
+        let mut generics = syn::Generics::default();
+        for param in self.generics.params.iter() {
+            generics.params.push(Rename::apply_to(&renames, param)?);
+        }
+        if let Some(ref where_clause) = self.generics.where_clause {
+            generics.where_clause = Some(Rename::apply_to(&renames, where_clause)?);
+        }
+
+        let target = Self {
+            typ: Rename::apply_to(&renames, &self.typ)?,
+            generics,
+            inner: match self.inner {
+                Some(ref inner) => Some(Rename::apply_to(&renames, inner)?),
+                None => None,
+            },
+            keyword: self.keyword,
+            trait_path: match self.trait_path {
+                Some(ref path) => Some(Rename::apply_to(&renames, path)?),
+                None => None,
+            },
+        };
+        Ok((target, renames))
+    }
+
+    ///Returns reference to the target, which forwards to target's implementation
+    pub fn reference(&self, keyword: Keyword) -> Self {
+        let lifetime = syn::Lifetime::new(KEYWORD_LIFETIME, proc_macro2::Span::call_site());
+        let typ = &self.typ;
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, syn::GenericParam::Lifetime(syn::LifetimeParam::new(lifetime.clone())));
+
+        Self {
+            typ: match keyword {
+                Keyword::Mut => syn::parse_quote!(&#lifetime mut #typ),
+                _ => syn::parse_quote!(&#lifetime #typ),
+            },
+            generics,
+            inner: Some(self.typ.clone()),
+            keyword: Some(keyword),
+            trait_path: self.trait_path.clone(),
+        }
+    }
+
+    ///Returns whether implementation for generic target also covers `other` target, e.g. `Box<T>` covers `Box<u32>`
+    ///
+    ///Types are matched by their paths, ignoring lifetimes, which is enough to detect common overlaps.
+    ///Function is known to be distinct from other types, as `Fn` traits are fundamental.
+    pub fn subsumes(&self, other: &Target) -> bool {
+        if self.keyword == Some(Keyword::Fn) || other.keyword == Some(Keyword::Fn) {
+            return false;
+        }
+        let params = self.generics.type_params().map(|param| &param.ident).collect::<Vec<_>>();
+        let mut bindings = Vec::new();
+        !params.is_empty() && matches_type(&self.typ, &other.typ, &params, &mut bindings)
+    }
+
+    ///Parses target, which may stand for several targets, as `dyn` does for `Box<dyn>` and `&dyn`
+    pub fn parse_targets(input: ParseStream) -> syn::Result<Vec<Self>> {
+        let fork = input.fork();
+        if fork.parse::<Token![dyn]>().is_ok() && (fork.is_empty() || fork.peek(Token![,])) {
+            input.advance_to(&fork);
+            return Ok(vec![Keyword::DynBox.target(), Keyword::DynRef.target()]);
+        }
+
+        input.parse().map(|target| vec![target])
+    }
+}
+
+impl Parse for Target {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if let Some(keyword) = Keyword::parse(input) {
+            return Ok(keyword.target());
+        }
+
+        let explicit = if input.peek(Token![for]) {
+            input.parse::<Token![for]>()?;
+            input.parse::<syn::Generics>()?
+        } else {
+            syn::Generics::default()
+        };
+
+        let mut generics = syn::Generics::default();
+
+        let fork = input.fork();
+        //Macro cannot be expanded by attribute, so it is kept as it is written
+        let typ = if let Ok(typ) = fork.parse::<syn::TypeMacro>() {
+            input.advance_to(&fork);
+            syn::Type::Macro(typ)
+        } else if starts_path(input) {
+            syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: parse_path(input, &mut generics)?,
+            })
+        } else if is_tuple(input) {
+            parse_tuple(input, &mut generics)?
+        } else {
+            unwrap_type(input.parse()?)
+        };
+
+        let trait_path = if input.peek(Token![for]) {
+            input.parse::<Token![for]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let inner = if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        let mut generics = merge(explicit, generics);
+        if input.peek(Token![where]) {
+            let where_clause = parse_where_clause(input)?;
+            //Plain identifier bound by where clause is declared as parameter, same as `T: Bound` within type
+            for predicate in where_clause.predicates.iter() {
+                let bounded = match predicate {
+                    syn::WherePredicate::Type(syn::PredicateType { bounded_ty: syn::Type::Path(bounded), .. }) if bounded.qself.is_none() => bounded.path.get_ident(),
+                    _ => None,
+                };
+                if let Some(ident) = bounded {
+                    if !generics.type_params().any(|param| param.ident == *ident) {
+                        generics.params.push(syn::GenericParam::Type(ident.clone().into()));
+                    }
+                }
+            }
+            generics.where_clause = Some(where_clause);
+        }
+
+        Ok(Self {
+            typ,
+            generics,
+            inner,
+            keyword: None,
+            trait_path,
+        })
+    }
+}
+
+///Returns whether input continues with where clause predicate, rather than next argument
+fn peek_predicate(input: ParseStream) -> bool {
+    (input.peek(syn::Ident) && input.peek2(Token![:]) && !input.peek2(Token![::])) || (input.peek(syn::Lifetime) && input.peek2(Token![:])) || input.peek(Token![for])
+}
+
+///Parses where clause, which predicates are separated by comma, same as arguments
+fn parse_where_clause(input: ParseStream) -> syn::Result<syn::WhereClause> {
+    let mut where_clause = syn::WhereClause {
+        where_token: input.parse()?,
+        predicates: Punctuated::new(),
+    };
+
+    loop {
+        where_clause.predicates.push_value(input.parse()?);
+
+        let fork = input.fork();
+        if fork.parse::<Token![,]>().is_ok() && peek_predicate(&fork) {
+            where_clause.predicates.push_punct(input.parse()?);
+        } else {
+            break;
+        }
+    }
+
+    Ok(where_clause)
+}
+
+///Modules of `std` and `alloc`, which define smart pointers of keyword targets
+const POINTER_MODULES: &[(&str, &str)] = &[("Box", "boxed"), ("Rc", "rc"), ("Arc", "sync")];
+
+///Returns name of smart pointer, written either by itself or by its path within `std` or `alloc`, e.g. `alloc::rc::Rc`
+fn pointer_name(path: &syn::Path) -> Option<&syn::Ident> {
+    let segments = path.segments.iter().map(|segment| &segment.ident).collect::<Vec<_>>();
+    match segments[..] {
+        [name] if path.leading_colon.is_none() => Some(name),
+        [krate, module, name] if (krate == "std" || krate == "alloc") && POINTER_MODULES.iter().any(|&(pointer, path)| name == pointer && module == path) => Some(name),
+        _ => None,
+    }
+}
+
+///Returns whether paths name the same item, assuming that absolute path can be imported and named by its suffix, e.g. `::std::rc::Rc` and `Rc`
+fn same_path(left: &syn::Path, right: &syn::Path) -> bool {
+    let is_suffix = |path: &syn::Path, of: &syn::Path| {
+        path.segments.len() <= of.segments.len() && path.segments.iter().rev().zip(of.segments.iter().rev()).all(|(path, of)| path.ident == of.ident)
+    };
+    match (left.leading_colon.is_some(), right.leading_colon.is_some()) {
+        (true, false) => is_suffix(right, left),
+        (false, true) => is_suffix(left, right),
+        _ => left.segments.len() == right.segments.len() && is_suffix(left, right),
+    }
+}
+
+///Matches type against pattern, which parameters stand for any type, as long as every occurrence of parameter stands for the same type
+fn matches_type(pattern: &syn::Type, typ: &syn::Type, params: &[&syn::Ident], bindings: &mut Vec<(syn::Ident, String)>) -> bool {
+    match (pattern, typ) {
+        (syn::Type::Path(pattern), _) if pattern.qself.is_none() && pattern.path.get_ident().map(|ident| params.contains(&ident)).unwrap_or(false) => {
+            let param = &pattern.path.segments[0].ident;
+            let typ = typ.to_token_stream().to_string();
+            match bindings.iter().find(|(bound, _)| bound == param) {
+                Some((_, bound)) => *bound == typ,
+                None => {
+                    bindings.push((param.clone(), typ));
+                    true
+                },
+            }
+        },
+        (syn::Type::Path(pattern), syn::Type::Path(typ)) if pattern.qself.is_none() && typ.qself.is_none() && same_path(&pattern.path, &typ.path) => match (pattern.path.segments.last(), typ.path.segments.last()) {
+            (Some(pattern), Some(typ)) if pattern.ident == typ.ident => match (&pattern.arguments, &typ.arguments) {
+                (syn::PathArguments::AngleBracketed(pattern), syn::PathArguments::AngleBracketed(typ)) => {
+                    let pattern = pattern.args.iter().filter(|arg| !matches!(arg, syn::GenericArgument::Lifetime(_))).collect::<Vec<_>>();
+                    let typ = typ.args.iter().filter(|arg| !matches!(arg, syn::GenericArgument::Lifetime(_))).collect::<Vec<_>>();
+                    pattern.len() == typ.len() && pattern.iter().zip(typ.iter()).all(|(pattern, typ)| match (pattern, typ) {
+                        (syn::GenericArgument::Type(pattern), syn::GenericArgument::Type(typ)) => matches_type(pattern, typ, params, bindings),
+                        (pattern, typ) => pattern.to_token_stream().to_string() == typ.to_token_stream().to_string(),
+                    })
+                },
+                (pattern, typ) => pattern.to_token_stream().to_string() == typ.to_token_stream().to_string(),
+            },
+            _ => false,
+        },
+        (syn::Type::Reference(pattern), syn::Type::Reference(typ)) => pattern.mutability.is_some() == typ.mutability.is_some() && matches_type(&pattern.elem, &typ.elem, params, bindings),
+        (syn::Type::Ptr(pattern), syn::Type::Ptr(typ)) => pattern.mutability.is_some() == typ.mutability.is_some() && matches_type(&pattern.elem, &typ.elem, params, bindings),
+        (syn::Type::Slice(pattern), syn::Type::Slice(typ)) => matches_type(&pattern.elem, &typ.elem, params, bindings),
+        (syn::Type::Array(pattern), syn::Type::Array(typ)) => pattern.len.to_token_stream().to_string() == typ.len.to_token_stream().to_string() && matches_type(&pattern.elem, &typ.elem, params, bindings),
+        (syn::Type::Tuple(pattern), syn::Type::Tuple(typ)) => pattern.elems.len() == typ.elems.len() && pattern.elems.iter().zip(typ.elems.iter()).all(|(pattern, typ)| matches_type(pattern, typ, params, bindings)),
+        (syn::Type::Paren(pattern), _) => matches_type(&pattern.elem, typ, params, bindings),
+        (_, syn::Type::Paren(typ)) => matches_type(pattern, &typ.elem, params, bindings),
+        (pattern, typ) => pattern.to_token_stream().to_string() == typ.to_token_stream().to_string(),
+    }
+}
+
+fn param_name(param: &syn::GenericParam) -> &syn::Ident {
+    match param {
+        syn::GenericParam::Lifetime(param) => &param.lifetime.ident,
+        syn::GenericParam::Type(param) => &param.ident,
+        syn::GenericParam::Const(param) => &param.ident,
+    }
+}
+
+///Merges explicitly declared parameters with parameters declared within type, keeping lifetimes first.
+pub fn merge(explicit: syn::Generics, declared: syn::Generics) -> syn::Generics {
+    let mut params = explicit.params.into_iter().collect::<Vec<_>>();
+    for param in declared.params {
+        if !params.iter().any(|explicit| param_name(explicit) == param_name(&param)) {
+            params.push(param);
+        }
+    }
+    params.sort_by_key(|param| !matches!(param, syn::GenericParam::Lifetime(_)));
+
+    syn::Generics {
+        params: params.into_iter().collect(),
+        ..syn::Generics::default()
+    }
+}
+
+const PRIMITIVES: &[&str] = &[
+    "bool", "char", "str",
+    "u8", "u16", "u32", "u64", "u128", "usize",
+    "i8", "i16", "i32", "i64", "i128", "isize",
+    "f32", "f64",
+];
+
+fn is_primitive(ident: &syn::Ident) -> bool {
+    PRIMITIVES.iter().any(|primitive| ident == primitive)
+}
+
+///Strips parentheses and invisible groups, which macro_rules produces when substituting `$typ:ty`
+fn unwrap_type(typ: syn::Type) -> syn::Type {
+    match typ {
+        syn::Type::Paren(typ) => unwrap_type(*typ.elem),
+        syn::Type::Group(typ) => unwrap_type(*typ.elem),
+        typ => typ,
+    }
+}
+
+fn starts_path(input: ParseStream) -> bool {
+    input.peek(syn::Ident) || input.peek(Token![::]) || input.peek(Token![crate]) || input.peek(Token![self]) || input.peek(Token![super])
+}
+
+fn ident_argument(ident: syn::Ident) -> syn::GenericArgument {
+    syn::GenericArgument::Type(syn::Type::Path(syn::TypePath {
+        qself: None,
+        path: ident.into(),
+    }))
+}
+
+fn parse_path(input: ParseStream, generics: &mut syn::Generics) -> syn::Result<syn::Path> {
+    let leading_colon = input.parse()?;
+    let mut segments = Punctuated::new();
+
+    loop {
+        let ident = input.call(syn::ext::IdentExt::parse_any)?;
+        //Turbofish is the same as plain arguments within type
+        if input.peek(Token![::]) && input.peek3(Token![<]) {
+            input.parse::<Token![::]>()?;
+        }
+        let arguments = if input.peek(Token![<]) && !input.peek(Token![<=]) {
+            syn::PathArguments::AngleBracketed(parse_arguments(input, generics)?)
+        } else if input.peek(syn::token::Paren) {
+            syn::PathArguments::Parenthesized(input.parse()?)
+        } else {
+            syn::PathArguments::None
+        };
+
+        segments.push_value(syn::PathSegment {
+            ident,
+            arguments,
+        });
+
+        if input.peek(Token![::]) {
+            segments.push_punct(input.parse()?);
+        } else {
+            break;
+        }
+    }
+
+    Ok(syn::Path {
+        leading_colon,
+        segments,
+    })
+}
+
+///Returns whether input starts with parentheses containing comma, which is tuple, rather than parenthesized type
+fn is_tuple(input: ParseStream) -> bool {
+    match input.cursor().group(proc_macro2::Delimiter::Parenthesis) {
+        Some((content, _, _)) => content.token_stream().into_iter().any(|token| matches!(token, proc_macro2::TokenTree::Punct(ref punct) if punct.as_char() == ',')),
+        None => false,
+    }
+}
+
+///Parses elements of tuple, which declare parameters the same way as generic arguments
+///
+///Single element without trailing comma is parenthesized type, rather than tuple.
+fn parse_tuple(input: ParseStream, generics: &mut syn::Generics) -> syn::Result<syn::Type> {
+    let content;
+    let paren_token = syn::parenthesized!(content in input);
+    let mut elems = Punctuated::new();
+
+    while !content.is_empty() {
+        match parse_argument(&content, generics)? {
+            syn::GenericArgument::Type(typ) => {
+                //Plain identifier, which is element of tuple, declares parameter implied to be bound by the trait
+                if let syn::Type::Path(syn::TypePath { qself: None, ref path }) = typ {
+                    if let Some(ident) = path.get_ident().filter(|ident| !is_primitive(ident) && !generics.type_params().any(|param| param.ident == **ident)) {
+                        generics.params.push(syn::GenericParam::Type(ident.clone().into()));
+                    }
+                }
+                elems.push_value(typ)
+            },
+            other => return Err(diagnostic::error_spanned(&other, Code::InvalidTarget, "Element of tuple must be a type")),
+        }
+        if content.is_empty() {
+            break;
+        }
+        elems.push_punct(content.parse()?);
+    }
+
+    if elems.len() == 1 && !elems.trailing_punct() {
+        return Ok(elems.into_iter().next().expect("to have element"));
+    }
+
+    Ok(syn::Type::Tuple(syn::TypeTuple {
+        paren_token,
+        elems,
+    }))
+}
+
+fn parse_arguments(input: ParseStream, generics: &mut syn::Generics) -> syn::Result<syn::AngleBracketedGenericArguments> {
+    let lt_token = input.parse()?;
+    let mut args = Punctuated::new();
+
+    while !input.peek(Token![>]) {
+        args.push_value(parse_argument(input, generics)?);
+        if input.peek(Token![>]) {
+            break;
+        }
+        args.push_punct(input.parse()?);
+    }
+
+    Ok(syn::AngleBracketedGenericArguments {
+        colon2_token: None,
+        lt_token,
+        args,
+        gt_token: input.parse()?,
+    })
+}
+
+fn parse_argument(input: ParseStream, generics: &mut syn::Generics) -> syn::Result<syn::GenericArgument> {
+    if input.peek(syn::Lifetime) {
+        let lifetime: syn::Lifetime = input.parse()?;
+        if lifetime.ident != "static" && lifetime.ident != "_" {
+            generics.params.push(syn::GenericParam::Lifetime(syn::LifetimeParam::new(lifetime.clone())));
+        }
+
+        Ok(syn::GenericArgument::Lifetime(lifetime))
+    } else if input.peek(Token![const]) && input.peek2(syn::Ident) && input.peek3(Token![:]) {
+        let param = syn::ConstParam {
+            attrs: Vec::new(),
+            const_token: input.parse()?,
+            ident: input.parse()?,
+            colon_token: input.parse()?,
+            ty: input.parse()?,
+            eq_token: None,
+            default: None,
+        };
+        let ident = param.ident.clone();
+        generics.params.push(syn::GenericParam::Const(param));
+
+        Ok(ident_argument(ident))
+    } else if input.peek(syn::Ident) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+        let ident: syn::Ident = input.parse()?;
+        let colon_token = input.parse()?;
+        let mut bounds = Punctuated::new();
+        loop {
+            bounds.push_value(input.parse()?);
+            if input.peek(Token![+]) {
+                bounds.push_punct(input.parse()?);
+            } else {
+                break;
+            }
+        }
+
+        generics.params.push(syn::GenericParam::Type(syn::TypeParam {
+            attrs: Vec::new(),
+            ident: ident.clone(),
+            colon_token: Some(colon_token),
+            bounds,
+            eq_token: None,
+            default: None,
+        }));
+
+        Ok(ident_argument(ident))
+    } else if starts_path(input) {
+        let path = parse_path(input, generics)?;
+        //Trait object without `dyn`, which edition 2015 allows, cannot be anything else, as bounds are written after colon
+        if input.peek(Token![+]) {
+            let mut object = quote::quote!(#path);
+            while input.peek(Token![+]) {
+                let plus: Token![+] = input.parse()?;
+                let bound: syn::TypeParamBound = input.parse()?;
+                object.extend(quote::quote!(#plus #bound));
+            }
+            return Err(diagnostic::error_spanned(&object, Code::BareTraitObject, format!("Trait object '{}' is written without `dyn`, write `dyn {}` instead", object, object)));
+        }
+        Ok(syn::GenericArgument::Type(syn::Type::Path(syn::TypePath {
+            qself: None,
+            path,
+        })))
+    } else {
+        input.parse()
+    }
+}
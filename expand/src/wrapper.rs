@@ -0,0 +1,473 @@
+//!Generation of conversions for `AutoWrapper` derive
+//!
+//!Wrapper's field is selected by `#[auto_wrapper(inner)]` on the field or by `#[auto_wrapper(field = <index | name>)]` on the struct,
+//!unless struct has only one field.
+//!
+//!`#[auto_wrapper(forwarder = <name>)]` additionally exports `macro_rules!` forwarder, which implements traits of other crates for the wrapper, see `forwarder`.
+//!
+//!`newtype` option of `auto_trait` generates wrapper struct for the trait, see `newtype`, while `enum` option generates enum over its targets, see `dispatch_enum`.
+//!`cache` option generates wrapper struct, which caches results of methods, see `cache`.
+//!
+//!`auto_wrap!` defines wrapper struct, deriving `AutoWrapper` and implementing exported traits listed after `impl`, as `AutoTrait` does.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::Token;
+
+use crate::diagnostic::{self, Code};
+use crate::export::{self, DeriveTrait};
+
+///Field selected by `field = <index | name>` option of the struct
+enum Selection {
+    Index(syn::LitInt),
+    Name(syn::Ident),
+}
+
+///Options of `auto_wrapper` attribute on the struct
+#[derive(Default)]
+struct WrapperOptions {
+    selection: Option<Selection>,
+    ///Name of `macro_rules!` forwarder to export
+    forwarder: Option<syn::Ident>,
+    ///Path to the wrapper within its crate, used by forwarder
+    path: Option<syn::Path>,
+    ///Path to `core` crate, same as `crate_path` of `auto_trait`
+    crate_path: Option<syn::Path>,
+}
+
+impl WrapperOptions {
+    ///Returns path to `core` crate, `::core` by default
+    fn core_path(&self) -> TokenStream {
+        match self.crate_path {
+            Some(ref path) => quote!(#path),
+            None => crate::extern_crate("core", proc_macro2::Span::call_site()),
+        }
+    }
+}
+
+fn parse_options(attrs: &[syn::Attribute]) -> syn::Result<WrapperOptions> {
+    let mut options = WrapperOptions::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("auto_wrapper")) {
+        attr.parse_args_with(|input: ParseStream| {
+            loop {
+                let name: syn::Ident = input.parse()?;
+                input.parse::<Token![=]>()?;
+                let duplicate = if name == "field" {
+                    let field = match input.peek(syn::LitInt) {
+                        true => Selection::Index(input.parse()?),
+                        false => Selection::Name(input.parse()?),
+                    };
+                    if options.selection.replace(field).is_some() {
+                        return Err(diagnostic::error_spanned(&name, Code::DuplicateWrapperField, "Field of wrapper is selected twice"));
+                    }
+                    false
+                } else if name == "forwarder" {
+                    options.forwarder.replace(input.parse()?).is_some()
+                } else if name == "path" {
+                    let path: syn::Path = input.parse()?;
+                    if path.leading_colon.is_some() || path.segments.len() < 2 || path.segments[0].ident != "crate" {
+                        return Err(diagnostic::error_spanned(&path, Code::InvalidWrapperPath, "Path of wrapper must start with `crate`, e.g. `crate::wrappers::Wrapper`, so that forwarder refers to it from other crates"));
+                    }
+                    options.path.replace(path).is_some()
+                } else if name == "crate_path" {
+                    let path: syn::LitStr = input.parse()?;
+                    let path = path.parse().map_err(|_| diagnostic::error_spanned(&path, Code::InvalidCratePath, "`crate_path` must be a path, e.g. \"::core\""))?;
+                    options.crate_path.replace(path).is_some()
+                } else {
+                    return Err(diagnostic::error_spanned(&name, Code::UnknownWrapperOption, format!("Unknown option '{}' of wrapper, expected `field = <index | name>`, `forwarder = <name>`, `path = <path>` or `crate_path = \"<path>\"`", name)));
+                };
+                if duplicate {
+                    return Err(diagnostic::error_spanned(&name, Code::DuplicateWrapperOption, format!("Duplicate option '{}' of wrapper", name)));
+                }
+
+                if input.is_empty() {
+                    break Ok(());
+                }
+                input.parse::<Token![,]>()?;
+            }
+        })?;
+    }
+
+    if let (Some(path), None) = (&options.path, &options.forwarder) {
+        return Err(diagnostic::error_spanned(path, Code::InvalidWrapperPath, "Path of wrapper is used only by forwarder, specify its name with `forwarder = <name>`"));
+    }
+    Ok(options)
+}
+
+///Returns whether field is annotated with `#[auto_wrapper(inner)]`
+fn is_inner(field: &syn::Field) -> syn::Result<bool> {
+    let mut inner = false;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("auto_wrapper")) {
+        let name: syn::Ident = attr.parse_args()?;
+        if name != "inner" {
+            return Err(diagnostic::error_spanned(&name, Code::UnknownWrapperOption, format!("Unknown option '{}' of wrapper's field, expected `inner`", name)));
+        }
+        inner = true;
+    }
+
+    Ok(inner)
+}
+
+///Returns field of the struct to forward to, with its index
+fn field<'a>(input: &'a syn::DeriveInput, options: &WrapperOptions) -> syn::Result<(usize, &'a syn::Field)> {
+    let fields = match input.data {
+        syn::Data::Struct(ref data) => &data.fields,
+        _ => return Err(diagnostic::error_spanned(&input.ident, Code::WrapperNotStruct, format!("Wrapper '{}' must be struct with field to forward to", input.ident))),
+    };
+
+    let mut selected = None;
+    for (idx, field) in fields.iter().enumerate() {
+        if is_inner(field)? && selected.replace((idx, field)).is_some() {
+            return Err(diagnostic::error_spanned(field, Code::DuplicateWrapperField, "Field of wrapper is selected twice"));
+        }
+    }
+    if let Some(ref selection) = options.selection {
+        let field = match selection {
+            Selection::Index(ref index) => index.base10_parse::<usize>().ok().and_then(|index| fields.iter().nth(index).map(|field| (index, field))),
+            Selection::Name(ref name) => fields.iter().enumerate().find(|(_, field)| field.ident.as_ref() == Some(name)),
+        };
+        let field = match (field, selection) {
+            (Some(field), _) => field,
+            (None, Selection::Index(index)) => return Err(diagnostic::error_spanned(index, Code::WrapperFieldNotFound, format!("Wrapper '{}' has no field with index {}", input.ident, index))),
+            (None, Selection::Name(name)) => return Err(diagnostic::error_spanned(name, Code::WrapperFieldNotFound, format!("Wrapper '{}' has no field '{}'", input.ident, name))),
+        };
+        if selected.replace(field).is_some() {
+            return Err(diagnostic::error_spanned(&input.ident, Code::DuplicateWrapperField, "Field of wrapper is selected twice"));
+        }
+    }
+    match (selected, fields.len()) {
+        (Some(selected), _) => Ok(selected),
+        (None, 1) => Ok((0, fields.iter().next().expect("to have field"))),
+        (None, 0) => Err(diagnostic::error_spanned(&input.ident, Code::WrapperFieldRequired, format!("Wrapper '{}' has no field to forward to", input.ident))),
+        (None, _) => Err(diagnostic::error_spanned(&input.ident, Code::WrapperFieldRequired, format!("Wrapper '{}' has several fields, select field to forward to with `#[auto_wrapper(inner)]` on the field", input.ident))),
+    }
+}
+
+///Generates `Deref`, `DerefMut` and conversion into inner value for the struct
+pub fn derive(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let options = parse_options(&input.attrs)?;
+    let (idx, field) = field(input, &options)?;
+
+    let name = &input.ident;
+    let inner = &field.ty;
+    let member = match field.ident {
+        Some(ref ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(idx.into()),
+    };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let core = options.core_path();
+
+    //Conversion into type parameter is disallowed by coherence rules, as it would implement foreign trait for any type
+    fn is_param(typ: &syn::Type, generics: &syn::Generics) -> bool {
+        match typ {
+            syn::Type::Path(typ) => typ.qself.is_none() && typ.path.get_ident().map(|ident| generics.type_params().any(|param| param.ident == *ident)).unwrap_or(false),
+            syn::Type::Reference(typ) => is_param(&typ.elem, generics),
+            _ => false,
+        }
+    }
+    let conversion = match is_param(inner, &input.generics) {
+        true => None,
+        false => Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #core::convert::From<#name #ty_generics> for #inner #where_clause {
+                #[inline(always)]
+                fn from(value: #name #ty_generics) -> Self {
+                    value.#member
+                }
+            }
+        }),
+    };
+
+    let forwarder = options.forwarder.as_ref().map(|forwarder_name| forwarder(input, forwarder_name, &options, inner, &member));
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #core::ops::Deref for #name #ty_generics #where_clause {
+            type Target = #inner;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                &self.#member
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #core::ops::DerefMut for #name #ty_generics #where_clause {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.#member
+            }
+        }
+
+        #conversion
+
+        #forwarder
+    })
+}
+
+///Generates `macro_rules!` forwarder, which implements trait, restated as for `auto_trait_impl`, for the wrapper
+///
+///Macro is exported, so that crates, which depend on wrapper's crate, refer to wrapper through `$crate` and to `auto_trait_impl` of their own dependency.
+///Wrapper is converted through `Deref` and `DerefMut`, while owned wrapper is converted by hidden inherent method, as its field can be private.
+fn forwarder(input: &syn::DeriveInput, name: &syn::Ident, options: &WrapperOptions, inner: &syn::Type, member: &syn::Member) -> TokenStream {
+    let wrapper = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    //`crate` is replaced with `$crate`, which refers to wrapper's crate wherever forwarder is expanded
+    let path = match options.path {
+        Some(ref path) => {
+            let segments = path.segments.iter().skip(1);
+            quote!($crate #(::#segments)*)
+        },
+        None => quote!($crate::#wrapper),
+    };
+    //Parameters are written without bounds, as target would forward to parameter bound by the trait
+    let params = input.generics.params.iter().map(|param| match param {
+        syn::GenericParam::Lifetime(param) => {
+            let lifetime = &param.lifetime;
+            quote!(#lifetime)
+        },
+        syn::GenericParam::Type(param) => {
+            let ident = &param.ident;
+            quote!(#ident)
+        },
+        syn::GenericParam::Const(param) => {
+            let ident = &param.ident;
+            let typ = &param.ty;
+            quote!(const #ident: #typ)
+        },
+    });
+    //Type parameters are declared explicitly, as plain identifier within target names type
+    let free = input.generics.type_params().map(|param| &param.ident).collect::<Vec<_>>();
+    let typ = match input.generics.params.is_empty() {
+        true => path.clone(),
+        false => quote!(#path<#(#params),*>),
+    };
+    let target = match free.is_empty() {
+        true => typ.clone(),
+        false => quote!(for<#(#free),*> #typ),
+    };
+    //Generic wrapper's bounds are restated, along with bound of type to forward to by the trait
+    let core = options.core_path();
+    let mut bounds = Vec::new();
+    for param in input.generics.type_params().filter(|param| !param.bounds.is_empty()) {
+        let ident = &param.ident;
+        let param_bounds = &param.bounds;
+        bounds.push(quote!(#ident: #param_bounds));
+    }
+    bounds.extend(input.generics.where_clause.iter().flat_map(|where_clause| where_clause.predicates.iter()).map(|predicate| quote!(#predicate)));
+    let bound = match input.generics.params.is_empty() {
+        true => TokenStream::new(),
+        false => quote! {
+            , bound(#(#bounds,)* <#typ as #core::ops::Deref>::Target: $trait)
+        },
+    };
+    let into = quote!(#path::__auto_wrapper_into_inner);
+    //Implementation refers to `core` by the same path as wrapper does
+    let crate_path = options.crate_path.as_ref().map(|crate_path| {
+        let crate_path = syn::LitStr::new(&quote!(#crate_path).to_string(), proc_macro2::Span::call_site());
+        quote!(, crate_path = #crate_path)
+    });
+    let doc = format!("Implements trait for `{}`, forwarding to its field, written as `{}!(impl Trait {{ <methods> }})`, same as `auto_trait_impl!`", wrapper, name);
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #wrapper #ty_generics #where_clause {
+            #[doc(hidden)]
+            #[inline(always)]
+            pub fn __auto_wrapper_into_inner(self) -> #inner {
+                self.#member
+            }
+        }
+
+        #[doc = #doc]
+        #[macro_export]
+        macro_rules! #name {
+            (impl $trait:path { $($methods:tt)* }) => {
+                ::auto_trait::auto_trait_impl!(impl $trait for #target, into = #into #crate_path #bound { $($methods)* });
+            };
+            (unsafe impl $trait:path { $($methods:tt)* }) => {
+                ::auto_trait::auto_trait_impl!(unsafe impl $trait for #target, into = #into #crate_path #bound { $($methods)* });
+            };
+        }
+    }
+}
+
+///Input of `auto_wrap!`: struct, followed by `impl` with traits to implement for it
+pub struct Wrap {
+    item: syn::ItemStruct,
+    traits: Vec<DeriveTrait>,
+}
+
+impl Parse for Wrap {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let item = input.parse()?;
+        let mut traits = Vec::new();
+        if input.parse::<Option<Token![impl]>>()?.is_some() {
+            loop {
+                traits.push(input.parse()?);
+                if input.is_empty() || input.peek(Token![;]) {
+                    break;
+                }
+                input.parse::<Token![,]>()?;
+            }
+            input.parse::<Option<Token![;]>>()?;
+        }
+
+        Ok(Self {
+            item,
+            traits,
+        })
+    }
+}
+
+///Emits wrapper struct, deriving its conversions, along with implementations of exported traits, which forward to wrapper's field
+pub fn wrap(input: Wrap) -> syn::Result<TokenStream> {
+    let Wrap { item, traits } = input;
+    let derive_input = syn::DeriveInput::from(item.clone());
+    let options = parse_options(&derive_input.attrs)?;
+    let (_, field) = field(&derive_input, &options)?;
+    let impls = match traits.is_empty() {
+        true => TokenStream::new(),
+        false => export::implement(&derive_input, traits, Some(&field.ty))?,
+    };
+
+    Ok(quote! {
+        #[derive(::auto_trait::AutoWrapper)]
+        #item
+        #impls
+    })
+}
+
+///Returns name of field, which stores result of cached method
+pub fn cache_field(method: &syn::Ident) -> syn::Ident {
+    let method = syn::ext::IdentExt::unraw(method);
+    syn::Ident::new(&format!("cached_{}", method), method.span())
+}
+
+///Generates generic wrapper struct `name`, which stores results of cached methods in `fields` of their return types
+///
+///It dereferences to wrapped value and is converted into it by `into_inner`, while mutable access by `inner_mut` clears cached results.
+pub fn cache(name: &syn::Ident, vis: &syn::Visibility, trait_name: &syn::Ident, fields: &[(syn::Ident, syn::Type)], cfg: Option<&syn::Attribute>, core: &TokenStream) -> TokenStream {
+    let doc = format!("Wrapper of `{}` implementation, which caches results of its methods", trait_name);
+    let names = fields.iter().map(|(field, _)| field).collect::<Vec<_>>();
+    let types = fields.iter().map(|(_, typ)| typ);
+
+    quote! {
+        #[doc = #doc]
+        #cfg
+        #vis struct #name<T> {
+            inner: T,
+            #(#names: ::std::sync::OnceLock<#types>,)*
+        }
+
+        #cfg
+        impl<T> #name<T> {
+            ///Wraps value without cached results
+            #[inline(always)]
+            #vis fn new(inner: T) -> Self {
+                Self {
+                    inner,
+                    #(#names: ::std::sync::OnceLock::new(),)*
+                }
+            }
+
+            ///Returns mutable reference to wrapped value, clearing cached results
+            #[inline(always)]
+            #vis fn inner_mut(&mut self) -> &mut T {
+                #(self.#names = ::std::sync::OnceLock::new();)*
+                &mut self.inner
+            }
+
+            ///Returns wrapped value
+            #[inline(always)]
+            #vis fn into_inner(self) -> T {
+                self.inner
+            }
+        }
+
+        #cfg
+        #[automatically_derived]
+        impl<T> #core::ops::Deref for #name<T> {
+            type Target = T;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+    }
+}
+
+///Generates generic wrapper struct `name`, which dereferences to its only field and is converted into it by `into_inner`
+///
+///Parameter is left unbounded, as implementation of the trait for the struct bounds it, which is documented for `newtype` option.
+pub fn newtype(name: &syn::Ident, vis: &syn::Visibility, trait_name: &syn::Ident, core: &TokenStream) -> TokenStream {
+    let doc = format!("Wrapper of `{}` implementation, which forwards to it", trait_name);
+
+    quote! {
+        #[doc = #doc]
+        #vis struct #name<T>(pub T);
+
+        impl<T> #name<T> {
+            ///Returns wrapped value
+            #[inline(always)]
+            #vis fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        #[automatically_derived]
+        impl<T> #core::ops::Deref for #name<T> {
+            type Target = T;
+
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        #[automatically_derived]
+        impl<T> #core::ops::DerefMut for #name<T> {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+    }
+}
+
+///Generates enum `name` with variant for every target, which is converted from it
+///
+///Enum and conversions are gated by `cfg`, if any, the same way as implementations of targets.
+pub fn dispatch_enum(name: &syn::Ident, vis: &syn::Visibility, trait_name: &syn::Ident, variants: &[(syn::Ident, syn::Type)], cfg: Option<&syn::Attribute>, core: &TokenStream) -> TokenStream {
+    let doc = format!("Dispatch of `{}` over its implementations, which forwards to the held one", trait_name);
+    let names = variants.iter().map(|(variant, _)| variant);
+    let types = variants.iter().map(|(_, typ)| typ);
+    let conversions = variants.iter().map(|(variant, typ)| quote! {
+        #cfg
+        #[automatically_derived]
+        impl #core::convert::From<#typ> for #name {
+            #[inline(always)]
+            fn from(value: #typ) -> Self {
+                Self::#variant(value)
+            }
+        }
+    });
+
+    quote! {
+        #[doc = #doc]
+        #cfg
+        #vis enum #name {
+            #(
+                #[allow(missing_docs)]
+                #names(#types),
+            )*
+        }
+
+        #(
+            #conversions
+        )*
+    }
+}
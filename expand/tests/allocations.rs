@@ -0,0 +1,53 @@
+//!Guards number of allocations made by expansion of large trait against growing faster than the trait itself
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+mod large;
+
+///Allocator, which counts allocations made by the test binary
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+///Returns number of allocations made by expansion of trait with `methods` for 5 targets
+fn allocations(methods: usize) -> usize {
+    let args = large::args_with(3);
+    let input = large::input_with(methods);
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let output = auto_trait_expand::expand(args, input).expect("to expand");
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    drop(output);
+    allocations
+}
+
+#[test]
+fn should_allocate_in_proportion_to_methods() {
+    //Bound is loose, as every identifier is allocated outside of compiler, while re-parsing or re-collecting output per method exceeds it
+    const PER_METHOD: usize = 400;
+    const TARGETS: usize = 5;
+
+    let small = allocations(large::HUGE_METHODS / 5);
+    let huge = allocations(large::HUGE_METHODS);
+    assert!(huge <= small * 11 / 2, "Expansion of {} methods made {} allocations, while expansion of {} methods made {}", large::HUGE_METHODS, huge, large::HUGE_METHODS / 5, small);
+    assert!(huge <= large::HUGE_METHODS * TARGETS * PER_METHOD, "Expansion of trait with {} methods for {} targets made {} allocations", large::HUGE_METHODS, TARGETS, huge);
+}
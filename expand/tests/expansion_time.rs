@@ -0,0 +1,31 @@
+//!Guards time of expanding large trait for several targets against regressions
+
+use std::time::{Duration, Instant};
+
+mod large;
+
+#[test]
+fn should_expand_large_trait_in_time() {
+    //Limit is generous for unoptimized build on slow machine, while expansion, which is quadratic or re-parses output, exceeds it
+    const LIMIT: Duration = Duration::from_secs(2);
+    const REPEAT: u32 = 5;
+
+    let started = Instant::now();
+    for _ in 0..REPEAT {
+        let output = auto_trait_expand::expand(large::args(), large::input()).expect("to expand");
+        assert!(!output.is_empty());
+    }
+    let elapsed = started.elapsed() / REPEAT;
+    assert!(elapsed < LIMIT, "Expansion of trait with {} methods for 8 targets took {:?}", large::METHODS, elapsed);
+}
+
+#[test]
+fn should_expand_huge_trait_in_time() {
+    const LIMIT: Duration = Duration::from_secs(5);
+
+    let started = Instant::now();
+    let output = auto_trait_expand::expand(large::args_with(3), large::input_with(large::HUGE_METHODS)).expect("to expand");
+    assert!(!output.is_empty());
+    let elapsed = started.elapsed();
+    assert!(elapsed < LIMIT, "Expansion of trait with {} methods for 5 targets took {:?}", large::HUGE_METHODS, elapsed);
+}
@@ -0,0 +1,63 @@
+//!Synthetic trait, which is large enough for expansion time to be noticeable
+
+//Module is included by several tests and benchmark, each using only part of it
+#![allow(dead_code)]
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+///Number of methods within trait
+pub const METHODS: usize = 120;
+
+///Number of methods within trait with hundreds of methods, as generated by other code generators
+pub const HUGE_METHODS: usize = 500;
+
+///Returns arguments of attribute with 8 targets
+pub fn args() -> TokenStream {
+    args_with(6)
+}
+
+///Returns arguments of attribute with `wrappers` concrete targets, followed by `Box` and `&mut` targets
+pub fn args_with(wrappers: usize) -> TokenStream {
+    let wrappers = (0..wrappers).map(|idx| format_ident!("Wrapper{}", idx));
+    quote! {
+        #(#wrappers => u32,)* Box<T: Large>, &mut
+    }
+}
+
+///Returns trait with `METHODS` methods
+pub fn input() -> TokenStream {
+    input_with(METHODS)
+}
+
+///Returns trait with `count` documented methods, half of which have default implementation
+pub fn input_with(count: usize) -> TokenStream {
+    let methods = (0..count).map(|idx| {
+        let name = format_ident!("method{}", idx);
+        let doc = format!("Method number {}, which is documented to be cloned along with other attributes", idx);
+        let receiver = match idx % 2 {
+            0 => quote!(&self),
+            _ => quote!(&mut self),
+        };
+        let body = match idx % 4 < 2 {
+            true => quote!(;),
+            false => quote! {
+                {
+                    let total = (0..value).map(|value| value * 2).filter(|value| value % 3 == 0).sum::<u32>();
+                    total + other.len() as u32
+                }
+            },
+        };
+        quote! {
+            #[doc = #doc]
+            #[must_use]
+            fn #name(#receiver, value: u32, other: &str) -> u32 #body
+        }
+    });
+
+    quote! {
+        pub trait Large {
+            #(#methods)*
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//!Measures size of code generated for large trait with and without `shared` option
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+
+mod large;
+
+///Counts tokens, including tokens within groups
+fn count(tokens: TokenStream) -> usize {
+    tokens.into_iter().map(|token| match token {
+        TokenTree::Group(group) => 1 + count(group.stream()),
+        _ => 1,
+    }).sum()
+}
+
+#[test]
+fn should_generate_less_code_with_shared_helper() {
+    let args = large::args();
+    let plain = count(auto_trait_expand::expand(args.clone(), large::input()).expect("to expand"));
+    let shared = count(auto_trait_expand::expand(quote!(#args, shared), large::input()).expect("to expand"));
+    println!("Trait with {} methods for 8 targets: {} tokens, {} tokens with `shared` option", large::METHODS, plain, shared);
+    assert!(shared < plain, "Expansion with `shared` option has {} tokens, while expansion without it has {}", shared, plain);
+}
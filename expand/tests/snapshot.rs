@@ -0,0 +1,245 @@
+//!Compares expansion with snapshots in `tests/snapshots`
+//!
+//!Snapshot is written when it is missing, or when `AUTO_TRAIT_SNAPSHOT=overwrite` is set, to be reviewed within diff.
+
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use quote::quote;
+
+///Formats tokens with line per statement and item, so that snapshot diff points at changed line
+fn format(tokens: TokenStream) -> String {
+    fn write(tokens: TokenStream, depth: usize, result: &mut String) {
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            if result.ends_with('\n') {
+                result.push_str(&"    ".repeat(depth));
+            }
+            match token {
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Brace => {
+                            result.push_str("{\n");
+                            write(group.stream(), depth + 1, result);
+                            if !result.ends_with('\n') {
+                                result.push('\n');
+                            }
+                            result.push_str(&"    ".repeat(depth));
+                            result.push('}');
+                            match tokens.peek() {
+                                Some(TokenTree::Punct(punct)) if punct.as_char() == ';' || punct.as_char() == ',' => (),
+                                _ => result.push('\n'),
+                            }
+                            continue;
+                        },
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::None => ("", ""),
+                    };
+                    result.push_str(open);
+                    write(group.stream(), depth, result);
+                    while result.ends_with(' ') {
+                        result.pop();
+                    }
+                    result.push_str(close);
+                    result.push(' ');
+                },
+                TokenTree::Punct(punct) => {
+                    if punct.as_char() == ';' || punct.as_char() == ',' {
+                        while result.ends_with(' ') {
+                            result.pop();
+                        }
+                    }
+                    result.push(punct.as_char());
+                    match punct.as_char() {
+                        ';' => result.push('\n'),
+                        _ if punct.spacing() == Spacing::Joint => (),
+                        _ => result.push(' '),
+                    }
+                },
+                token => {
+                    result.push_str(&token.to_string());
+                    result.push(' ');
+                },
+            }
+        }
+    }
+
+    let mut result = String::new();
+    write(tokens, 0, &mut result);
+    result.lines().map(|line| format!("{}\n", line.trim_end())).collect()
+}
+
+fn assert_snapshot(name: &str, output: TokenStream) {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("snapshots").join(format!("{}.rs", name));
+    let output = format(output);
+
+    let overwrite = std::env::var_os("AUTO_TRAIT_SNAPSHOT").map(|value| value == "overwrite").unwrap_or(false);
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if !overwrite => assert_eq!(output, expected, "Expansion doesn't match snapshot {}, set AUTO_TRAIT_SNAPSHOT=overwrite to update it", path.display()),
+        _ => {
+            std::fs::create_dir_all(path.parent().expect("to have parent")).expect("to create snapshots directory");
+            std::fs::write(&path, output).expect("to write snapshot");
+        },
+    }
+}
+
+#[test]
+fn should_forward_references() {
+    let output = auto_trait_expand::expand(quote!(Box<T: Lolka>, Wrapper => u32), quote! {
+        pub trait Lolka {
+            fn lolka_ref(&self) -> u32;
+            fn lolka_mut(&mut self) -> u32;
+        }
+    }).expect("to expand");
+
+    assert_snapshot("references", output);
+}
+
+#[test]
+fn should_forward_owned_self() {
+    let output = auto_trait_expand::expand(quote!(Wrapper => u32, inline), quote! {
+        pub trait Lolka {
+            fn lolka(self) -> u32;
+            fn lolka_static() -> u32 {
+                0
+            }
+        }
+    }).expect("to expand");
+
+    assert_snapshot("owned", output);
+}
+
+#[test]
+fn should_forward_associated_items() {
+    let output = auto_trait_expand::expand(quote!(Wrapper => u32), quote! {
+        pub trait Lolka {
+            type Output;
+            const ID: u32;
+            fn lolka(&self) -> Self::Output;
+        }
+    }).expect("to expand");
+
+    assert_snapshot("associated", output);
+}
+
+#[test]
+fn should_expand_exported_trait() {
+    let output = auto_trait_expand::expand(quote!(export), quote! {
+        pub trait Lolka {
+            fn lolka(&self) -> u32;
+        }
+    }).expect("to expand");
+
+    assert_snapshot("export", output);
+}
+
+#[test]
+fn should_expand_derive() {
+    let output = auto_trait_expand::macros::auto_trait_derive(quote! {
+        #[auto_trait(Lolka)]
+        pub struct Wrapper(u32);
+    });
+
+    assert_snapshot("derive", output);
+}
+
+///Trait, which is forwarded by several attributes, with items of every kind
+fn representative() -> (TokenStream, TokenStream) {
+    let args = quote!(Box<T: Lolka>; inline(always), Wrapper => u32, &, no_mut);
+    let input = quote! {
+        #[auto_trait(Rc<T: Lolka>, Arc<T: Lolka>, no_mut)]
+        pub trait Lolka {
+            type Output;
+            const ID: u32;
+            fn lolka(&self) -> Self::Output;
+            fn add<V: Into<u32>>(&self, value: V, #[cfg(feature = "extra")] extra: u32) -> u32;
+            fn name(&self) -> &str {
+                "lolka"
+            }
+            fn reset(&mut self) {
+            }
+            fn create() -> u32 where Self: Sized;
+        }
+    };
+    (args, input)
+}
+
+#[test]
+fn should_expand_representative_trait() {
+    let (args, input) = representative();
+    let output = auto_trait_expand::expand(args, input).expect("to expand");
+
+    assert_snapshot("representative", output);
+}
+
+#[test]
+fn should_expand_deterministically() {
+    let (args, input) = representative();
+    let expected = auto_trait_expand::expand(args.clone(), input.clone()).expect("to expand").to_string();
+    for _ in 0..3 {
+        assert_eq!(auto_trait_expand::expand(args.clone(), input.clone()).expect("to expand").to_string(), expected);
+    }
+
+    //Expansion shares nothing between invocations, hence concurrent expansions are the same as well
+    let (args, input) = (args.to_string(), input.to_string());
+    let threads = (0..4).map(|_| {
+        let (args, input) = (args.clone(), input.clone());
+        std::thread::spawn(move || auto_trait_expand::expand(args.parse().expect("args"), input.parse().expect("input")).expect("to expand").to_string())
+    }).collect::<Vec<_>>();
+    for thread in threads {
+        assert_eq!(thread.join().expect("to expand"), expected);
+    }
+}
+
+#[test]
+fn should_follow_attribute_order() {
+    let input = quote! {
+        pub trait Lolka {
+            fn lolka(&self) -> u32;
+        }
+    };
+    //Implementations are scoped by `const _: () = { .. }`, which token stream lists in order of targets
+    let targets = |args| {
+        let output: syn::File = syn::parse2(auto_trait_expand::expand(args, input.clone()).expect("to expand")).expect("valid output");
+        output.items.into_iter().filter_map(|item| match item {
+            syn::Item::Const(item) => match *item.expr {
+                syn::Expr::Block(block) => block.block.stmts.into_iter().find_map(|stmt| match stmt {
+                    syn::Stmt::Item(syn::Item::Impl(item)) => {
+                        let typ = item.self_ty;
+                        Some(quote!(#typ).to_string())
+                    },
+                    _ => None,
+                }),
+                _ => None,
+            },
+            _ => None,
+        }).collect::<Vec<_>>()
+    };
+
+    assert_eq!(targets(quote!(Box<T: Lolka>, Rc<T: Lolka>, Arc<T: Lolka>)), ["Box < T >", "Rc < T >", "Arc < T >"]);
+    assert_eq!(targets(quote!(Arc<T: Lolka>, Box<T: Lolka>, Rc<T: Lolka>)), ["Arc < T >", "Box < T >", "Rc < T >"]);
+    //Targets with their own options keep their place among other targets
+    assert_eq!(targets(quote!(Box<T: Lolka>; inline, Rc<T: Lolka>, Arc<T: Lolka>; no_mut, Wrapper => u32)), ["Box < T >", "Rc < T >", "Arc < T >", "Wrapper"]);
+    assert_eq!(targets(quote!(Rc<T: Lolka>, Box<T: Lolka>; inline, Arc<T: Lolka>; no_mut)), ["Rc < T >", "Box < T >", "Arc < T >"]);
+}
+
+#[test]
+fn should_fail_for_every_target() {
+    let error = auto_trait_expand::expand(quote!(for<T> Box<T>, for<T> Rc<T>), quote! {
+        pub trait Lolka {
+            fn lolka(&self) -> u32;
+        }
+    }).expect_err("to fail");
+
+    let errors = error.into_iter().map(|error| error.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(|error| error.starts_with("[auto_trait::unbound_target]")), "{:?}", errors);
+}
+
+#[test]
+fn should_fail_for_non_trait() {
+    let error = auto_trait_expand::expand(quote!(Box<T: Lolka>), quote! {
+        pub struct Lolka;
+    }).expect_err("to fail");
+
+    assert!(error.to_string().starts_with("[auto_trait::not_trait]"), "{}", error);
+}
@@ -0,0 +1,19 @@
+pub trait Lolka {
+    type Output;
+    const ID : u32;
+    fn lolka (& self) -> Self :: Output;
+}
+const _ : () = {
+    const _ : fn () = || {
+        fn assert_impl < T : Lolka + ? :: core :: marker :: Sized > () {
+        }
+        assert_impl :: < u32 > ();
+    };
+    # [automatically_derived] impl Lolka for Wrapper where Wrapper : :: core :: ops :: Deref < Target = u32 > {
+        type Output = < u32 as Lolka > :: Output;
+        const ID : u32 = < u32 as Lolka > :: ID;
+        # [inline] fn lolka (& self) -> Self :: Output {
+            < u32 as Lolka > :: lolka (:: core :: ops :: Deref :: deref (self),)
+        }
+    }
+};
@@ -0,0 +1,5 @@
+const _ : () = {
+    __auto_trait_Lolka ! {
+        Wrapper
+    }
+};
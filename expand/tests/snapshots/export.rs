@@ -0,0 +1,20 @@
+pub trait Lolka {
+    fn lolka (& self) -> u32;
+}
+# [doc (hidden)] # [allow (unused_macros)] macro_rules ! __auto_trait_Lolka {
+    (@ complete $ ($ input : tt) *) => {
+        :: auto_trait :: __complete ! {
+            $ ($ input) * pub trait Lolka {
+                fn lolka (& self) -> u32;
+            }
+        }
+    };
+    ($ ($ target : tt) *) => {
+        :: auto_trait :: __forward ! {
+            [$ ($ target) *] pub trait Lolka {
+                fn lolka (& self) -> u32;
+            }
+        }
+    };
+}
+# [doc (hidden)] # [allow (unused_imports)] pub (crate) use __auto_trait_Lolka;
@@ -0,0 +1,21 @@
+pub trait Lolka {
+    fn lolka (self) -> u32;
+    fn lolka_static () -> u32 {
+        0
+    }
+}
+const _ : () = {
+    const _ : fn () = || {
+        fn assert_impl < T : Lolka + ? :: core :: marker :: Sized > () {
+        }
+        assert_impl :: < u32 > ();
+    };
+    # [automatically_derived] impl Lolka for Wrapper where Wrapper : :: core :: convert :: Into < u32 > {
+        # [inline] fn lolka (self) -> u32 {
+            < u32 as Lolka > :: lolka (:: core :: convert :: Into :: < u32 > :: into (self),)
+        }
+        # [inline] fn lolka_static () -> u32 {
+            < u32 as Lolka > :: lolka_static ()
+        }
+    }
+};
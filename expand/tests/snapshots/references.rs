@@ -0,0 +1,29 @@
+pub trait Lolka {
+    fn lolka_ref (& self) -> u32;
+    fn lolka_mut (& mut self) -> u32;
+}
+const _ : () = {
+    # [automatically_derived] impl < T : Lolka > Lolka for Box < T > {
+        # [inline] fn lolka_ref (& self) -> u32 {
+            < T as Lolka > :: lolka_ref (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn lolka_mut (& mut self) -> u32 {
+            < T as Lolka > :: lolka_mut (:: core :: ops :: DerefMut :: deref_mut (self),)
+        }
+    }
+};
+const _ : () = {
+    const _ : fn () = || {
+        fn assert_impl < T : Lolka + ? :: core :: marker :: Sized > () {
+        }
+        assert_impl :: < u32 > ();
+    };
+    # [automatically_derived] impl Lolka for Wrapper where Wrapper : :: core :: ops :: Deref < Target = u32 > {
+        # [inline] fn lolka_ref (& self) -> u32 {
+            < u32 as Lolka > :: lolka_ref (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn lolka_mut (& mut self) -> u32 {
+            < u32 as Lolka > :: lolka_mut (:: core :: ops :: DerefMut :: deref_mut (self),)
+        }
+    }
+};
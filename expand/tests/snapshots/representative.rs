@@ -0,0 +1,132 @@
+pub trait Lolka {
+    type Output;
+    const ID : u32;
+    fn lolka (& self) -> Self :: Output;
+    fn add < V : Into < u32 > > (& self, value : V, # [cfg (feature = "extra")] extra : u32) -> u32;
+    fn name (& self) -> & str {
+        "lolka"
+    }
+    fn reset (& mut self) {
+    }
+    fn create () -> u32 where Self : Sized;
+}
+const _ : () = {
+    # [automatically_derived] impl < T : Lolka > Lolka for Box < T > {
+        type Output = < T as Lolka > :: Output;
+        const ID : u32 = < T as Lolka > :: ID;
+        # [inline (always)] fn lolka (& self) -> Self :: Output {
+            < T as Lolka > :: lolka (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline (always)] fn add < V : Into < u32 > > (& self, value : V, # [cfg (feature = "extra")] extra : u32) -> u32 {
+            # [cfg (all (not (all (feature = "extra"))))] {
+                < T as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value,)
+            }
+            # [cfg (all (all (feature = "extra")))] {
+                < T as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value, extra,)
+            }
+        }
+        # [inline (always)] fn name (& self) -> & str {
+            < T as Lolka > :: name (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline (always)] fn create () -> u32 where Self : Sized {
+            < T as Lolka > :: create ()
+        }
+    }
+};
+const _ : () = {
+    const _ : fn () = || {
+        fn assert_impl < T : Lolka + ? :: core :: marker :: Sized > () {
+        }
+        assert_impl :: < u32 > ();
+    };
+    # [automatically_derived] impl Lolka for Wrapper where Wrapper : :: core :: ops :: Deref < Target = u32 > {
+        type Output = < u32 as Lolka > :: Output;
+        const ID : u32 = < u32 as Lolka > :: ID;
+        # [inline] fn lolka (& self) -> Self :: Output {
+            < u32 as Lolka > :: lolka (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn add < V : Into < u32 > > (& self, value : V, # [cfg (feature = "extra")] extra : u32) -> u32 {
+            # [cfg (all (not (all (feature = "extra"))))] {
+                < u32 as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value,)
+            }
+            # [cfg (all (all (feature = "extra")))] {
+                < u32 as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value, extra,)
+            }
+        }
+        # [inline] fn name (& self) -> & str {
+            < u32 as Lolka > :: name (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn create () -> u32 where Self : Sized {
+            < u32 as Lolka > :: create ()
+        }
+    }
+};
+const _ : () = {
+    # [automatically_derived] impl < '__auto_trait, __AutoTraitInner : Lolka + ? :: core :: marker :: Sized > Lolka for & '__auto_trait __AutoTraitInner {
+        type Output = < __AutoTraitInner as Lolka > :: Output;
+        const ID : u32 = < __AutoTraitInner as Lolka > :: ID;
+        # [inline] fn lolka (& self) -> Self :: Output {
+            < __AutoTraitInner as Lolka > :: lolka (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn add < V : Into < u32 > > (& self, value : V, # [cfg (feature = "extra")] extra : u32) -> u32 {
+            # [cfg (all (not (all (feature = "extra"))))] {
+                < __AutoTraitInner as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value,)
+            }
+            # [cfg (all (all (feature = "extra")))] {
+                < __AutoTraitInner as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value, extra,)
+            }
+        }
+        # [inline] fn name (& self) -> & str {
+            < __AutoTraitInner as Lolka > :: name (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn create () -> u32 where Self : Sized {
+            < __AutoTraitInner as Lolka > :: create ()
+        }
+    }
+};
+const _ : () = {
+    # [automatically_derived] impl < T : Lolka > Lolka for Rc < T > {
+        type Output = < T as Lolka > :: Output;
+        const ID : u32 = < T as Lolka > :: ID;
+        # [inline] fn lolka (& self) -> Self :: Output {
+            < T as Lolka > :: lolka (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn add < V : Into < u32 > > (& self, value : V, # [cfg (feature = "extra")] extra : u32) -> u32 {
+            # [cfg (all (not (all (feature = "extra"))))] {
+                < T as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value,)
+            }
+            # [cfg (all (all (feature = "extra")))] {
+                < T as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value, extra,)
+            }
+        }
+        # [inline] fn name (& self) -> & str {
+            < T as Lolka > :: name (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn create () -> u32 where Self : Sized {
+            < T as Lolka > :: create ()
+        }
+    }
+};
+const _ : () = {
+    # [automatically_derived] impl < T : Lolka > Lolka for Arc < T > {
+        type Output = < T as Lolka > :: Output;
+        const ID : u32 = < T as Lolka > :: ID;
+        # [inline] fn lolka (& self) -> Self :: Output {
+            < T as Lolka > :: lolka (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn add < V : Into < u32 > > (& self, value : V, # [cfg (feature = "extra")] extra : u32) -> u32 {
+            # [cfg (all (not (all (feature = "extra"))))] {
+                < T as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value,)
+            }
+            # [cfg (all (all (feature = "extra")))] {
+                < T as Lolka > :: add (:: core :: ops :: Deref :: deref (self), value, extra,)
+            }
+        }
+        # [inline] fn name (& self) -> & str {
+            < T as Lolka > :: name (:: core :: ops :: Deref :: deref (self),)
+        }
+        # [inline] fn create () -> u32 where Self : Sized {
+            < T as Lolka > :: create ()
+        }
+    }
+};
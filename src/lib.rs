@@ -1,17 +1,365 @@
 //!Automatic trait extension macro for wrapper types
+//!
+//!## Errors
+//!
+//!Every error is prefixed with identifier, e.g. `[auto_trait::unsupported_item]`, which stays the same, when wording of message changes,
+//!so that compile tests can match on it, while identifier is never reused for error with different meaning.
+//!
+//!## Expansion
+//!
+//!Macros are thin wrappers over `auto-trait-expand` crate, which exposes the same expansion over `proc_macro2` tokens,
+//!e.g. to inspect code generated for the trait.
+//!
+//!Generated code is free of warnings, including lints allowed by default, e.g. `unused_qualifications` or `unit_bindings`,
+//!so that it compiles within crate denying them, while lints still apply to user's own code, e.g. to unused parameter of default method.
+//!
+//!## Unsafe code
+//!
+//!Generated code contains no `unsafe` blocks and never allows `unsafe_code` lint, hence it compiles within crate with `#![forbid(unsafe_code)]`.
+//!The only unsafe code is `unsafe impl` of unsafe trait, which requires `unsafe` option, and which `unsafe_code` lint reports at the option,
+//!the same as implementation written by hand, hence forwarding unsafe trait is incompatible with `forbid(unsafe_code)`, while under `deny(unsafe_code)` it can be allowed for the trait.
+//!`via = transparent` relies on `TransparentWrapper` implementation of the target, which is written by user.
+//!
+//!## Features
+//!
+//!- `nightly-diagnostics` - emits warnings about suspicious forwarding, e.g. overriding default implementation of method, which requires nightly compiler.
+//!- `tracing` - enables `instrument` option, which annotates forwarding methods with `tracing::instrument`, while this crate stays without dependency on it.
+//!- `const-impl` - enables `const_impl` option, which generates `impl const` of `const trait`, which requires nightly compiler.
+//!- `bytemuck` - enables `via = transparent` option, which converts `#[repr(transparent)]` target through `bytemuck::TransparentWrapper`, while this crate stays without dependency on it.
+//!- `specialization` - enables `specialization` option, which generates blanket implementation with `default` methods, which requires nightly compiler.
+//!- `std` (default) - refers to `Box`, `Rc` and `Arc` through `::std`, and enables `Mutex` targets, `cache` option and `io` traits of `auto_trait_std`, which require `std`.
+//!
+//!## no_std
+//!
+//!Generated code refers to `core` only, except for keyword targets `Box`, `Rc`, `Arc` and `Box<dyn>`, which refer to `alloc`.
+//!Without `std` feature, they are referred through `::alloc`, hence `no_std` crate is to declare `extern crate alloc;`,
+//!while targets and options requiring `std` are reported with `[auto_trait::std_feature_required]`.
+//!
+//!Forwarding through `Deref`, `Into`, `field`, `via`, `RefCell`, `Weak`, guards, tuples, `match` dispatch, `enum`, `newtype`, `shared` and `stub` is available without `std`,
+//!as well as `try_trait` option, which error type of `auto-trait-delegate` is `no_std` itself.
+//!`Mutex` target and `cache` option require `std`.
+//!
+//!## Editions
+//!
+//!Generated code compiles within crate of any edition, as leading `::` of paths to `core`, `alloc` and `auto_trait`, which edition 2015 resolves relative to crate root,
+//!is spanned by the macro itself, hence errors are reported instead of missing `core` as well.
+//!Within crate of edition 2015, other crates, which generated code refers to (e.g. `bytemuck` of `via = transparent`), are to be declared at crate root, as they are for code written by hand.
+//!
+//!Trait object, which edition 2015 allows to write without `dyn`, is rejected within target with `[auto_trait::bare_trait_object]`, e.g. `Box<Lolka>`,
+//!as it would be parameter named after the trait, hence `Box<dyn>` or `Box<T: Lolka>` is to be written instead.
+//!Trait is parsed as syntax of the latest edition, hence its anonymous parameters are to be named, e.g. `_: u32`,
+//!and keywords of later editions are to be written as raw identifiers, e.g. `fn r#async(&self)`.
 #![warn(missing_docs)]
-#![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
+#![allow(clippy::style)]
 
 use proc_macro::TokenStream;
 
-use quote::quote;
-
 ///Generates trait implementation for specified type, relying on `Deref` or `Into` depending on
 ///whether `self` is reference or owned
 ///
 ///Note that this crate is only needed due to lack of specialization that would allow to have
 ///generic implementation over `T: Deref<Target=O>`
 ///
+///On nightly compiler `specialization` option generates such implementation, e.g. `#[auto_trait(specialization = Meters)]`
+///implements trait for every `T: ?Sized + Deref<Target = Meters>` with `default` methods, which requires `#![feature(min_specialization)]`,
+///so that wrapper can override some of them by its own implementation, while the rest is forwarded.
+///Blanket implementation cannot overlap with implementation for type to forward to, hence it is to be local type, which is known not to implement `Deref`.
+///
+///Each implementation is generated within anonymous scope `const _: () = { ... };`, which doesn't affect its visibility.
+///
+///## Target
+///
+///Target is written as type, which generic arguments can declare parameters of generated implementation:
+///
+///- `T: Bound` declares type parameter with bounds, e.g. `T: Iterator<Item = u32> + Lolka`. Parameter bound by annotated trait is used as forwarding target;
+///- `const N: usize` declares const parameter;
+///- Plain identifier, like `Config` in `Arc<Config>`, names concrete type, while unbounded type parameter is declared with `for<...>` prefix, e.g. `for<Meta> Tagged<T: Lolka, Meta>`,
+///  or by where clause, e.g. `Box<T> where T: Lolka`;
+///- Lifetimes, except `'static` and `'_`, are declared as lifetime parameters.
+///
+///Parameters can be declared at any nesting level, e.g. `Box<Inner<T: Lolka>>`.
+///
+///Parameters with default values can be omitted, in which case trait is implemented only for default.
+///
+///Bounds can be also specified with where clause at the end of target, e.g. `#[auto_trait(Box<T> where T: Lolka + Send)]`,
+///which is added to where clause of generated implementation. Parameter bound by annotated trait is used as forwarding target, same as with `T: Lolka`.
+///
+///Parameters can be also declared explicitly with `for<...>` prefix, e.g. `#[auto_trait(for<'a> Cow<'a, u32>)]` or `#[auto_trait(for<'a> &'a Wrapper)]`,
+///which is required for lifetimes, that are not arguments of type's path, and can be combined with declarations within type.
+///
+///Generic trait is implemented for every combination of its parameters, while bound to the trait is written without arguments,
+///e.g. `#[auto_trait(Box<T: Lolka>)]` for `trait Lolka<T>` implements `Lolka<T>` for `Box<__AutoTraitT>`, where `__AutoTraitT: Lolka<T>`.
+///Parameters of target, which are named the same as parameters of trait or its methods, are renamed in generated implementation, so that they don't collide.
+///Target can instead implement trait only for specific arguments, written after `for`, e.g. `#[auto_trait(Wrapper for Lolka<u32>)]`,
+///in which case trait's parameters are replaced by these arguments in forwarded methods and trait's where clause.
+///Bound to the trait with arguments selects them the same way, e.g. `#[auto_trait(Box<T: Lolka<u32>>)]` implements only `Lolka<u32>`.
+///
+///Macro invocation in type position, e.g. `#[auto_trait(wrapper!(u32))]`, is used as it is written and expanded later, hence it is treated as concrete target,
+///which parameters, if any, are declared with `for<...>` prefix.
+///
+///Tuple target, e.g. `#[auto_trait((A, B))]`, calls every method on each element in order, which suits observer-like traits.
+///Its elements, which are plain identifiers of non-primitive types, declare parameters implied to be bound by the trait, while methods must have receiver and return `()`,
+///otherwise they are left with their default implementation, if any. Arguments are cloned for every element, except the last one.
+///
+///Target can be followed by `=> <type>` to specify type to forward to, e.g. `#[auto_trait(Wrapper => u32)]`.
+///Then methods are called as `<u32 as Lolka>::lolka()` and `self` is converted by `Into::<u32>::into(self)`,
+///which resolves ambiguity for functions without receiver and wrappers with several `Into` implementations.
+///Without it, methods taking `self` by value are converted into parameter bound by the trait, e.g. `Into::<T>::into(self)`,
+///while concrete target requires it to forward them, otherwise they are left with their default implementation, if any.
+///Conversion is required by implementation only if trait has such methods, e.g. `Box<T>: Into<T>`.
+///Functions without receiver returning `Self`, `Option<Self>` or `Result<Self, E>`, e.g. `fn parse(text: &str) -> Self`, construct type to forward to,
+///which is converted by `Self::from(<u32 as Parse>::parse(text))`, hence implementation requires `Wrapper: From<u32>`,
+///while target without type to forward to leaves them with their default implementation, if any.
+///Methods taking `self` by reference are forwarded through `Deref`, hence implementation requires `Deref<Target = <type>>`,
+///unless `deref` or `deref_mut` option is specified, so that both conversions agree on type to forward to.
+///Concrete target asserts that type it forwards to implements the trait, so that it is reported at target, rather than by every forwarded call.
+///
+///Umbrella trait, which has no items of its own, e.g. `trait Storage: Read + Write {}`, has nothing to forward,
+///hence target itself is required to implement its supertraits, which is reported at concrete target.
+///Generic target implements the trait only when it implements supertraits, e.g. `impl<T: Storage> Storage for Box<T> where Box<T>: Read + Write`.
+///
+///## Options
+///
+///Attribute accepts comma separated list of targets, e.g. `#[auto_trait(Wrapper, Box<T: Lolka>)]`,
+///which is the same as specifying each target in separate attribute.
+///Attributes are recognized by their name, which can be qualified, e.g. `#[auto_trait::auto_trait(Wrapper)]`.
+///
+///Targets can be accompanied by comma separated options, which apply to every target of the attribute.
+///Options following target after `;` apply only to this target, until next target, e.g. `#[auto_trait(Wrapper; inline, no_owned, Box<T: Lolka>)]`,
+///while target's options take precedence over options of attribute:
+///
+///- `forward = <param>` - selects parameter to forward to, when several are bound by annotated trait;
+///- `dispatch = match` - forwards by matching on `Left` and `Right` variants of either-shaped enum, instead of `Deref`.
+///  Variants, each holding single value to forward to, can be listed explicitly, e.g. `#[auto_trait(Backend, dispatch = match(Fast, Safe))]`;
+///- `enum = <name>` - generates enum `<name>` with visibility of the trait, which has variant for every target of the attribute, with `From` conversion from it,
+///  and implements trait for it with `dispatch = match`, e.g. `#[auto_trait(enum = AnyLolka, Wrapper, Box<u32>; variant = Boxed)]`.
+///  Variant is named after last segment of target's path, unless it is named with `variant = <name>` option of target, while targets must be concrete types.
+///  Option applies to the whole attribute, hence it cannot be option of single target;
+///- `lock_mut` - forwards `&mut self` methods of `Mutex` target by locking it;
+///- `upgrade = panic | default` - policy on failure to upgrade `Weak` target, `panic` by default;
+///- `try_trait(<name>)` - generates fallible counterpart of the trait, which is described in [Fallible access](#fallible-access), and implements it for targets reached through `Mutex`, `RefCell` or `Weak`.
+///  Its methods return `Result<_, auto_trait_delegate::AccessError>`, unless error type is specified as `try_trait(<name>, error = <type>)`, which implements `From<AccessError>`;
+///- `shared` - writes bodies of methods once by hidden helper trait, generic over conversion into value to forward to, which targets only implement, reducing generated code of large traits with many targets.
+///  It applies to methods with receiver, which signature doesn't refer to `Self`, while it requires type to forward to, and is incompatible with generic trait, `const_impl` and targets reached through `Mutex`, `RefCell` or `Weak`;
+///- `deref = <path>` - function to use instead of `Deref::deref`, e.g. `deref = str::as_bytes`;
+///- `deref_mut = <path>` - function to use instead of `DerefMut::deref_mut`;
+///- `into = <path>` - function to use instead of `Into::into` for methods taking `self` by value, e.g. `into = Wrapper::into_inner`;
+///- `field = <field>` - forwards to field of target, instead of `Deref`, borrowing or moving it out, e.g. `#[auto_trait(Wrapper => u32, field = 0)]`.
+///  Field is moved out in place by methods taking `self`, hence it is preferable to conversion for large values;
+///- `via = <path>` - converts target by trait of `Delegate` shape (`delegate`, `delegate_mut` and `delegate_owned` methods with associated `Target`), instead of `Deref`, `DerefMut` and `Into`.
+///  `via = Delegate` refers to trait of `auto-trait-delegate` crate, which is to be dependency of the crate, while other paths are used as written, e.g. re-export `via = my_crate::Delegate`.
+///  Conversions specified by `deref`, `deref_mut` and `into` take precedence;
+///- `via = transparent` - converts target by `peel_ref`, `peel_mut` and `peel` of `bytemuck::TransparentWrapper`, which target implements for type it wraps, specified by `=> <type>` or by parameter bound by the trait.
+///  It requires `bytemuck` feature, while `bytemuck` is to be dependency of the crate;
+///- `pin_project(<field>)` - forwards methods taking `self: Pin<&mut Self>` or `self: Pin<&Self>` to pinned field through projection, generated by `#[pin_project::pin_project]` of the target,
+///  i.e. `self.project().<field>` and `self.project_ref().<field>`, e.g. `#[auto_trait(Timed<T: Ticker>, pin_project(inner))]`. Without it, such methods require default implementation;
+///- `crate_path = "<path>"` - path to `core` crate used by generated code, `"::core"` by default.
+///  Attribute, which contains only this option, sets it for every attribute of the trait, e.g. `#[auto_trait(crate_path = "::my_core")]`.
+///  Items generated along with the trait, e.g. by `newtype`, `enum` or `cache`, refer to `core` by it as well.
+///  Items of `std`, like `Mutex`, are always referred through `::std`, while generated code names nothing through prelude and calls even inherent methods by their paths,
+///  so that it compiles next to module named `core` or `std`, as well as within module or crate with `no_implicit_prelude` attribute;
+///- `inline(always)` or `inline(never)` - annotates generated methods with `#[inline(always)]` or `#[inline(never)]`, unless method is already annotated with `inline` attribute.
+///  Forwarding methods are annotated with `#[inline]` otherwise, so that forwarded call is inlined across crates, as direct call would be, while `inline` states it explicitly;
+///- `cfg(<predicate>)` - gates generated implementations with `#[cfg(<predicate>)]`, e.g. `cfg(feature = "alloc")`.
+///  Multiple predicates are combined with `all(...)`;
+///- `bound(<predicate>)` - adds predicate to where clause of generated implementations, e.g. `bound(T: Send + Sync)`.
+///  Multiple `bound` options accumulate;
+///- `debug` - prints generated implementations to stderr, which can be also enabled for every attribute by setting `AUTO_TRAIT_DEBUG` environment variable;
+///- `dry_run` - validates target and reports errors, but emits only the trait without generated implementations, which can be previewed along with `debug`;
+///- `only(<method>, ...)` - forwards only specified methods, leaving others with their default implementation;
+///- `except(<method>, ...)` - forwards all methods except specified, which are left with their default implementation;
+///- `no_mut` - forwards all methods except `&mut self` methods, which are left with their default implementation;
+///- `no_owned` - forwards all methods except methods taking `self` by value, which are left with their default implementation;
+///- `ref_only` - same as `no_mut` and `no_owned` together, forwarding only `&self` methods and functions without receiver;
+///- `no_builtin` - forwards target through `Deref`, even when it is named the same as built-in target, which is described in [Built-in targets](#built-in-targets),
+///  e.g. `#[auto_trait(my_sync::Mutex<T: Lolka>; no_builtin)]`. Option doesn't apply to keyword targets;
+///- `trait_path = <path>` - path to the trait used by generated implementations, e.g. `trait_path = crate::api::Lolka` for re-exported trait.
+///  Attribute, which contains only this option, sets it for every attribute of the trait;
+///- `test_target = <type>` - forwards to specified type in `cfg(test)` builds, e.g. `#[auto_trait(Wrapper, test_target = MockInner)]`,
+///  while implementation for other builds is gated by `cfg(not(test))`.
+///  Test target is borrowed through `AsRef` and `AsMut`, unless `deref` or `deref_mut` is specified, and `self` is converted by `Into`;
+///- `test(with = <function>)` - generates `#[cfg(test)]` tests, comparing result of every forwarded `&self` method without arguments, called on target and on its inner value.
+///  Function must return tuple of target and inner value, e.g. `test(with = make_wrapper)` for `fn make_wrapper() -> (Wrapper, u32)`.
+///  Methods with incomparable results can be omitted with `skip(<method>, ...)`, e.g. `test(with = make_wrapper, skip(lolka_iter))`;
+///- `stub` or `stub = noop` - generates stub implementation instead of forwarding, which methods panic with `unimplemented!("<target> does not support <trait>::<method>")`.
+///  With `noop`, methods returning `()` do nothing instead. Target doesn't need to provide any conversion, e.g. `#[auto_trait(NullBackend, stub)]`;
+///- `panic_msg = "<message>"` - message of every panic in generated methods, i.e. of `stub`, failure to upgrade `Weak`, to lock `Mutex` or to borrow `RefCell`.
+///  Message can contain `{trait}`, `{method}` and `{target}` placeholders, e.g. `panic_msg = "{target} cannot {method}"`;
+///- `generics = "<...> where ..."` - replaces generics and where clause of generated implementation, which are otherwise inferred from target,
+///  e.g. `#[auto_trait(Wrapper<'a, T>, generics = "<'a, T: Lolka + 'a> where T: Send")]`;
+///- `doc_note` - documents generated implementations with what they forward to, e.g. "forwards Lolka for Box<T> to T via Deref";
+///- `unsafe` - acknowledges that forwarding implementation upholds contract of unsafe trait, which is required to implement unsafe trait;
+///- `const_impl` - generates `impl const` for trait, declared as `const trait` (or `#[const_trait]` on older nightly), which requires `const-impl` feature.
+///- `specialization = <type>` - generates blanket implementation with `default` methods for every type dereferencing to `<type>` instead of targets, which requires `specialization` feature.
+///  Forwarding is restricted to const-callable strategies: `field`, `dispatch = match` and conversions by `deref`, `deref_mut` and `into` options, which are to be const functions;
+///- `also_ref` - additionally implements trait for `&Target`, forwarding `&self` methods and functions without receiver to target's implementation;
+///- `also_mut_ref` - additionally implements trait for `&mut Target`, forwarding `&self` and `&mut self` methods and functions without receiver.
+///  Other methods are left with their default implementation, which is required. Both options apply only to targets, which are not keywords;
+///- `strict` - reports error, listing every method or associated item, which is left with its default implementation instead of being forwarded,
+///  e.g. because of receiver filter or target's limitations. Items omitted by `skip`, `only` and `except` are not reported;
+///- `inherent` - additionally generates inherent methods of target with visibility of the trait, which delegate to trait's implementation,
+///  so that methods can be called without importing the trait. Option applies only to targets, which are not keywords;
+///- `map_return = <path>` - passes result of every forwarded call to specified function, e.g. `map_return = Wrapper::from` to wrap returned inner value.
+///  Method's option with the same name overrides it;
+///- `hooks = <path>` - calls `before` and `after` functions of specified module around every forwarded call, passing them name of the method,
+///  e.g. `hooks = my_hooks` for `fn before(method: &'static str)` and `fn after(method: &'static str)` within `my_hooks`;
+///- `instrument` - annotates every forwarding method with `#[tracing::instrument(skip(self))]`, which requires `tracing` feature.
+///  Path to `tracing` crate can be specified as `instrument = <path>`, while `instrument(on_call = <function>)` calls specified function
+///  before every forwarded call instead, passing it names of the trait and method, e.g. `fn on_call(trait_name: &str, method: &str)`;
+///- `newtype = <name>` - generates `struct <name><T>(pub T)` with visibility of the trait, which is target of the attribute instead of listed targets,
+///  forwarding to `T: Trait` through its field. It dereferences to `T` and is converted into it by `into_inner`, so that it decorates implementation with `hooks`, if any.
+///  Struct itself leaves `T` unbounded, as its bound would be required wherever struct is named, while bound of generic trait cannot be written without its arguments,
+///  hence only implementation of the trait requires `T: Trait`;
+///- `cache = <name>` - generates `struct <name><T>` with visibility of the trait, which is target of the attribute instead of listed targets,
+///  forwarding to `T: Trait` and storing result of every method annotated with `#[auto_trait(cache)]` in `std::sync::OnceLock` on first call.
+///  It is created by `new`, dereferences to `T` and is converted into it by `into_inner`, while `inner_mut` clears cached results;
+///- `module = <name>` - places generated implementations into module `<name>_<trait>` (e.g. `auto_impls_lolka_mut` for `module = auto_impls` and trait `LolkaMut`),
+///  which imports everything from enclosing module with `use super::*`. Attribute, which contains only this option, sets it for every attribute of the trait;
+///- `export` - exports trait's definition for [AutoTrait](derive.AutoTrait.html) derive, which can be specified in attribute without targets, e.g. `#[auto_trait(export)]`.
+///- `registered` - same as `export`, stating that trait is implemented for types registered by [auto_trait_register](attr.auto_trait_register.html) attribute, e.g. `#[auto_trait(registered)]`.
+///
+///## cfg_attr
+///
+///Attribute can be wrapped into `cfg_attr`, e.g. `#[cfg_attr(feature = "alloc", auto_trait(Box<T: Lolka>))]`,
+///which is the same as `#[auto_trait(Box<T: Lolka>, cfg(feature = "alloc"))]`.
+///Method options cannot be wrapped into `cfg_attr`.
+///
+///## Method options
+///
+///Trait methods can be annotated with `#[auto_trait(...)]`, which is removed from the emitted trait:
+///
+///- `skip` - omits method from every generated implementation, leaving it with its default implementation, which is required;
+///- `body = { ... }` - uses specified block as method's body in every generated implementation, instead of forwarding call;
+///- `body(<target>) = { ... }` - same as `body`, but only for specified target, which is written with parameter names only (e.g. `body(Box<T>)` for `Box<T: Lolka>`);
+///- `call = <path>` - calls specified function instead of forwarded method, with the same receiver and arguments (e.g. `call = OtherTrait::read_exact_at`);
+///- `map_return = <path>` - passes result of forwarded call to specified function, overriding target's option with the same name (e.g. `map_return = Result::ok`);
+///- `cache` - caches result of `&self` method without arguments in wrapper generated by `cache = <name>`, returning its clone on every call.
+///
+///## Associated items
+///
+///Associated consts and types are forwarded to type, which methods are forwarded to, e.g. `type Output = <T as Lolka>::Output;` for `Box<T: Lolka>`.
+///For target forwarding through `Deref`, it is `<Target as Deref>::Target`, unless type is specified with `=> <type>`.
+///Items can be annotated with `#[auto_trait(...)]` as well:
+///
+///- `skip` - omits item from every generated implementation, leaving it with its default, which is required;
+///- `value = <expr>` - uses specified value of associated const in every generated implementation, instead of forwarding (e.g. `#[auto_trait(value = 42)]`).
+///
+///Macro invocation within trait cannot be forwarded, as items it defines are unknown, but it can be skipped, provided these items have default implementation.
+///
+///## Keyword targets
+///
+///Target can be written as one of keywords `&`, `&mut`, `Box`, `Rc` and `Arc`, e.g. `#[auto_trait(&, &mut, Box)]`,
+///to implement trait for such pointer to any type implementing the trait:
+///
+///- `&` forwards only `&self` methods;
+///- `&mut` forwards `&self` and `&mut self` methods;
+///- `Box` forwards all methods, requiring pointee to be `Sized` only if there are methods taking `self` by value;
+///- `Rc` and `Arc` forward only `&self` methods.
+///
+///Functions without receiver are forwarded for every keyword.
+///Keywords `Box`, `Rc` and `Arc` can be also written by their path within `std` or `alloc`, e.g. `alloc::rc::Rc` or `::std::boxed::Box<dyn>`.
+///
+///Target can be also written as `Box<dyn>` or `&dyn` to implement trait for `Box<dyn Lolka + '_>` or `&dyn Lolka`,
+///forwarding to trait object, while `dyn` stands for both of them.
+///`Box<dyn>` forwards `&self` and `&mut self` methods, while `&dyn` forwards only `&self` methods.
+///Methods, which cannot be called on trait object (e.g. generic methods, methods returning `Self` and functions without receiver),
+///are left with their default implementation, which is required.
+///Trait must be compatible with trait object, otherwise every item preventing it is reported, e.g. generic method without `where Self: Sized`.
+///
+///Target can be written as `fn` to implement trait with exactly one required `&self` method for any function, closure or `dyn Fn`,
+///e.g. `impl<F: Fn(u32) -> u32 + ?Sized> Lolka for F`, which calls function with method's arguments, while other methods keep their default implementation.
+///Trait with several required methods, required method with other receiver, generic or async one, or associated type is rejected.
+///
+///```rust
+///use auto_trait::auto_trait;
+///
+///#[auto_trait(fn)]
+///pub trait Score {
+///    fn score(&self, value: u32) -> u32;
+///}
+///
+///fn total(score: &impl Score) -> u32 {
+///    score.score(1) + score.score(2)
+///}
+///
+///assert_eq!(total(&|value| value * 10), 30);
+///```
+///
+///## Mutex
+///
+///Target `Mutex<T: Lolka>`, possibly behind `Box`, `Rc` or `Arc` (e.g. `Arc<Mutex<T: Lolka>>`),
+///forwards `&self` methods by locking mutex.
+///`&mut self` methods require `lock_mut` option, while methods taking `self` by value cannot be forwarded.
+///
+///## RefCell
+///
+///Target `RefCell<T: Lolka>`, possibly behind `Box`, `Rc` or `Arc` (e.g. `Rc<RefCell<T: Lolka>>`),
+///forwards `&self` methods through `borrow()` and `&mut self` methods through `borrow_mut()`.
+///Methods taking `self` by value cannot be forwarded.
+///
+///## Weak
+///
+///Target `Weak<T: Lolka>` forwards `&self` methods by upgrading it for the duration of call.
+///When pointer cannot be upgraded, method either panics or returns `Default::default()`, according to `upgrade` option.
+///Other methods cannot be forwarded.
+///
+///Return value of methods forwarded through `Mutex`, `RefCell` or `Weak` cannot borrow receiver (e.g. `&str` or boxed future capturing `&'a self`),
+///as lock guard or upgraded pointer is dropped at the end of the call, hence such methods are left with their default implementation, which is required.
+///
+///## Fallible access
+///
+///Option `try_trait(TryLolka)` generates trait `TryLolka` with visibility of the trait, leaving the trait itself as it is.
+///It mirrors every `&self` and `&mut self` method, which is not skipped, doesn't refer to `Self` and doesn't borrow receiver from its result,
+///while every mirrored method returns `Result` of the original return type.
+///It is implemented for `Mutex`, `RefCell` and `Weak` targets of the attribute, which report failure to lock, borrow or upgrade as error instead of panicking,
+///while other targets implement only the trait itself. `AccessError` belongs to `auto-trait-delegate` crate, which is to be dependency of the crate.
+///
+///```rust
+///use auto_trait::auto_trait;
+///use auto_trait_delegate::AccessError;
+///
+///use core::cell::RefCell;
+///
+///#[auto_trait(RefCell<T: Lolka>, try_trait(TryLolka))]
+///pub trait Lolka {
+///    fn lolka(&self) -> u32;
+///}
+///
+///impl Lolka for u32 {
+///    fn lolka(&self) -> u32 {
+///        *self
+///    }
+///}
+///
+///let cell = RefCell::new(1u32);
+///assert_eq!(TryLolka::lolka(&cell), Ok(1));
+///let _guard = cell.borrow_mut();
+///assert_eq!(TryLolka::lolka(&cell), Err(AccessError::Borrowed));
+///```
+///
+///## Guards
+///
+///Guard targets `MutexGuard`, `RwLockReadGuard`, `RwLockWriteGuard`, `Ref` and `RefMut` (e.g. `MutexGuard<'a, T: Lolka>`)
+///forward through `Deref`, while `&mut self` methods are forwarded only for guards providing `DerefMut`.
+///Guard is never consumed, hence methods taking `self` by value are left with their default implementation.
+///
+///## Built-in targets
+///
+///Smart pointers `Box`, `Rc` and `Arc`, as well as `Mutex`, `RefCell`, `Weak` and guards, are recognized by last segment of target's path,
+///hence `Rc<T: Lolka>`, `std::rc::Rc<T: Lolka>`, `alloc::rc::Rc<T: Lolka>`, `::alloc::rc::Rc<T: Lolka>` and re-export, e.g. `crate::prelude::Rc<T: Lolka>`, are forwarded the same,
+///whether crate refers to them through `std` or through `alloc` within `no_std` crate.
+///Consequently, user's type named the same, e.g. `my_sync::Mutex<T: Lolka>`, is treated as built-in target,
+///unless it is annotated with `no_builtin` option, which forwards it through `Deref` as any other type.
+///
+///## Unsized targets
+///
+///Targets `str`, slices (e.g. `[u8]`) and trait objects (e.g. `dyn Base`) forward only by reference,
+///usually with help of `deref` option (e.g. `#[auto_trait(str, deref = str::as_bytes)]`).
+///Same applies to `Box`, `Rc` and `Arc` pointing to unsized type (e.g. `Box<dyn Base + Send + Sync>`),
+///which forward through `Deref` to the trait object, keeping all its bounds.
+///Same applies to targets forwarding to parameter with `?Sized` bound, e.g. `Box<T: Lolka + ?Sized>`.
+///Methods taking `self` by value or requiring `Self: Sized` are left with their default implementation.
+///
 ///## Example
 ///
 ///```rust
@@ -59,7 +407,7 @@ use quote::quote;
 ///   }
 ///}
 ///
-///#[auto_trait(Box<T: Lolka + From<Box<T>>>)]
+///#[auto_trait(Box<T: Lolka>)]
 ///pub trait Lolka {
 ///   fn lolka() -> u32;
 ///
@@ -101,161 +449,443 @@ use quote::quote;
 ///```
 #[proc_macro_attribute]
 pub fn auto_trait(args: TokenStream, input: TokenStream) -> TokenStream {
-    let mut input = syn::parse_macro_input!(input as syn::ItemTrait);
-    let args: syn::Type = match syn::parse(args) {
-        Ok(args) => args,
-        Err(error) => {
-            return syn::Error::new(error.span(), "Argument is required and must be a type").to_compile_error().into()
-        }
-    };
-
-    let mut args = vec![args];
-    let mut attrs_to_remove = Vec::new();
-
-    for idx in 0..input.attrs.len() {
-        let attr = &input.attrs[idx];
-
-        if attr.path().is_ident("auto_trait") {
-            match attr.parse_args() {
-                Ok(arg) => match arg {
-                    syn::Type::Paren(arg) => args.push(*arg.elem),
-                    arg => args.push(arg),
-                },
-                Err(error) => {
-                    return syn::Error::new(error.span(), "Argument is required and must be a type").to_compile_error().into()
-                }
-            }
-
-            attrs_to_remove.push(idx);
-        }
-    }
-
-    //We need to remove attributes that we're going to parse
-    for idx in attrs_to_remove {
-        input.attrs.swap_remove(idx);
-    }
-
-    let mut impls = Vec::new();
-
-    for mut args in args.drain(..) {
-        let trait_name = input.ident.clone();
-        let mut deref_type = None;
-        let type_generics = match args {
-            syn::Type::Path(ref mut typ) => match typ.path.segments.last_mut().expect("To have at least on type path segment").arguments {
-                syn::PathArguments::AngleBracketed(ref mut args) => {
-                    let mut result = args.clone();
-
-                    for arg in args.args.iter_mut() {
-                        if let syn::GenericArgument::Constraint(constraint) = arg {
-
-                            for param in constraint.bounds.iter() {
-                                if let syn::TypeParamBound::Trait(bound) = param {
-                                    if bound.path.is_ident(&trait_name) {
-                                        if let Some(ident) = deref_type.replace(constraint.ident.clone()) {
-                                            return syn::Error::new_spanned(ident, "Multiple bounds to trait, can be problematic so how about no?").to_compile_error().into();
-                                        }
-                                    }
-                                }
-                            }
-
-                            let mut segments = syn::punctuated::Punctuated::new();
-                            segments.push(syn::PathSegment {
-                                ident: constraint.ident.clone(),
-                                arguments: syn::PathArguments::None
-                            });
-
-                            *arg = syn::GenericArgument::Type(syn::Type::Path(syn::TypePath {
-                                qself: None,
-                                path: syn::Path {
-                                    leading_colon: None,
-                                    segments
-                                },
-                            }));
-                        }
-                    }
-
-                    if deref_type.is_none() && result.args.len() == 1 {
-                        result.args.last_mut();
-                    }
+    auto_trait_expand::macros::auto_trait(args.into(), input.into()).into()
+}
 
-                    Some(result)
-                },
-                syn::PathArguments::None => None,
-                syn::PathArguments::Parenthesized(ref args) => return syn::Error::new_spanned(args, "Unsupported type arguments").to_compile_error().into(),
-            },
-            other => {
-                println!("other={:?}", other);
-                return syn::Error::new_spanned(other, "Unsupported type").to_compile_error().into();
-            },
-        };
+///Sets default options for every [auto_trait](attr.auto_trait.html) attribute of the trait
+///
+///Options are written as in [auto_trait](attr.auto_trait.html) attribute, while options of each attribute override them.
+///Attribute is removed from the emitted trait:
+///
+///```rust
+///use auto_trait::{auto_trait, auto_trait_default};
+///
+///pub struct Wrapper(u32);
+///
+///impl Into<u32> for Wrapper {
+///    fn into(self) -> u32 {
+///        self.0
+///    }
+///}
+///
+///#[auto_trait(Box)]
+///#[auto_trait(Wrapper => u32, inline(always))]
+///#[auto_trait_default(inline, no_mut)]
+///pub trait Lolka {
+///    fn lolka(self) -> u32;
+///
+///    fn lolka_mut(&mut self) -> u32 {
+///        0
+///    }
+///}
+///
+///impl Lolka for u32 {
+///    fn lolka(self) -> u32 {
+///        self
+///    }
+///
+///    fn lolka_mut(&mut self) -> u32 {
+///        *self
+///    }
+///}
+///
+///assert_eq!(Wrapper(1).lolka(), 1);
+///assert_eq!(Box::new(2u32).lolka_mut(), 0);
+///```
+#[proc_macro_attribute]
+pub fn auto_trait_default(args: TokenStream, input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::auto_trait_default(args.into(), input.into()).into()
+}
 
-        let deref_name = deref_type.unwrap_or_else(|| trait_name.clone());
+///Generates trait implementations for the wrapper type, using trait exported by `#[auto_trait(export)]`
+///
+///Traits are listed in helper attribute `#[auto_trait(<trait>, ...)]`, e.g.
+///
+///```rust
+///use auto_trait::{auto_trait, AutoTrait};
+///
+///#[auto_trait(export)]
+///pub trait Lolka {
+///    fn lolka_ref(&self) -> u32;
+///}
+///
+///impl Lolka for u32 {
+///    fn lolka_ref(&self) -> u32 {
+///        *self
+///    }
+///}
+///
+///#[derive(AutoTrait)]
+///#[auto_trait(Lolka)]
+///pub struct Wrapper(u32);
+///
+///impl core::ops::Deref for Wrapper {
+///    type Target = u32;
+///    fn deref(&self) -> &Self::Target {
+///        &self.0
+///    }
+///}
+///
+///assert_eq!(Wrapper(1).lolka_ref(), 1);
+///```
+///
+///Wrapper is used as target, with its generic parameters and where clause.
+///Enum is forwarded by matching on its variants, each of which must hold single value implementing the trait,
+///e.g. `enum Backend { Fast(FastImpl), Safe(SafeImpl) }`, same as `dispatch = match(Fast, Safe)` option.
+///Unsafe trait is to be prefixed with `unsafe`, e.g. `#[auto_trait(unsafe Lolka)]`, to acknowledge its contract.
+///Exported trait can be used only within the same crate, by path to trait (e.g. `#[auto_trait(crate::traits::Lolka)]`),
+///while types in its method signatures must be nameable where derive is used.
+#[proc_macro_derive(AutoTrait, attributes(auto_trait))]
+pub fn auto_trait_derive(input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::auto_trait_derive(input.into()).into()
+}
 
-        let mut methods = Vec::new();
+///Registers type as target of traits with `#[auto_trait(registered)]`, which is implemented for every registered type
+///
+///Trait is annotated once and each wrapper registers itself, so that there is no list of targets to maintain:
+///
+///```rust
+///use auto_trait::{auto_trait, auto_trait_register};
+///
+///#[auto_trait(registered)]
+///pub trait Lolka {
+///    fn lolka_ref(&self) -> u32;
+///}
+///
+///impl Lolka for u32 {
+///    fn lolka_ref(&self) -> u32 {
+///        *self
+///    }
+///}
+///
+///#[auto_trait_register(Lolka)]
+///pub struct Wrapper(u32);
+///
+///impl core::ops::Deref for Wrapper {
+///    type Target = u32;
+///    fn deref(&self) -> &Self::Target {
+///        &self.0
+///    }
+///}
+///
+///assert_eq!(Wrapper(1).lolka_ref(), 1);
+///```
+///
+///Macros cannot share state between invocations, hence trait exports its definition, same as `export` option,
+///which type's attribute expands for the type, the same way as [AutoTrait](derive.AutoTrait.html) derive does.
+///Type is kept as it is written, while its traits follow the same rules as traits of derive, e.g. `unsafe` prefix and path to the trait.
+#[proc_macro_attribute]
+pub fn auto_trait_register(args: TokenStream, input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::auto_trait_register(args.into(), input.into()).into()
+}
 
-        for item in input.items.iter() {
-            match item {
-                syn::TraitItem::Fn(ref method) => {
-                    let method_name = method.sig.ident.clone();
-                    let mut method_args = Vec::new();
-                    for arg in method.sig.inputs.iter() {
-                        match arg {
-                            syn::FnArg::Receiver(arg) => {
-                                if arg.reference.is_some() {
-                                    if arg.mutability.is_some() {
-                                        method_args.push(quote! {
-                                            core::ops::DerefMut::deref_mut(self)
-                                        })
-                                    } else {
-                                        method_args.push(quote! {
-                                            core::ops::Deref::deref(self)
-                                        })
-                                    }
-                                } else {
-                                    method_args.push(quote! {
-                                        self.into()
-                                    })
-                                }
-                            },
-                            syn::FnArg::Typed(arg) => {
-                                let name = &arg.pat;
-                                method_args.push(quote! {
-                                    #name
-                                })
-                            },
-                        }
-                    }
+///Completes trait implementation with forwarding of every item, which is not written within it, using trait exported by `#[auto_trait(export)]`
+///
+///Items written by hand are kept as they are, while the rest is forwarded for implementation's type, as if it was target of [auto_trait](attr.auto_trait.html) attribute,
+///with implementation's generics and where clause:
+///
+///```rust
+///use auto_trait::auto_trait;
+///
+///#[auto_trait(export)]
+///pub trait Lolka {
+///    fn lolka_ref(&self) -> u32;
+///    fn lolka_mut(&mut self) -> u32;
+///}
+///
+///impl Lolka for u32 {
+///    fn lolka_ref(&self) -> u32 {
+///        *self
+///    }
+///
+///    fn lolka_mut(&mut self) -> u32 {
+///        *self += 1;
+///        *self
+///    }
+///}
+///
+///pub struct Wrapper(u32);
+///
+///impl core::ops::Deref for Wrapper {
+///    type Target = u32;
+///    fn deref(&self) -> &Self::Target {
+///        &self.0
+///    }
+///}
+///
+///impl core::ops::DerefMut for Wrapper {
+///    fn deref_mut(&mut self) -> &mut Self::Target {
+///        &mut self.0
+///    }
+///}
+///
+///#[auto_trait::complete]
+///impl Lolka for Wrapper {
+///    fn lolka_ref(&self) -> u32 {
+///        42
+///    }
+///}
+///
+///let mut wrapper = Wrapper(1);
+///assert_eq!(wrapper.lolka_ref(), 42);
+///assert_eq!(wrapper.lolka_mut(), 2);
+///```
+///
+///Attribute accepts options of [auto_trait](attr.auto_trait.html), which are preceded by type to forward to, if specified,
+///e.g. `#[auto_trait::complete(=> u32, no_owned)]`.
+///Trait is referred to the same way as by [AutoTrait](derive.AutoTrait.html) derive, hence it can be completed only within the same crate.
+#[proc_macro_attribute]
+pub fn complete(args: TokenStream, input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::complete(args.into(), input.into()).into()
+}
 
-                    let deref_block: syn::Block = syn::parse2(quote! {
-                        {
-                            #deref_name::#method_name(#(#method_args,)*)
-                        }
-                    }).unwrap();
+///Generates `Deref`, `DerefMut` and conversion into inner value for the wrapper struct
+///
+///Struct with single field forwards to it, otherwise field is selected with `#[auto_wrapper(inner)]`,
+///or with `#[auto_wrapper(field = <index | name>)]` on the struct, e.g. `#[auto_wrapper(field = 1)]`:
+///
+///```rust
+///use auto_trait::{auto_trait, AutoWrapper};
+///
+///#[derive(AutoWrapper)]
+///pub struct Wrapper(u32);
+///
+///#[derive(AutoWrapper)]
+///pub struct Named<T> {
+///    pub name: &'static str,
+///    #[auto_wrapper(inner)]
+///    pub value: Vec<T>,
+///}
+///
+///#[auto_trait(Wrapper => u32, for<T> Named<T> => Vec<T>)]
+///pub trait Lolka {
+///    fn lolka(self) -> usize;
+///}
+///
+///impl Lolka for u32 {
+///    fn lolka(self) -> usize {
+///        self as usize
+///    }
+///}
+///
+///impl<T> Lolka for Vec<T> {
+///    fn lolka(self) -> usize {
+///        self.len()
+///    }
+///}
+///
+///assert_eq!(Wrapper(1).lolka(), 1);
+///assert_eq!(Named { name: "lolka", value: vec![1, 2] }.lolka(), 2);
+///```
+///
+///Conversion is implemented as `From<Wrapper> for Inner`, which is disallowed by coherence rules, when field's type is type parameter (e.g. `Wrapper<T>(T)`),
+///hence only `Deref` and `DerefMut` are implemented then.
+///
+///`#[auto_wrapper(forwarder = <name>)]` additionally exports `macro_rules!` forwarder, which crates depending on wrapper's crate
+///invoke with their own traits, restated as for [auto_trait_impl](macro.auto_trait_impl.html), to forward them to wrapper's field.
+///Wrapper is to be reachable at crate's root, unless its path is specified with `path = crate::<path>`.
+///Path to `core` crate, which generated code refers to, is specified with `crate_path = "<path>"`, same as for [auto_trait](attr.auto_trait.html),
+///and is passed to forwarder as well.
+///Forwarder expands into `::auto_trait::auto_trait_impl!`, hence invoking crate also depends on `auto-trait`:
+///
+///```rust
+///use auto_trait::AutoWrapper;
+///
+///#[derive(AutoWrapper)]
+///#[auto_wrapper(forwarder = forward_to_meters)]
+///pub struct Meters(u32);
+///
+///pub trait Lolka {
+///    fn lolka(&self) -> u32;
+///}
+///
+///impl Lolka for u32 {
+///    fn lolka(&self) -> u32 {
+///        *self
+///    }
+///}
+///
+/////Other crates invoke it as `my_crate::forward_to_meters!`
+///forward_to_meters!(impl Lolka {
+///    fn lolka(&self) -> u32;
+///});
+///
+///fn main() {
+///    assert_eq!(Meters(1).lolka(), 1);
+///}
+///```
+///
+///Owned wrapper is converted by hidden inherent method, so that its field can stay private, while generic wrapper requires its field to implement the trait.
+///Forwarder, exported by the same crate, can be used only within textual scope, following the wrapper, rather than by path.
+#[proc_macro_derive(AutoWrapper, attributes(auto_wrapper))]
+pub fn auto_wrapper_derive(input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::auto_wrapper_derive(input.into()).into()
+}
 
-                    let mut method = method.clone();
-                    method.default = Some(deref_block);
-                    method.semi_token = None;
+///Defines wrapper struct, which derives [AutoWrapper](derive.AutoWrapper.html) and implements traits listed after `impl`, as [AutoTrait](derive.AutoTrait.html) does
+///
+///Traits are to be exported by `#[auto_trait(export)]` and forward to wrapper's field, including methods taking `self` by value.
+///Semicolon after `impl` list is optional:
+///
+///```rust
+///use auto_trait::{auto_trait, auto_wrap};
+///
+///#[auto_trait(export)]
+///pub trait Lolka {
+///    fn lolka(&self) -> u32;
+///}
+///
+///impl Lolka for u32 {
+///    fn lolka(&self) -> u32 {
+///        *self
+///    }
+///}
+///
+///auto_wrap!(pub struct Meters(u32); impl Lolka);
+///
+///assert_eq!(Meters(1).lolka(), 1);
+///assert_eq!(*Meters(2), 2);
+///```
+#[proc_macro]
+pub fn auto_wrap(input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::auto_wrap(input.into()).into()
+}
 
-                    methods.push(method);
-                },
-                unsupported => return syn::Error::new_spanned(unsupported, "Trait contains non-method definitions which is unsupported").to_compile_error().into(),
+///Generates implementations for exported trait, not to be used directly
+#[doc(hidden)]
+#[proc_macro]
+pub fn __forward(input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::forward(input.into()).into()
+}
 
-            }
-        }
+///Completes implementation of exported trait, not to be used directly
+#[doc(hidden)]
+#[proc_macro]
+pub fn __complete(input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::completion(input.into()).into()
+}
 
-        impls.push(quote! {
-            impl#type_generics #trait_name for #args {
-                #(
-                    #methods
-                )*
-            }
-        });
-    }
+///Generates trait implementations for trait, which cannot be annotated, e.g. defined in other crate
+///
+///Trait's methods are restated within braces, while targets and options are written as in [auto_trait](attr.auto_trait.html) attribute.
+///Unsafe trait is implemented by writing `unsafe impl`:
+///
+///```rust
+///use auto_trait::auto_trait_impl;
+///
+///pub struct Wrapper(u32);
+///
+///impl core::ops::Deref for Wrapper {
+///    type Target = u32;
+///    fn deref(&self) -> &Self::Target {
+///        &self.0
+///    }
+///}
+///
+///auto_trait_impl!(impl core::fmt::Display for Wrapper {
+///    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+///});
+///
+///assert_eq!(Wrapper(1).to_string(), "1");
+///```
+#[proc_macro]
+pub fn auto_trait_impl(input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::auto_trait_impl(input.into()).into()
+}
 
-    let mut result = quote! {
-        #input
-    };
-    result.extend(impls.drain(..));
+///Generates trait implementations for `std` traits, as [auto_trait_impl](macro.auto_trait_impl.html) does with signatures bundled within the crate
+///
+///Traits are listed before `for`, followed by targets and options, which are written as in [auto_trait](attr.auto_trait.html) attribute,
+///while `Self` stands for each trait, e.g. `Box<T: Self>`:
+///
+///```rust
+///use auto_trait::auto_trait_std;
+///
+///pub struct Stream(std::io::Cursor<Vec<u8>>);
+///
+///impl core::ops::Deref for Stream {
+///    type Target = std::io::Cursor<Vec<u8>>;
+///    fn deref(&self) -> &Self::Target {
+///        &self.0
+///    }
+///}
+///
+///impl core::ops::DerefMut for Stream {
+///    fn deref_mut(&mut self) -> &mut Self::Target {
+///        &mut self.0
+///    }
+///}
+///
+///auto_trait_std!(std::io::Read, std::io::Write, std::io::Seek for Stream);
+///
+///use std::io::{Read, Seek, Write};
+///
+///let mut stream = Stream(Default::default());
+///stream.write_all(b"lolka").unwrap();
+///stream.rewind().unwrap();
+///let mut text = String::new();
+///stream.read_to_string(&mut text).unwrap();
+///assert_eq!(text, "lolka");
+///```
+///
+///Supported traits are `io::Read`, `io::Write`, `io::Seek`, `io::BufRead`, `fmt::Display`, `fmt::Debug`, `iter::Iterator` and `hash::Hasher`,
+///which are referred to by path with or without leading segments (e.g. `Read`, `io::Read` or `std::io::Read`).
+///Besides required methods, provided ones, which implementations commonly specialize, are forwarded too (e.g. `Read::read_exact` or `Write::write_all`).
+///Signatures are versioned with the crate, hence methods, which are added to `std` later, are left with their default implementation.
+#[proc_macro]
+pub fn auto_trait_std(input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::auto_trait_std(input.into()).into()
+}
 
-    result.into()
+///Generates trait implementations for every trait within module, as if each is annotated with [auto_trait](attr.auto_trait.html)
+///
+///Targets and options are written as in [auto_trait](attr.auto_trait.html) attribute, while `Self` stands for each trait,
+///e.g. `Box<T: Self>`, and types are resolved within module. Trait is opted out by `#[auto_trait_all(skip)]`, while other items are left untouched:
+///
+///```rust
+///use auto_trait::auto_trait_all;
+///
+///#[auto_trait_all(Wrapper, Box<T: Self>)]
+///mod traits {
+///    pub struct Wrapper(pub u32);
+///
+///    impl core::ops::Deref for Wrapper {
+///        type Target = u32;
+///        fn deref(&self) -> &Self::Target {
+///            &self.0
+///        }
+///    }
+///
+///    pub trait Lolka {
+///        fn lolka(&self) -> u32;
+///    }
+///
+///    pub trait Lolka2 {
+///        fn lolka2(&self) -> u32;
+///    }
+///
+///    impl Lolka for u32 {
+///        fn lolka(&self) -> u32 {
+///            *self
+///        }
+///    }
+///
+///    impl Lolka2 for u32 {
+///        fn lolka2(&self) -> u32 {
+///            *self + 1
+///        }
+///    }
+///}
+///
+///use traits::{Lolka, Lolka2, Wrapper};
+///
+///assert_eq!(Wrapper(1).lolka(), 1);
+///assert_eq!(Box::new(1u32).lolka2(), 2);
+///```
+///
+///Traits can have their own `auto_trait` attributes, which are combined with module's, without need to import [auto_trait](attr.auto_trait.html).
+#[proc_macro_attribute]
+pub fn auto_trait_all(args: TokenStream, input: TokenStream) -> TokenStream {
+    auto_trait_expand::macros::auto_trait_all(args.into(), input.into()).into()
 }
+
@@ -0,0 +1,43 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(::std::boxed::Box<T: Lolka>, ::std::sync::Mutex<T: Lolka>, ::std::rc::Weak<T: Lolka>)]
+#[auto_trait(::std::vec::Vec<u32>, crate::Wrapper)]
+pub trait Lolka {
+    fn lolka(&self) -> usize;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl Lolka for [u32] {
+    fn lolka(&self) -> usize {
+        self.len()
+    }
+}
+
+fn lolka(lolka: impl Lolka) -> usize {
+    lolka.lolka()
+}
+
+#[test]
+fn should_accept_absolute_paths() {
+    assert_eq!(lolka(Box::new(1u32)), 1);
+    assert_eq!(lolka(std::sync::Mutex::new(2u32)), 2);
+    let rc = std::rc::Rc::new(3u32);
+    assert_eq!(lolka(std::rc::Rc::downgrade(&rc)), 3);
+
+    assert_eq!(lolka(vec![1u32, 2]), 2);
+    assert_eq!(lolka(Wrapper(4)), 4);
+}
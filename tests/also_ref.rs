@@ -0,0 +1,71 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.0
+    }
+}
+
+#[auto_trait(Wrapper => u32, also_ref, also_mut_ref)]
+pub trait Lolka {
+    fn lolka() -> u32;
+
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32 {
+        0
+    }
+
+    fn lolka_self(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka() -> u32 {
+        1
+    }
+
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self + 2
+    }
+}
+
+fn lolka<T: Lolka>(mut lolka: T) -> (u32, u32, u32, u32) {
+    (T::lolka(), lolka.lolka_ref(), lolka.lolka_mut(), lolka.lolka_self())
+}
+
+#[test]
+fn should_forward_for_references_to_target() {
+    let mut wrapper = Wrapper(1);
+
+    assert_eq!(lolka(&wrapper), (1, 1, 0, 0));
+    assert_eq!(lolka(&mut wrapper), (1, 1, 2, 0));
+    assert_eq!(wrapper.0, 2);
+    assert_eq!(lolka(wrapper), (1, 2, 3, 5));
+}
@@ -0,0 +1,62 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(Wrapper, Box<T: Lolka>)]
+#[auto_trait(std::rc::Rc<u32> => u32)]
+pub trait Lolka {
+    type Output;
+
+    const LEN: usize;
+
+    #[auto_trait(value = 42)]
+    const PINNED: usize;
+
+    #[auto_trait(skip)]
+    const DEFAULT: usize = 1;
+
+    fn lolka_output(&self) -> Self::Output;
+}
+
+pub struct Unrelated;
+
+#[auto_trait(Unrelated, stub)]
+pub trait LolkaStub {
+    #[auto_trait(value = "stub")]
+    const NAME: &'static str;
+
+    fn lolka_stub(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    type Output = u64;
+
+    const LEN: usize = 4;
+    const PINNED: usize = 0;
+    const DEFAULT: usize = 2;
+
+    fn lolka_output(&self) -> Self::Output {
+        *self as u64
+    }
+}
+
+fn lolka<T: Lolka<Output = u64>>(lolka: T) -> (u64, usize, usize, usize) {
+    (lolka.lolka_output(), T::LEN, T::PINNED, T::DEFAULT)
+}
+
+#[test]
+fn should_forward_associated_items() {
+    assert_eq!(lolka(Wrapper(1)), (1, 4, 42, 1));
+    assert_eq!(lolka(Box::new(2u32)), (2, 4, 42, 1));
+    assert_eq!(<std::rc::Rc<u32> as Lolka>::LEN, 4);
+    assert_eq!(<u32 as Lolka>::PINNED, 0);
+    assert_eq!(<Unrelated as LolkaStub>::NAME, "stub");
+}
@@ -0,0 +1,42 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Converted(u32);
+
+impl From<Converted> for u32 {
+    fn from(value: Converted) -> Self {
+        value.0
+    }
+}
+
+#[auto_trait(self::Wrapper, self::Converted => u32, Box<T: Lolka>)]
+pub trait Lolka {
+    fn lolka() -> u32;
+    fn lolka_sum(left: u32, right: u32) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka() -> u32 {
+        42
+    }
+
+    fn lolka_sum(left: u32, right: u32) -> u32 {
+        left + right + 1
+    }
+}
+
+#[test]
+fn should_forward_associated_function_to_inner_implementation() {
+    assert_eq!(<Wrapper as Lolka>::lolka(), 42);
+    assert_eq!(<Wrapper as Lolka>::lolka_sum(1, 2), 4);
+    assert_eq!(<Converted as Lolka>::lolka(), 42);
+    assert_eq!(<Box<u32> as Lolka>::lolka_sum(1, 2), 4);
+}
@@ -0,0 +1,113 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Bytes(Vec<u8>);
+
+//Every target is spelled the same way as the first attribute and as a later one
+
+#[auto_trait((self::Wrapper))]
+#[auto_trait(Rc)]
+pub trait FirstParen {
+    fn first_paren(&self) -> u32;
+}
+
+#[auto_trait(Rc)]
+#[auto_trait((self::Wrapper))]
+pub trait LaterParen {
+    fn later_paren(&self) -> u32;
+}
+
+#[auto_trait(Box<T: FirstGroup>; inline, Arc)]
+#[auto_trait(Rc)]
+pub trait FirstGroup {
+    fn first_group(&self) -> u32;
+}
+
+#[auto_trait(Rc)]
+#[auto_trait(Box<T: LaterGroup>; inline, Arc)]
+pub trait LaterGroup {
+    fn later_group(&self) -> u32;
+}
+
+#[auto_trait(for<'a> &'a Bytes)]
+#[auto_trait(Rc)]
+pub trait FirstFor {
+    fn first_for(&self) -> usize;
+}
+
+#[auto_trait(Rc)]
+#[auto_trait(for<'a> &'a Bytes)]
+pub trait LaterFor {
+    fn later_for(&self) -> usize;
+}
+
+macro_rules! impl_for {
+    ($($name:ident::$method:ident),*) => {
+        $(
+            impl $name for u32 {
+                fn $method(&self) -> u32 {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_for!(FirstParen::first_paren, LaterParen::later_paren, FirstGroup::first_group, LaterGroup::later_group);
+
+impl FirstFor for Bytes {
+    fn first_for(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl LaterFor for Bytes {
+    fn later_for(&self) -> usize {
+        self.0.len()
+    }
+}
+
+fn first_paren(value: impl FirstParen) -> u32 {
+    value.first_paren()
+}
+
+fn later_paren(value: impl LaterParen) -> u32 {
+    value.later_paren()
+}
+
+fn first_group(value: impl FirstGroup) -> u32 {
+    value.first_group()
+}
+
+fn later_group(value: impl LaterGroup) -> u32 {
+    value.later_group()
+}
+
+fn first_for(value: impl FirstFor) -> usize {
+    value.first_for()
+}
+
+fn later_for(value: impl LaterFor) -> usize {
+    value.later_for()
+}
+
+#[test]
+fn should_accept_same_target_regardless_of_attribute_position() {
+    assert_eq!(first_paren(Wrapper(1)), later_paren(Wrapper(1)));
+    assert_eq!(first_paren(std::rc::Rc::new(2u32)), later_paren(std::rc::Rc::new(2u32)));
+
+    assert_eq!(first_group(Box::new(3u32)), later_group(Box::new(3u32)));
+    assert_eq!(first_group(std::sync::Arc::new(4u32)), later_group(std::sync::Arc::new(4u32)));
+
+    let bytes = Bytes(vec![1, 2, 3]);
+    assert_eq!(first_for(&bytes), 3);
+    assert_eq!(later_for(&bytes), 3);
+}
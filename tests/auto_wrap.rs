@@ -0,0 +1,70 @@
+mod traits {
+    use auto_trait::auto_trait;
+
+    #[auto_trait(export)]
+    pub trait Lolka {
+        fn lolka_ref(&self) -> u32;
+
+        fn lolka_mut(&mut self) -> u32;
+    }
+
+    #[auto_trait(export)]
+    pub trait Lolka2 {
+        fn lolka2(self) -> u32;
+    }
+
+    impl Lolka for u32 {
+        fn lolka_ref(&self) -> u32 {
+            *self
+        }
+
+        fn lolka_mut(&mut self) -> u32 {
+            *self += 1;
+            *self
+        }
+    }
+
+    impl Lolka2 for u32 {
+        fn lolka2(self) -> u32 {
+            self * 2
+        }
+    }
+}
+
+mod wrappers {
+    use auto_trait::auto_wrap;
+
+    auto_wrap!(pub struct Meters(pub u32); impl crate::traits::Lolka, crate::traits::Lolka2);
+
+    auto_wrap! {
+        #[derive(Debug, PartialEq)]
+        pub struct Named {
+            #[auto_wrapper(inner)]
+            pub value: u32,
+            pub name: &'static str,
+        }
+        impl crate::traits::Lolka;
+    }
+
+    auto_wrap!(pub struct Plain(pub u32););
+}
+
+use traits::{Lolka, Lolka2};
+
+#[test]
+fn should_define_wrapper_with_implementations() {
+    let mut meters = wrappers::Meters(1);
+    assert_eq!(meters.lolka_ref(), 1);
+    assert_eq!(meters.lolka_mut(), 2);
+    assert_eq!(meters.lolka2(), 4);
+
+    let mut named = wrappers::Named {
+        value: 5,
+        name: "lolka",
+    };
+    assert_eq!(named.lolka_mut(), 6);
+    assert_eq!(named, wrappers::Named { value: 6, name: "lolka" });
+    assert_eq!(u32::from(named), 6);
+
+    assert_eq!(*wrappers::Plain(3), 3);
+}
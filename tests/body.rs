@@ -0,0 +1,63 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait(Wrapper, Box<T: Lolka>)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    #[auto_trait(body = { self.lolka_ref() * 2 })]
+    fn lolka_double(&self) -> u32;
+
+    #[auto_trait(body(Wrapper) = { self.0 += value; self.0 })]
+    fn lolka_add(&mut self, value: u32) -> u32;
+
+    #[auto_trait(body = { 0 })]
+    #[auto_trait(body(Box<T>) = { 1 })]
+    fn lolka_self(self) -> u32 where Self: Sized;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_double(&self) -> u32 {
+        unreachable!()
+    }
+
+    fn lolka_add(&mut self, value: u32) -> u32 {
+        *self += value * 10;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        unreachable!()
+    }
+}
+
+#[test]
+fn should_use_body_override() {
+    let mut wrapper = Wrapper(5);
+    assert_eq!(wrapper.lolka_double(), 10);
+    assert_eq!(wrapper.lolka_add(1), 6);
+    assert_eq!(wrapper.lolka_self(), 0);
+
+    let mut boxed = Box::new(5u32);
+    assert_eq!(boxed.lolka_double(), 10);
+    assert_eq!(boxed.lolka_add(1), 15);
+    assert_eq!(boxed.lolka_self(), 1);
+}
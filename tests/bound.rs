@@ -0,0 +1,100 @@
+use auto_trait::auto_trait;
+
+use std::sync::Arc;
+
+#[auto_trait(Arc<T: Lolka>, bound(T: Send + Sync), bound(T: 'static))]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+}
+
+fn spawn_lolka(lolka: impl Lolka + Send + 'static) -> u32 {
+    std::thread::spawn(move || lolka.lolka_ref()).join().expect("to join")
+}
+
+#[auto_trait(Box<T: LolkaSpawn>, bound(T: Clone + Send + 'static))]
+pub trait LolkaSpawn {
+    fn lolka_spawn(&self) -> u32;
+}
+
+impl LolkaSpawn for u32 {
+    fn lolka_spawn(&self) -> u32 {
+        *self + 1
+    }
+}
+
+//Only compiles with injected bounds
+fn spawn_boxed<T: LolkaSpawn + Clone + Send + 'static>(lolka: T) -> u32 {
+    let boxed = Box::new(lolka);
+    std::thread::spawn(move || boxed.lolka_spawn()).join().expect("to join")
+}
+
+#[test]
+fn should_add_bounds() {
+    assert_eq!(spawn_lolka(Arc::new(5u32)), 5);
+    assert_eq!(spawn_boxed(5u32), 6);
+}
+
+pub trait Legacy {
+    fn get(&self) -> u32;
+
+    fn get_mut(&mut self) -> u32;
+
+    fn get_default() -> u32;
+}
+
+#[auto_trait(Box<T: LolkaGet + Legacy>, std::rc::Rc<T> where T: Legacy + LolkaGet; no_mut)]
+pub trait LolkaGet {
+    fn get(&self) -> u32;
+
+    fn get_mut(&mut self) -> u32 {
+        0
+    }
+
+    fn get_default() -> u32;
+}
+
+impl Legacy for u32 {
+    fn get(&self) -> u32 {
+        0
+    }
+
+    fn get_mut(&mut self) -> u32 {
+        0
+    }
+
+    fn get_default() -> u32 {
+        0
+    }
+}
+
+impl LolkaGet for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+
+    fn get_mut(&mut self) -> u32 {
+        *self + 1
+    }
+
+    fn get_default() -> u32 {
+        2
+    }
+}
+
+#[test]
+fn should_call_trait_method_when_other_bound_has_same_name() {
+    let mut boxed = Box::new(5u32);
+    assert_eq!(LolkaGet::get(&boxed), 5);
+    assert_eq!(LolkaGet::get_mut(&mut boxed), 6);
+    assert_eq!(<Box<u32> as LolkaGet>::get_default(), 2);
+
+    let rc = std::rc::Rc::new(7u32);
+    assert_eq!(LolkaGet::get(&rc), 7);
+    assert_eq!(<std::rc::Rc<u32> as LolkaGet>::get_default(), 2);
+}
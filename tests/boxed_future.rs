@@ -0,0 +1,136 @@
+use auto_trait::auto_trait;
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::cell::RefCell;
+use std::sync::Arc;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+pub struct Generic<T>(T);
+
+impl<T> core::ops::Deref for Generic<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for Generic<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait(Wrapper, Generic<T: Fetch>, &, Box, Arc)]
+pub trait Fetch {
+    fn fetch<'a>(&'a self) -> Pin<Box<dyn Future<Output = u32> + Send + 'a>>;
+    fn fetch_elided(&self) -> Pin<Box<dyn Future<Output = u32> + Send + '_>>;
+    fn fetch_with<'a, 'b>(&'a self, add: &'b u32) -> Pin<Box<dyn Future<Output = u32> + Send + 'a>> where 'b: 'a;
+}
+
+#[auto_trait(Wrapper, Generic<T: Store>, &mut, Box)]
+pub trait Store {
+    fn store<'a>(&'a mut self, value: u32) -> Pin<Box<dyn Future<Output = u32> + Send + 'a>>;
+}
+
+impl Fetch for u32 {
+    fn fetch<'a>(&'a self) -> Pin<Box<dyn Future<Output = u32> + Send + 'a>> {
+        Box::pin(async move { *self })
+    }
+    fn fetch_elided(&self) -> Pin<Box<dyn Future<Output = u32> + Send + '_>> {
+        Box::pin(async move { *self + 1 })
+    }
+    fn fetch_with<'a, 'b>(&'a self, add: &'b u32) -> Pin<Box<dyn Future<Output = u32> + Send + 'a>> where 'b: 'a {
+        Box::pin(async move { *self + *add })
+    }
+}
+
+impl Store for u32 {
+    fn store<'a>(&'a mut self, value: u32) -> Pin<Box<dyn Future<Output = u32> + Send + 'a>> {
+        Box::pin(async move {
+            *self = value;
+            *self
+        })
+    }
+}
+
+//Future borrowing the cell cannot outlive its borrow, hence it's left with default implementation
+#[auto_trait(RefCell<T: Cached>)]
+pub trait Cached {
+    fn cached(&self) -> u32;
+    fn refresh<'a>(&'a self) -> Pin<Box<dyn Future<Output = u32> + 'a>> {
+        Box::pin(async move { self.cached() + 100 })
+    }
+}
+
+impl Cached for u32 {
+    fn cached(&self) -> u32 {
+        *self
+    }
+
+    fn refresh<'a>(&'a self) -> Pin<Box<dyn Future<Output = u32> + 'a>> {
+        Box::pin(async move { *self + 1 })
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = core::pin::pin!(future);
+    let mut cx = Context::from_waker(core::task::Waker::noop());
+    loop {
+        if let Poll::Ready(result) = future.as_mut().poll(&mut cx) {
+            break result;
+        }
+    }
+}
+
+#[test]
+fn should_forward_boxed_future_for_concrete_target() {
+    let mut wrapper = Wrapper(1);
+    assert_eq!(block_on(wrapper.fetch()), 1);
+    assert_eq!(block_on(wrapper.fetch_elided()), 2);
+    assert_eq!(block_on(wrapper.fetch_with(&2)), 3);
+    assert_eq!(block_on(wrapper.store(5)), 5);
+    assert_eq!(wrapper.0, 5);
+}
+
+#[test]
+fn should_forward_boxed_future_for_generic_target() {
+    let mut generic = Generic(Box::new(2u32));
+    assert_eq!(block_on(generic.fetch()), 2);
+    assert_eq!(block_on(generic.fetch_with(&3)), 5);
+    assert_eq!(block_on(generic.store(4)), 4);
+
+    let value = 3u32;
+    let reference = Generic(&value);
+    assert_eq!(block_on(reference.fetch_elided()), 4);
+
+    let mut value = 3u32;
+    let mut reference = Generic(&mut value);
+    assert_eq!(block_on(reference.store(6)), 6);
+    assert_eq!(value, 6);
+
+    let shared = Generic(Arc::new(7u32));
+    assert_eq!(block_on(shared.fetch()), 7);
+}
+
+#[test]
+fn should_leave_boxed_future_borrowing_cell_with_default() {
+    let cell = RefCell::new(1u32);
+    assert_eq!(cell.cached(), 1);
+    assert_eq!(block_on(cell.refresh()), 101);
+}
@@ -0,0 +1,109 @@
+use auto_trait::auto_trait;
+
+use core::cell::Cell;
+
+#[auto_trait(cache = CachedConfig)]
+pub trait Config {
+    #[auto_trait(cache)]
+    fn name(&self) -> String;
+    #[auto_trait(cache)]
+    fn r#type(&self) -> u32;
+    fn uncached(&self) -> u32;
+    fn set(&mut self, value: u32);
+    fn into_value(self) -> u32 where Self: Sized;
+}
+
+pub struct Loader {
+    value: u32,
+    loads: Cell<u32>,
+}
+
+impl Config for Loader {
+    fn name(&self) -> String {
+        self.loads.set(self.loads.get() + 1);
+        format!("lolka{}", self.value)
+    }
+
+    fn r#type(&self) -> u32 {
+        self.loads.set(self.loads.get() + 1);
+        self.value
+    }
+
+    fn uncached(&self) -> u32 {
+        self.loads.set(self.loads.get() + 1);
+        self.value
+    }
+
+    fn set(&mut self, value: u32) {
+        self.value = value;
+    }
+
+    fn into_value(self) -> u32 {
+        self.value
+    }
+}
+
+#[test]
+fn should_cache_marked_methods() {
+    let config = CachedConfig::new(Loader {
+        value: 1,
+        loads: Cell::new(0),
+    });
+    assert_eq!(config.name(), "lolka1");
+    assert_eq!(config.name(), "lolka1");
+    assert_eq!(config.r#type(), 1);
+    assert_eq!(config.r#type(), 1);
+    assert_eq!(config.loads.get(), 2);
+
+    assert_eq!(config.uncached(), 1);
+    assert_eq!(config.uncached(), 1);
+    assert_eq!(config.loads.get(), 4);
+}
+
+#[test]
+fn should_clear_cache_on_mutable_access() {
+    let mut config = CachedConfig::new(Loader {
+        value: 1,
+        loads: Cell::new(0),
+    });
+    assert_eq!(config.name(), "lolka1");
+    config.set(2);
+    assert_eq!(config.name(), "lolka2");
+    assert_eq!(config.loads.get(), 2);
+    assert_eq!(config.into_value(), 2);
+}
+
+mod internal {
+    use auto_trait::auto_trait;
+
+    //Cached call names the trait by its path, same as implementations placed into module
+    #[auto_trait(cache = CachedSettings, trait_path = crate::api::Settings, module = cached_impls)]
+    pub trait Settings {
+        #[auto_trait(cache)]
+        fn limit(&self) -> u32;
+    }
+}
+
+pub mod api {
+    pub use super::internal::{CachedSettings, Settings};
+}
+
+impl api::Settings for Loader {
+    fn limit(&self) -> u32 {
+        self.loads.set(self.loads.get() + 1);
+        self.value
+    }
+}
+
+#[test]
+fn should_cache_through_trait_path_within_module() {
+    use api::Settings;
+
+    let settings = api::CachedSettings::new(Loader {
+        value: 3,
+        loads: Cell::new(0),
+    });
+    assert_eq!(settings.limit(), 3);
+    assert_eq!(settings.limit(), 3);
+    assert_eq!(settings.loads.get(), 1);
+}
@@ -0,0 +1,70 @@
+use auto_trait::auto_trait;
+
+use std::sync::Mutex;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+pub trait Storage {
+    fn read_exact_at(&self, offset: u32) -> u32;
+
+    fn write_at(&mut self, offset: u32) -> u32;
+}
+
+impl Storage for u32 {
+    fn read_exact_at(&self, offset: u32) -> u32 {
+        *self + offset
+    }
+
+    fn write_at(&mut self, offset: u32) -> u32 {
+        *self += offset;
+        *self
+    }
+}
+
+#[auto_trait(Wrapper, Box<T: Lolka + Storage>, Mutex<T: Lolka + Storage>)]
+pub trait Lolka {
+    #[auto_trait(call = Storage::read_exact_at)]
+    fn read(&self, offset: u32) -> u32;
+
+    #[auto_trait(call = Storage::write_at)]
+    #[auto_trait(body(Mutex<T>) = { offset })]
+    fn write(&mut self, offset: u32) -> u32;
+}
+
+impl Lolka for u32 {
+    fn read(&self, _: u32) -> u32 {
+        unreachable!()
+    }
+
+    fn write(&mut self, _: u32) -> u32 {
+        unreachable!()
+    }
+}
+
+#[test]
+fn should_call_specified_function() {
+    let mut wrapper = Wrapper(5);
+    assert_eq!(wrapper.read(1), 6);
+    assert_eq!(wrapper.write(2), 7);
+
+    let mut boxed = Box::new(5u32);
+    assert_eq!(boxed.read(1), 6);
+    assert_eq!(boxed.write(2), 7);
+
+    let mut mutex = Mutex::new(5u32);
+    assert_eq!(mutex.read(1), 6);
+    assert_eq!(mutex.write(2), 2);
+}
@@ -0,0 +1,55 @@
+use auto_trait::auto_trait;
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[auto_trait(Box<T: Lolka>, cfg(all()))]
+#[auto_trait(Rc<T: Lolka>, cfg(any()))]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+}
+
+//Conflicts with generated implementation, unless it is disabled
+impl Lolka for Rc<u32> {
+    fn lolka_ref(&self) -> u32 {
+        0
+    }
+}
+
+#[auto_trait(Box<T: LolkaAll>, Arc<T: LolkaAll>, cfg(all()), cfg(any()))]
+pub trait LolkaAll {
+    fn lolka_all(&self) -> u32;
+}
+
+impl LolkaAll for u32 {
+    fn lolka_all(&self) -> u32 {
+        *self
+    }
+}
+
+impl LolkaAll for Box<u32> {
+    fn lolka_all(&self) -> u32 {
+        0
+    }
+}
+
+impl LolkaAll for Arc<u32> {
+    fn lolka_all(&self) -> u32 {
+        1
+    }
+}
+
+#[test]
+fn should_gate_implementation_with_cfg() {
+    assert_eq!(Box::new(5u32).lolka_ref(), 5);
+    assert_eq!(Rc::new(5u32).lolka_ref(), 0);
+
+    assert_eq!(Box::new(5u32).lolka_all(), 0);
+    assert_eq!(Arc::new(5u32).lolka_all(), 1);
+}
@@ -0,0 +1,31 @@
+use auto_trait::auto_trait;
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[auto_trait(Box<T: Lolka>)]
+#[cfg_attr(all(), auto_trait(Arc<T: Lolka>))]
+#[cfg_attr(any(), auto_trait(Rc<T: Lolka>))]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+}
+
+//Conflicts with generated implementation, unless it is disabled
+impl Lolka for Rc<u32> {
+    fn lolka_ref(&self) -> u32 {
+        0
+    }
+}
+
+#[test]
+fn should_gate_implementation_with_cfg_attr() {
+    assert_eq!(Box::new(5u32).lolka_ref(), 5);
+    assert_eq!(Arc::new(5u32).lolka_ref(), 5);
+    assert_eq!(Rc::new(5u32).lolka_ref(), 0);
+}
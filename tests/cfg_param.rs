@@ -0,0 +1,66 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+//`test` is always enabled for integration tests
+#[auto_trait(Wrapper; inherent, Box<T: Lolka>, std::sync::Mutex<T: Lolka>, &)]
+pub trait Lolka {
+    fn lolka_enabled(&self, #[cfg(test)] extra: u32) -> u32;
+
+    fn lolka_disabled(&self, #[cfg(not(test))] extra: u32) -> u32;
+
+    fn lolka_both(&self, #[cfg(test)] enabled: u32, value: u32, #[cfg(not(test))] disabled: u32) -> u32;
+
+    fn lolka_unit(&self, #[cfg(not(test))] disabled: u32, #[allow(unused_variables)] value: u32);
+}
+
+impl Lolka for u32 {
+    fn lolka_enabled(&self, #[cfg(test)] extra: u32) -> u32 {
+        *self + extra
+    }
+
+    fn lolka_disabled(&self, #[cfg(not(test))] extra: u32) -> u32 {
+        #[cfg(not(test))]
+        return *self + extra;
+        #[cfg(test)]
+        return *self;
+    }
+
+    fn lolka_both(&self, #[cfg(test)] enabled: u32, value: u32, #[cfg(not(test))] disabled: u32) -> u32 {
+        #[cfg(not(test))]
+        let value = value + disabled;
+        *self + enabled * 10 + value
+    }
+
+    fn lolka_unit(&self, #[cfg(not(test))] _disabled: u32, _value: u32) {
+    }
+}
+
+#[test]
+fn should_forward_parameters_enabled_by_cfg() {
+    assert_eq!(Wrapper(1).lolka_enabled(2), 3);
+    assert_eq!(Lolka::lolka_enabled(&Box::new(1u32), 3), 4);
+    assert_eq!(Lolka::lolka_enabled(&std::sync::Mutex::new(1u32), 4), 5);
+    assert_eq!(Lolka::lolka_enabled(&&1u32, 5), 6);
+
+    assert_eq!(Wrapper(1).lolka_both(2, 3), 24);
+    assert_eq!(Lolka::lolka_both(&Box::new(1u32), 2, 3), 24);
+}
+
+#[test]
+fn should_omit_parameters_disabled_by_cfg() {
+    assert_eq!(Wrapper(1).lolka_disabled(), 1);
+    assert_eq!(Lolka::lolka_disabled(&Box::new(2u32)), 2);
+    assert_eq!(Lolka::lolka_disabled(&std::sync::Mutex::new(3u32)), 3);
+    assert_eq!(Lolka::lolka_disabled(&&4u32), 4);
+
+    Wrapper(1).lolka_unit(2);
+    Lolka::lolka_unit(&Box::new(1u32), 2);
+}
@@ -0,0 +1,14 @@
+//Expected output lacks warnings, which are emitted with `nightly-diagnostics`
+#[cfg(not(feature = "nightly-diagnostics"))]
+#[test]
+fn compile_fail() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
+
+#[cfg(feature = "nightly-diagnostics")]
+#[test]
+fn compile_fail_nightly() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui-nightly/*.rs");
+}
@@ -0,0 +1,110 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(export)]
+pub trait Lolka {
+    type Output;
+    const ID: u32;
+
+    fn lolka_ref(&self) -> u32;
+    fn lolka_mut(&mut self) -> u32;
+    fn lolka_self(self) -> u32 where Self: Sized;
+    fn output(&self) -> Self::Output;
+}
+
+impl Lolka for u32 {
+    type Output = String;
+    const ID: u32 = 1;
+
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+
+    fn output(&self) -> Self::Output {
+        self.to_string()
+    }
+}
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait::complete(=> u32)]
+impl Lolka for Wrapper {
+    const ID: u32 = 2;
+
+    fn lolka_ref(&self) -> u32 {
+        42
+    }
+
+    fn lolka_self(self) -> u32 {
+        self.0
+    }
+}
+
+pub struct Generic<T>(T);
+
+impl<T> core::ops::Deref for Generic<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for Generic<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait::complete(no_owned)]
+impl<T> Lolka for Generic<T> where T: Lolka {
+    type Output = Option<T::Output>;
+
+    fn lolka_self(self) -> u32 {
+        0
+    }
+
+    fn output(&self) -> Self::Output {
+        Some(self.0.output())
+    }
+}
+
+#[test]
+fn should_keep_written_items() {
+    let mut wrapper = Wrapper(1);
+    assert_eq!(wrapper.lolka_ref(), 42);
+    assert_eq!(wrapper.lolka_mut(), 2);
+    assert_eq!(wrapper.output(), "2");
+    assert_eq!(<Wrapper as Lolka>::ID, 2);
+    assert_eq!(wrapper.lolka_self(), 2);
+}
+
+#[test]
+fn should_complete_generic_implementation() {
+    let mut generic = Generic(1u32);
+    assert_eq!(generic.lolka_ref(), 1);
+    assert_eq!(generic.lolka_mut(), 2);
+    assert_eq!(generic.output(), Some("2".to_owned()));
+    assert_eq!(<Generic<u32> as Lolka>::ID, 1);
+    assert_eq!(generic.lolka_self(), 0);
+}
@@ -0,0 +1,151 @@
+use auto_trait::auto_trait;
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+pub struct Array<const N: usize>([u32; N]);
+
+impl<const N: usize> core::ops::Deref for Array<N> {
+    type Target = [u32];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Pair<A, B>(A, B);
+
+impl<A, B> core::ops::Deref for Pair<A, B> {
+    type Target = B;
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+pub struct Tagged<'a, T>(pub &'a str, T);
+
+pub struct Config(u32);
+
+impl<'a, T> core::ops::Deref for Tagged<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+//Concrete arguments
+#[auto_trait(Vec<u32>, Cow<'static, [u32]>, Array<3>, Pair<u8, u32>, Tagged<'static, u32>)]
+#[auto_trait(Arc<Config>, Vec<String>, Pair<Config, Config>)]
+pub trait Lolka {
+    fn lolka(&self) -> usize;
+}
+
+//Generic arguments
+#[auto_trait(for<A> Pair<A, T: Kek>, Tagged<'a, T: Kek>, Array<const N: usize>)]
+pub trait Kek {
+    fn kek(&self) -> usize;
+}
+
+//Mixed arguments
+#[auto_trait(Pair<u8, T: Cheburek>, Tagged<'static, T: Cheburek>, for<'a> Cow<'a, [u32]>, Array<2>)]
+#[auto_trait(Pair<Config, T: Cheburek>, Pair<String, String>)]
+pub trait Cheburek {
+    fn cheburek(&self) -> usize;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl Lolka for [u32] {
+    fn lolka(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Lolka for Config {
+    fn lolka(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Lolka for [String] {
+    fn lolka(&self) -> usize {
+        self.iter().map(String::len).sum()
+    }
+}
+
+impl Kek for u32 {
+    fn kek(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl Kek for [u32] {
+    fn kek(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Cheburek for u32 {
+    fn cheburek(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl Cheburek for String {
+    fn cheburek(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Cheburek for [u32] {
+    fn cheburek(&self) -> usize {
+        self.len()
+    }
+}
+
+fn lolka(lolka: impl Lolka) -> usize {
+    lolka.lolka()
+}
+
+fn kek(kek: impl Kek) -> usize {
+    kek.kek()
+}
+
+fn cheburek(cheburek: impl Cheburek) -> usize {
+    cheburek.cheburek()
+}
+
+#[test]
+fn should_implement_for_concrete_arguments() {
+    assert_eq!(lolka(vec![1u32, 2]), 2);
+    assert_eq!(lolka(Cow::<'static, [u32]>::Owned(vec![1])), 1);
+    assert_eq!(lolka(Array([1, 2, 3])), 3);
+    assert_eq!(lolka(Pair(1u8, 4u32)), 4);
+    assert_eq!(lolka(Tagged("lolka", 5u32)), 5);
+    assert_eq!(lolka(Arc::new(Config(6))), 6);
+    assert_eq!(lolka(vec![String::from("lolka"), String::from("kek")]), 8);
+    assert_eq!(lolka(Pair(Config(1), Config(7))), 7);
+}
+
+#[test]
+fn should_implement_for_generic_arguments() {
+    assert_eq!(kek(Pair("kek", 6u32)), 6);
+    assert_eq!(kek(Pair(1u16, Pair(2u8, 7u32))), 7);
+    let tag = String::from("kek");
+    assert_eq!(kek(Tagged(&tag, 8u32)), 8);
+    assert_eq!(kek(Array([1, 2, 3, 4])), 4);
+}
+
+#[test]
+fn should_implement_for_mixed_arguments() {
+    assert_eq!(cheburek(Pair(1u8, 9u32)), 9);
+    assert_eq!(cheburek(Tagged("cheburek", 10u32)), 10);
+    let values = [1u32, 2];
+    assert_eq!(cheburek(Cow::Borrowed(&values[..])), 2);
+    assert_eq!(cheburek(Array([1, 2])), 2);
+    assert_eq!(cheburek(Pair(Config(1), 11u32)), 11);
+    assert_eq!(cheburek(Pair(String::from("cheburek"), String::from("lolka"))), 5);
+}
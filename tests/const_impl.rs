@@ -0,0 +1,6 @@
+#![cfg_attr(feature = "const-impl", feature(const_trait_impl))]
+
+//Syntax of const traits requires nightly compiler, hence it is within module, which is not loaded otherwise
+#[cfg(feature = "const-impl")]
+#[path = "const_impl/nightly.rs"]
+mod nightly;
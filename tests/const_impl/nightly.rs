@@ -0,0 +1,38 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+pub enum Backend {
+    Fast(u32),
+    Slow(Wrapper),
+}
+
+#[auto_trait(Wrapper => u32, field = 0, const_impl)]
+#[auto_trait(Backend, dispatch = match(Fast, Slow), const_impl)]
+pub const trait Lolka {
+    fn lolka_ref(&self) -> u32;
+    fn lolka_self(self) -> u32;
+}
+
+impl const Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+}
+
+const REF: u32 = Wrapper(1).lolka_ref();
+const OWNED: u32 = Wrapper(2).lolka_self();
+const FAST: u32 = Backend::Fast(3).lolka_ref();
+const SLOW: u32 = Backend::Slow(Wrapper(4)).lolka_self();
+
+#[test]
+fn should_forward_in_const_context() {
+    assert_eq!(REF, 1);
+    assert_eq!(OWNED, 20);
+    assert_eq!(FAST, 3);
+    assert_eq!(SLOW, 40);
+}
@@ -0,0 +1,71 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<u32> for Wrapper {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+#[auto_trait(Wrapper => u32, Box<T: Parse>)]
+pub trait Parse: Sized {
+    fn parse(text: &str) -> Self;
+    fn try_parse(text: &str) -> Option<Self>;
+    fn parse_checked(text: &str) -> Result<Self, core::num::ParseIntError>;
+    fn value(&self) -> u32;
+}
+
+impl Parse for u32 {
+    fn parse(text: &str) -> Self {
+        text.parse().unwrap_or_default()
+    }
+
+    fn try_parse(text: &str) -> Option<Self> {
+        text.parse().ok()
+    }
+
+    fn parse_checked(text: &str) -> Result<Self, core::num::ParseIntError> {
+        text.parse()
+    }
+
+    fn value(&self) -> u32 {
+        *self
+    }
+}
+
+#[auto_trait(Wrapper => u32)]
+pub trait Initial {
+    fn initial() -> Self;
+}
+
+impl Initial for u32 {
+    fn initial() -> Self {
+        42
+    }
+}
+
+#[test]
+fn should_construct_from_inner_type() {
+    assert_eq!(<Wrapper as Parse>::parse("1").0, 1);
+    assert_eq!(<Wrapper as Parse>::try_parse("2").map(|wrapper| wrapper.0), Some(2));
+    assert!(<Wrapper as Parse>::try_parse("lolka").is_none());
+    assert_eq!(<Wrapper as Parse>::parse_checked("3").map(|wrapper| wrapper.0), Ok(3));
+    assert!(<Wrapper as Parse>::parse_checked("lolka").is_err());
+    assert_eq!(<Wrapper as Initial>::initial().0, 42);
+}
+
+#[test]
+fn should_construct_pointer() {
+    let boxed = <Box<u32> as Parse>::parse("4");
+    assert_eq!(*boxed, 4);
+    assert_eq!(<Box<u32> as Parse>::try_parse("5").as_deref(), Some(&5));
+    assert_eq!(<Box<u32> as Parse>::parse_checked("6").map(|boxed| boxed.value()), Ok(6));
+}
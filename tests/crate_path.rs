@@ -0,0 +1,155 @@
+mod shadowed {
+    use auto_trait::auto_trait;
+
+    //Shadows `core` crate for any path without leading `::`
+    #[allow(dead_code)]
+    mod core {
+    }
+
+    pub struct Wrapper(pub u32);
+
+    impl ::core::ops::Deref for Wrapper {
+        type Target = u32;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl ::core::ops::DerefMut for Wrapper {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    impl From<Wrapper> for u32 {
+        fn from(wrapper: Wrapper) -> u32 {
+            wrapper.0
+        }
+    }
+
+    #[auto_trait(Wrapper => u32)]
+    pub trait Lolka {
+        fn lolka_ref(&self) -> u32;
+
+        fn lolka_mut(&mut self) -> u32;
+
+        fn lolka_self(self) -> u32;
+    }
+
+    impl Lolka for u32 {
+        fn lolka_ref(&self) -> u32 {
+            *self
+        }
+
+        fn lolka_mut(&mut self) -> u32 {
+            *self += 1;
+            *self
+        }
+
+        fn lolka_self(self) -> u32 {
+            self * 10
+        }
+    }
+}
+
+mod custom {
+    use auto_trait::auto_trait;
+
+    use ::core as my_core;
+
+    pub struct Wrapper(pub u32);
+
+    impl my_core::ops::Deref for Wrapper {
+        type Target = u32;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    #[auto_trait(crate_path = "my_core")]
+    #[auto_trait(Wrapper)]
+    pub trait Lolka {
+        fn lolka_ref(&self) -> u32;
+    }
+
+    impl Lolka for u32 {
+        fn lolka_ref(&self) -> u32 {
+            *self
+        }
+    }
+
+    #[auto_trait(Wrapper, crate_path = "self::my_core")]
+    pub trait LolkaAttribute {
+        fn lolka_attribute(&self) -> u32;
+    }
+
+    impl LolkaAttribute for u32 {
+        fn lolka_attribute(&self) -> u32 {
+            *self + 1
+        }
+    }
+}
+
+mod generated {
+    use auto_trait::{auto_trait, AutoWrapper};
+
+    use ::core as my_core;
+
+    #[derive(AutoWrapper)]
+    #[auto_wrapper(crate_path = "my_core")]
+    pub struct Wrapper(pub u32);
+
+    #[derive(AutoWrapper)]
+    #[auto_wrapper(crate_path = "self::my_core")]
+    pub struct Meters(pub u32);
+
+    pub fn make_wrapper() -> (Wrapper, u32) {
+        (Wrapper(5), 5)
+    }
+
+    #[auto_trait(crate_path = "my_core")]
+    #[auto_trait(&, Box, Rc, Arc)]
+    #[auto_trait(Wrapper, test(with = make_wrapper))]
+    #[auto_trait(newtype = Logged)]
+    #[auto_trait(enum = AnyLolka, Meters => u32)]
+    #[auto_trait(cache = Cached)]
+    pub trait Lolka {
+        #[auto_trait(cache)]
+        fn lolka(&self) -> u32;
+    }
+
+    impl Lolka for u32 {
+        fn lolka(&self) -> u32 {
+            *self
+        }
+    }
+}
+
+#[test]
+fn should_refer_to_core_by_crate_path_in_generated_items() {
+    use generated::Lolka;
+
+    assert_eq!(<&u32 as Lolka>::lolka(&&1), 1);
+    assert_eq!(Box::new(2u32).lolka(), 2);
+    assert_eq!(std::rc::Rc::new(3u32).lolka(), 3);
+    assert_eq!(std::sync::Arc::new(4u32).lolka(), 4);
+    assert_eq!(generated::Wrapper(5).lolka(), 5);
+    assert_eq!(generated::Logged(6u32).lolka(), 6);
+    assert_eq!(generated::AnyLolka::from(generated::Meters(7)).lolka(), 7);
+    assert_eq!(generated::Cached::new(8u32).lolka(), 8);
+}
+
+#[test]
+fn should_use_crate_path() {
+    use shadowed::Lolka;
+    use custom::LolkaAttribute;
+
+    let mut wrapper = shadowed::Wrapper(5);
+    assert_eq!(wrapper.lolka_ref(), 5);
+    assert_eq!(wrapper.lolka_mut(), 6);
+    assert_eq!(wrapper.lolka_self(), 60);
+
+    let wrapper = custom::Wrapper(5);
+    assert_eq!(custom::Lolka::lolka_ref(&wrapper), 5);
+    assert_eq!(wrapper.lolka_attribute(), 6);
+}
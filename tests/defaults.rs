@@ -0,0 +1,77 @@
+use auto_trait::{auto_trait, auto_trait_default};
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+fn double(value: u32) -> u32 {
+    value * 2
+}
+
+fn triple(value: u32) -> u32 {
+    value * 3
+}
+
+#[auto_trait(self::Wrapper)]
+#[auto_trait(Box<T: Lolka>, map_return = triple)]
+#[auto_trait_default(no_mut, map_return = double)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32 {
+        0
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self
+    }
+}
+
+#[auto_trait_default(map_return = double)]
+#[auto_trait(self::Wrapper)]
+pub trait LolkaDoubled {
+    fn lolka_doubled(&self) -> u32;
+}
+
+impl LolkaDoubled for u32 {
+    fn lolka_doubled(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[test]
+fn should_inherit_defaults_of_trait() {
+    let mut wrapper = Wrapper(2);
+    assert_eq!(wrapper.lolka_ref(), 4);
+    assert_eq!(wrapper.lolka_mut(), 0);
+}
+
+#[test]
+fn should_override_defaults_of_trait() {
+    let mut boxed = Box::new(2u32);
+    assert_eq!(boxed.lolka_ref(), 6);
+    assert_eq!(boxed.lolka_mut(), 0);
+}
+
+#[test]
+fn should_inherit_defaults_written_before_attribute() {
+    let wrapper = Wrapper(2);
+    assert_eq!(wrapper.lolka_doubled(), 6);
+}
@@ -0,0 +1,99 @@
+use auto_trait::auto_trait;
+use auto_trait_delegate::Delegate;
+
+pub struct Wrapper(u32);
+
+impl Delegate for Wrapper {
+    type Target = u32;
+
+    fn delegate(&self) -> &Self::Target {
+        &self.0
+    }
+
+    fn delegate_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+
+    fn delegate_owned(self) -> Self::Target {
+        self.0
+    }
+}
+
+pub struct Generic<T>(T);
+
+impl<T> Delegate for Generic<T> {
+    type Target = T;
+
+    fn delegate(&self) -> &Self::Target {
+        &self.0
+    }
+
+    fn delegate_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+
+    fn delegate_owned(self) -> Self::Target {
+        self.0
+    }
+}
+
+#[auto_trait(Wrapper, Generic<T: Lolka>, via = Delegate)]
+pub trait Lolka {
+    type Output;
+
+    fn lolka_ref(&self) -> u32;
+    fn lolka_mut(&mut self) -> u32;
+    fn lolka_self(self) -> u32 where Self: Sized;
+}
+
+impl Lolka for u32 {
+    type Output = u8;
+
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+}
+
+mod facade {
+    pub use auto_trait_delegate::Delegate as Forward;
+}
+
+#[auto_trait(Wrapper => u32, via = facade::Forward)]
+pub trait Kekw {
+    fn kekw(&self) -> u32;
+}
+
+impl Kekw for u32 {
+    fn kekw(&self) -> u32 {
+        *self + 100
+    }
+}
+
+#[test]
+fn should_forward_through_delegate() {
+    let _: <Wrapper as Lolka>::Output = 1u8;
+
+    let mut wrapper = Wrapper(1);
+    assert_eq!(wrapper.lolka_ref(), 1);
+    assert_eq!(wrapper.lolka_mut(), 2);
+    assert_eq!(wrapper.lolka_self(), 20);
+
+    let mut generic = Generic(1u32);
+    assert_eq!(generic.lolka_ref(), 1);
+    assert_eq!(generic.lolka_mut(), 2);
+    assert_eq!(generic.lolka_self(), 20);
+}
+
+#[test]
+fn should_forward_through_configured_path() {
+    assert_eq!(Wrapper(1).kekw(), 101);
+}
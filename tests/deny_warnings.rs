@@ -0,0 +1,223 @@
+//!Generated code compiles without warnings of builtin lints, including allowed by default, which crates commonly deny
+
+#![deny(warnings, missing_docs, unused, unused_qualifications, unused_results, unreachable_code, unreachable_pub, single_use_lifetimes, unused_lifetimes, redundant_lifetimes, elided_lifetimes_in_paths, trivial_casts, trivial_numeric_casts, let_underscore_drop, unit_bindings)]
+
+use auto_trait::auto_trait;
+
+use core::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::sync::Mutex;
+
+///Wrapper of forwarded value
+pub struct Wrapper(pub u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<u32> for Wrapper {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> u32 {
+        wrapper.0
+    }
+}
+
+///Backend, which supports nothing
+pub struct NullBackend;
+
+///Either of two values
+pub enum Either<L, R> {
+    ///Left value
+    Left(L),
+    ///Right value
+    Right(R),
+}
+
+///Hooks around forwarded calls
+pub mod my_hooks {
+    ///Called before forwarded call
+    pub fn before(_: &'static str) {
+    }
+
+    ///Called after forwarded call
+    pub fn after(_: &'static str) {
+    }
+}
+
+///Trait with every kind of parameter and receiver, as well as patterns, which are renamed or ignored
+#[auto_trait(Wrapper => u32, inherent, doc_note)]
+#[auto_trait(Box<T: Lolka>, shared)]
+#[auto_trait(Either<L: Lolka, R: Lolka>, dispatch = match, no_owned)]
+#[auto_trait(NullBackend, stub)]
+#[auto_trait(newtype = Logged, hooks = my_hooks)]
+pub trait Lolka {
+    ///Constant
+    const ID: u32 = 0;
+
+    ///Shared receiver
+    fn lolka(&self, value: u32) -> u32;
+    ///Mutable receiver with mutable parameter
+    fn increment(&mut self, mut value: u32) -> u32 {
+        value += 1;
+        value
+    }
+    ///Owned mutable receiver
+    fn into_lolka(mut self) -> u32 where Self: Sized {
+        self.increment(0)
+    }
+    ///Ignored and destructured parameters
+    fn patterns(&self, _: u32, (left, right): (u32, u32), _unused: u32) -> u32 {
+        left + right
+    }
+    ///Unused parameter
+    #[allow(unused_variables)]
+    fn unused(&self, value: u32) {
+    }
+    ///Skipped method
+    #[auto_trait(skip)]
+    fn skipped(&self, value: u32) -> u32 {
+        value
+    }
+    ///Lifetime of reference
+    fn name<'a>(&self, name: &'a str) -> &'a str;
+    ///Argument with destructor, which stub drops
+    fn consume(&self, value: String) -> usize;
+}
+
+impl Lolka for u32 {
+    const ID: u32 = 1;
+
+    fn lolka(&self, value: u32) -> u32 {
+        *self + value
+    }
+
+    fn increment(&mut self, value: u32) -> u32 {
+        *self += value;
+        *self
+    }
+
+    fn into_lolka(self) -> u32 {
+        self
+    }
+
+    fn patterns(&self, _: u32, (left, right): (u32, u32), _: u32) -> u32 {
+        *self + left * right
+    }
+
+    fn unused(&self, _: u32) {
+    }
+
+    fn name<'a>(&self, name: &'a str) -> &'a str {
+        name
+    }
+
+    fn consume(&self, value: String) -> usize {
+        value.len()
+    }
+}
+
+///Trait of values reached through guards, which panic on failure
+#[auto_trait(Mutex<T: Counter>, RefCell<T: Counter>, lock_mut, try_trait(TryCounter))]
+#[auto_trait(Weak<T: Counter>, upgrade = default, no_mut)]
+#[auto_trait(Rc<T: Counter>, no_mut)]
+#[auto_trait(Box<dyn>, &dyn, no_mut)]
+#[auto_trait(cache = Cached)]
+#[auto_trait(enum = Any, Wrapper => u32, Box<u32>; variant = Boxed)]
+#[auto_trait(NullBackend, stub = noop)]
+pub trait Counter {
+    ///Cached value
+    #[auto_trait(cache)]
+    fn get(&self) -> u32;
+    ///Resets value
+    fn reset(&mut self) {
+    }
+}
+
+impl Counter for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+
+    fn reset(&mut self) {
+        *self = 0;
+    }
+}
+
+///Observer of values
+#[auto_trait((A, B), fn)]
+#[auto_trait(Wrapper, module = impls, test(with = make_wrapper, skip(notified)))]
+pub trait Observer {
+    ///Notifies of value
+    fn notify(&self, value: u32);
+    ///Returns observed value
+    fn observed(&self) -> u32 {
+        0
+    }
+    ///Notifies of value, which is present only in tests
+    fn notify_gated(&self, #[cfg(test)] value: u32) {
+        #[cfg(test)]
+        self.notify(value);
+    }
+    ///Replaced method
+    #[deprecated = "use `notify`"]
+    fn notified(&self, value: u32) {
+        self.notify(value);
+    }
+}
+
+fn make_wrapper() -> (Wrapper, u32) {
+    (Wrapper(1), 1)
+}
+
+impl Observer for u32 {
+    fn notify(&self, _: u32) {
+    }
+
+    fn observed(&self) -> u32 {
+        *self
+    }
+}
+
+impl Observer for core::cell::Cell<u32> {
+    fn notify(&self, value: u32) {
+        self.set(value);
+    }
+
+    fn observed(&self) -> u32 {
+        self.get()
+    }
+}
+
+#[test]
+fn should_forward_without_warnings() {
+    let mut wrapper = Wrapper(1);
+    assert_eq!(wrapper.increment(1), 2);
+    assert_eq!(Wrapper::patterns(&wrapper, 0, (2, 3), 0), 8);
+    assert_eq!(wrapper.name("lolka"), "lolka");
+    assert_eq!(wrapper.into_lolka(), 2);
+    assert_eq!(Logged(3u32).lolka(1), 4);
+    assert_eq!(Either::<u32, Wrapper>::Right(Wrapper(2)).lolka(1), 3);
+
+    let cell = RefCell::new(4u32);
+    assert_eq!(TryCounter::get(&cell), Ok(4));
+    assert_eq!(Counter::get(&Mutex::new(5u32)), 5);
+    assert_eq!(Counter::get(&Rc::new(6u32)), 6);
+    assert_eq!(Counter::get(&Weak::<u32>::new()), 0);
+    assert_eq!(Cached::new(7u32).get(), 7);
+    assert_eq!(Any::from(Box::new(8u32)).get(), 8);
+    Counter::reset(&mut NullBackend);
+}
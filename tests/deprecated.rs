@@ -0,0 +1,47 @@
+#![deny(deprecated)]
+
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(Box<T: Lolka>, std::rc::Rc<T: Lolka>, Wrapper => u32; inherent)]
+pub trait Lolka {
+    #[deprecated(note = "use LOLKA")]
+    const LOLKA_OLD: u32;
+    #[deprecated(note = "use Output")]
+    type OutputOld;
+
+    #[deprecated(note = "use lolka")]
+    fn lolka_old(&self) -> u32;
+
+    fn lolka(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    const LOLKA_OLD: u32 = 0;
+    type OutputOld = u32;
+
+    fn lolka_old(&self) -> u32 {
+        *self
+    }
+
+    fn lolka(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn should_forward_deprecated_items() {
+    assert_eq!(Lolka::lolka_old(&Box::new(1u32)), 1);
+    assert_eq!(Lolka::lolka(&Box::new(1u32)), 2);
+    assert_eq!(<std::rc::Rc<u32> as Lolka>::LOLKA_OLD, 0);
+    assert_eq!(Wrapper(3).lolka_old(), 3);
+}
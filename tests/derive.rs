@@ -0,0 +1,101 @@
+mod traits {
+    use auto_trait::auto_trait;
+
+    #[auto_trait(export)]
+    pub trait Lolka {
+        fn lolka_ref(&self) -> u32;
+
+        fn lolka_mut(&mut self) -> u32;
+
+        #[auto_trait(skip)]
+        fn lolka_default(&self) -> u32 {
+            0
+        }
+    }
+
+    impl Lolka for u32 {
+        fn lolka_ref(&self) -> u32 {
+            *self
+        }
+
+        fn lolka_mut(&mut self) -> u32 {
+            *self += 1;
+            *self
+        }
+
+        fn lolka_default(&self) -> u32 {
+            1
+        }
+    }
+
+    #[auto_trait(Box<T: Lolka2>, export)]
+    pub trait Lolka2 {
+        fn lolka2(&self) -> u32;
+    }
+
+    impl Lolka2 for u32 {
+        fn lolka2(&self) -> u32 {
+            *self * 2
+        }
+    }
+}
+
+mod wrappers {
+    use auto_trait::AutoTrait;
+
+    #[derive(AutoTrait)]
+    #[auto_trait(crate::traits::Lolka, super::traits::Lolka2)]
+    pub struct Wrapper(pub u32);
+
+    impl core::ops::Deref for Wrapper {
+        type Target = u32;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl core::ops::DerefMut for Wrapper {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    #[derive(AutoTrait)]
+    #[auto_trait(crate::traits::Lolka)]
+    pub struct Generic<'a, T> where T: Copy + crate::traits::Lolka {
+        pub value: &'a mut T,
+    }
+
+    impl<'a, T: Copy + crate::traits::Lolka> core::ops::Deref for Generic<'a, T> {
+        type Target = T;
+        fn deref(&self) -> &Self::Target {
+            self.value
+        }
+    }
+
+    impl<'a, T: Copy + crate::traits::Lolka> core::ops::DerefMut for Generic<'a, T> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            self.value
+        }
+    }
+}
+
+use traits::{Lolka, Lolka2};
+
+#[test]
+fn should_derive_exported_traits() {
+    let mut wrapper = wrappers::Wrapper(5);
+    assert_eq!(wrapper.lolka_ref(), 5);
+    assert_eq!(wrapper.lolka_mut(), 6);
+    assert_eq!(wrapper.lolka_default(), 0);
+    assert_eq!(wrapper.lolka2(), 12);
+    assert_eq!(Box::new(5u32).lolka2(), 10);
+
+    let mut value = 5u32;
+    let mut generic = wrappers::Generic {
+        value: &mut value,
+    };
+    assert_eq!(generic.lolka_ref(), 5);
+    assert_eq!(generic.lolka_mut(), 6);
+    assert_eq!(value, 6);
+}
@@ -0,0 +1,133 @@
+use auto_trait::{auto_trait, AutoTrait};
+
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[auto_trait(Either<L: Lolka, R: Lolka>, dispatch = match)]
+pub trait Lolka {
+    fn lolka_ref(&self, add: u32) -> u32;
+    fn lolka_mut(&mut self) -> u32;
+    fn lolka_self(self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self, add: u32) -> u32 {
+        *self + add
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self
+    }
+}
+
+impl Lolka for String {
+    fn lolka_ref(&self, add: u32) -> u32 {
+        self.len() as u32 + add
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        self.push('!');
+        self.len() as u32
+    }
+
+    fn lolka_self(self) -> u32 {
+        self.len() as u32 * 10
+    }
+}
+
+pub enum Backend {
+    Fast(u32),
+    Safe(String),
+}
+
+#[auto_trait(Backend, dispatch = match(Fast, Safe))]
+pub trait Kek {
+    fn kek_ref(&self) -> u32;
+    fn kek_self(self) -> u32;
+}
+
+impl Kek for u32 {
+    fn kek_ref(&self) -> u32 {
+        *self
+    }
+
+    fn kek_self(self) -> u32 {
+        self * 2
+    }
+}
+
+impl Kek for String {
+    fn kek_ref(&self) -> u32 {
+        self.len() as u32
+    }
+
+    fn kek_self(self) -> u32 {
+        self.len() as u32 * 2
+    }
+}
+
+#[auto_trait(export)]
+pub trait Cheburek {
+    fn cheburek_mut(&mut self, add: u32) -> u32;
+}
+
+impl Cheburek for u32 {
+    fn cheburek_mut(&mut self, add: u32) -> u32 {
+        *self += add;
+        *self
+    }
+}
+
+impl Cheburek for String {
+    fn cheburek_mut(&mut self, add: u32) -> u32 {
+        self.push('!');
+        self.len() as u32 + add
+    }
+}
+
+#[derive(AutoTrait)]
+#[auto_trait(Cheburek)]
+pub enum DerivedBackend {
+    Fast(u32),
+    Safe(String),
+}
+
+#[test]
+fn should_dispatch_left() {
+    let mut either = Either::<u32, String>::Left(1);
+    assert_eq!(either.lolka_ref(1), 2);
+    assert_eq!(either.lolka_mut(), 2);
+    assert_eq!(either.lolka_self(), 2);
+}
+
+#[test]
+fn should_dispatch_right() {
+    let mut either = Either::<u32, String>::Right("lolka".to_owned());
+    assert_eq!(either.lolka_ref(1), 6);
+    assert_eq!(either.lolka_mut(), 6);
+    assert_eq!(either.lolka_self(), 60);
+}
+
+#[test]
+fn should_dispatch_listed_variants() {
+    assert_eq!(Backend::Fast(3).kek_ref(), 3);
+    assert_eq!(Backend::Safe("kek".to_owned()).kek_ref(), 3);
+    assert_eq!(Backend::Fast(3).kek_self(), 6);
+    assert_eq!(Backend::Safe("kek".to_owned()).kek_self(), 6);
+}
+
+#[test]
+fn should_dispatch_derived_enum() {
+    let mut fast = DerivedBackend::Fast(1);
+    assert_eq!(fast.cheburek_mut(1), 2);
+    assert_eq!(fast.cheburek_mut(1), 3);
+    let mut safe = DerivedBackend::Safe("kek".to_owned());
+    assert_eq!(safe.cheburek_mut(1), 5);
+}
@@ -0,0 +1,106 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.0
+    }
+}
+
+mod other {
+    pub struct Wrapper(pub String);
+
+    impl core::ops::Deref for Wrapper {
+        type Target = String;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl core::ops::DerefMut for Wrapper {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+}
+
+#[auto_trait(enum = AnyLolka, Wrapper => u32, Box<u32>; variant = Boxed, other::Wrapper; variant = Named)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+    fn lolka_mut(&mut self) -> u32;
+    fn lolka_self(self) -> u32 where Self: Sized {
+        self.lolka_ref()
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+}
+
+impl Lolka for String {
+    fn lolka_ref(&self) -> u32 {
+        self.len() as u32
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        self.push('!');
+        self.len() as u32
+    }
+
+    fn lolka_self(self) -> u32 {
+        self.len() as u32 * 100
+    }
+}
+
+#[test]
+fn should_dispatch_to_every_variant() {
+    let mut lolkas: Vec<AnyLolka> = vec![Wrapper(1).into(), Box::new(2u32).into(), other::Wrapper("kek".to_owned()).into()];
+
+    assert_eq!(lolkas.iter().map(Lolka::lolka_ref).collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(lolkas.iter_mut().map(Lolka::lolka_mut).collect::<Vec<_>>(), [2, 3, 4]);
+    //Only `Wrapper` forwards owned method, while others use default implementation
+    assert_eq!(lolkas.into_iter().map(Lolka::lolka_self).collect::<Vec<_>>(), [20, 3, 4]);
+}
+
+#[test]
+fn should_name_variants() {
+    match AnyLolka::from(Wrapper(1)) {
+        AnyLolka::Wrapper(wrapper) => assert_eq!(wrapper.0, 1),
+        _ => unreachable!(),
+    }
+
+    match AnyLolka::from(Box::new(2u32)) {
+        AnyLolka::Boxed(boxed) => assert_eq!(*boxed, 2),
+        _ => unreachable!(),
+    }
+
+    match AnyLolka::from(other::Wrapper("kek".to_owned())) {
+        AnyLolka::Named(wrapper) => assert_eq!(wrapper.0, "kek"),
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,88 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(dyn)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32 {
+        0
+    }
+
+    fn lolka_generic<T: Into<u32>>(&self, value: T) -> u32 where Self: Sized {
+        self.lolka_ref() + value.into()
+    }
+
+    fn lolka_new() -> u32 where Self: Sized {
+        42
+    }
+}
+
+#[auto_trait(Box<dyn>)]
+pub trait LolkaMut {
+    fn lolka_mut(&mut self) -> u32;
+}
+
+#[auto_trait(&dyn)]
+pub trait LolkaRef {
+    fn lolka_ref(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+impl Lolka for String {
+    fn lolka_ref(&self) -> u32 {
+        self.len() as u32
+    }
+}
+
+impl LolkaMut for u32 {
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+impl LolkaRef for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self * 2
+    }
+}
+
+fn lolka(lolka: impl Lolka) -> u32 {
+    lolka.lolka_ref()
+}
+
+fn lolka_mut(mut lolka: impl LolkaMut) -> u32 {
+    lolka.lolka_mut()
+}
+
+fn lolka_ref(lolka: impl LolkaRef) -> u32 {
+    lolka.lolka_ref()
+}
+
+#[test]
+fn should_forward_to_trait_object() {
+    let lolkas: Vec<Box<dyn Lolka>> = vec![Box::new(1u32), Box::new(String::from("lolka"))];
+    assert_eq!(lolkas.into_iter().map(lolka).sum::<u32>(), 6);
+
+    let value = 2u32;
+    let object: &dyn Lolka = &value;
+    assert_eq!(lolka(object), 2);
+
+    let mut boxed: Box<dyn Lolka> = Box::new(2u32);
+    assert_eq!(Lolka::lolka_mut(&mut boxed), 3);
+    assert_eq!(boxed.lolka_generic(1u8), 4);
+    assert_eq!(<Box<dyn Lolka> as Lolka>::lolka_new(), 42);
+
+    assert_eq!(lolka_mut(Box::new(1u32) as Box<dyn LolkaMut>), 2);
+    assert_eq!(lolka_ref(&1u32 as &dyn LolkaRef), 2);
+}
@@ -0,0 +1,71 @@
+//!Forwards traits written with syntax of edition 2015: trait objects without `dyn`, and raw identifiers, which name keywords of later editions
+
+#![allow(bare_trait_objects)]
+
+extern crate auto_trait;
+
+use auto_trait::auto_trait;
+
+pub trait Visitor {
+    fn visit(&self, value: u32) -> u32;
+}
+
+impl Visitor for u32 {
+    fn visit(&self, value: u32) -> u32 {
+        *self + value
+    }
+}
+
+pub struct Wrapper(pub u32);
+
+impl std::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(Box<T: Lolka>, Box<dyn>, &dyn)]
+#[auto_trait(Wrapper, inherent)]
+pub trait Lolka {
+    fn accept(&self, visitor: &Visitor) -> u32;
+    fn visitor(&self) -> Box<Visitor>;
+    fn r#async(&self, r#dyn: u32) -> u32;
+    fn r#try(&self, r#await: u32) -> u32;
+}
+
+impl Lolka for u32 {
+    fn accept(&self, visitor: &Visitor) -> u32 {
+        visitor.visit(*self)
+    }
+
+    fn visitor(&self) -> Box<Visitor> {
+        Box::new(*self)
+    }
+
+    fn r#async(&self, r#dyn: u32) -> u32 {
+        *self + r#dyn
+    }
+
+    fn r#try(&self, r#await: u32) -> u32 {
+        *self * r#await
+    }
+}
+
+#[test]
+fn should_forward_syntax_of_edition() {
+    let boxed = Box::new(1u32);
+    assert_eq!(boxed.accept(&2u32), 3);
+    assert_eq!(boxed.visitor().visit(3), 4);
+    assert_eq!(boxed.r#async(4), 5);
+    assert_eq!(boxed.r#try(5), 5);
+
+    let object: Box<Lolka> = Box::new(2u32);
+    assert_eq!(object.r#async(1), 3);
+    let object: &Lolka = &3u32;
+    assert_eq!(Lolka::r#try(&object, 2), 6);
+
+    let wrapper = Wrapper(4);
+    assert_eq!(Wrapper::r#async(&wrapper, 1), 5);
+    assert_eq!(Wrapper::accept(&wrapper, &1u32), 5);
+}
@@ -0,0 +1,61 @@
+//!Forwards traits written with syntax of edition 2018: `async` methods, and raw identifiers, which name keywords it reserves
+
+use auto_trait::auto_trait;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = Pin::as_mut(&mut future).poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+pub struct Wrapper(pub u32);
+
+impl std::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[allow(async_fn_in_trait)]
+#[auto_trait(Box<T: Service>, Wrapper => u32)]
+pub trait Service {
+    async fn call(&self, request: u32) -> u32;
+    fn r#async(&self, r#dyn: u32) -> u32;
+    fn r#try(&self, r#await: u32) -> u32;
+}
+
+impl Service for u32 {
+    async fn call(&self, request: u32) -> u32 {
+        *self + request
+    }
+
+    fn r#async(&self, r#dyn: u32) -> u32 {
+        *self * r#dyn
+    }
+
+    fn r#try(&self, r#await: u32) -> u32 {
+        *self - r#await
+    }
+}
+
+#[test]
+fn should_forward_syntax_of_edition() {
+    let boxed = Box::new(3u32);
+    assert_eq!(block_on(boxed.call(1)), 4);
+    assert_eq!(boxed.r#async(2), 6);
+    assert_eq!(boxed.r#try(2), 1);
+
+    let wrapper = Wrapper(4);
+    assert_eq!(block_on(Service::call(&wrapper, 1)), 5);
+    assert_eq!(wrapper.r#async(2), 8);
+    assert_eq!(Service::r#try(&wrapper, 1), 3);
+}
@@ -0,0 +1,51 @@
+//!Forwards traits within crate of edition 2021, which prelude adds `TryFrom`, `TryInto` and `FromIterator` to
+
+use auto_trait::auto_trait;
+
+pub struct Wrapper(pub u32);
+
+impl std::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<u32> for Wrapper {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+//Methods are named the same as methods of traits, which prelude of the edition adds
+#[auto_trait(Box<T: Convert>, Wrapper => u32)]
+pub trait Convert {
+    fn try_into(&self) -> Option<u8>;
+    fn try_from(value: u32) -> Self where Self: Sized;
+    fn from_iter(values: &[u32]) -> Self where Self: Sized;
+}
+
+impl Convert for u32 {
+    fn try_into(&self) -> Option<u8> {
+        std::convert::TryFrom::try_from(*self).ok()
+    }
+
+    fn try_from(value: u32) -> Self {
+        value
+    }
+
+    fn from_iter(values: &[u32]) -> Self {
+        values.iter().sum()
+    }
+}
+
+#[test]
+fn should_forward_within_crate_of_edition() {
+    let boxed = Box::new(1u32);
+    assert_eq!(Convert::try_into(&boxed), Some(1));
+    assert_eq!(*<Box<u32> as Convert>::try_from(2), 2);
+    assert_eq!(*<Box<u32> as Convert>::from_iter(&[2, 3]), 5);
+    assert_eq!(Convert::try_into(&Wrapper(300)), None);
+    assert_eq!(<Wrapper as Convert>::try_from(3).0, 3);
+    assert_eq!(<Wrapper as Convert>::from_iter(&[4, 1]).0, 5);
+}
@@ -0,0 +1,271 @@
+//!Forwards traits within crate of every edition, which generated tokens follow, hence it is written to compile for each of them
+//!
+//!Crates are declared explicitly and referred through `std`, as edition 2015 resolves paths relative to crate root.
+
+#[macro_use]
+extern crate auto_trait;
+extern crate auto_trait_delegate;
+
+use auto_trait::{auto_trait, AutoTrait};
+use auto_trait_delegate::Delegate;
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use std::sync::{Arc, Mutex};
+
+pub struct Wrapper(pub u32);
+
+impl std::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<u32> for Wrapper {
+    fn from(value: u32) -> Self {
+        Wrapper(value)
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> u32 {
+        wrapper.0
+    }
+}
+
+pub struct Named {
+    value: u32,
+}
+
+impl From<u32> for Named {
+    fn from(value: u32) -> Self {
+        Named {
+            value,
+        }
+    }
+}
+
+pub struct Delegated(u32);
+
+impl From<u32> for Delegated {
+    fn from(value: u32) -> Self {
+        Delegated(value)
+    }
+}
+
+impl Delegate for Delegated {
+    type Target = u32;
+
+    fn delegate(&self) -> &u32 {
+        &self.0
+    }
+
+    fn delegate_mut(&mut self) -> &mut u32 {
+        &mut self.0
+    }
+
+    fn delegate_owned(self) -> u32 {
+        self.0
+    }
+}
+
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+pub struct NullBackend;
+
+fn make_wrapper() -> (Wrapper, u32) {
+    (Wrapper(1), 1)
+}
+
+#[auto_trait(Wrapper => u32, inherent, test(with = make_wrapper))]
+#[auto_trait(Box<T: Lolka>)]
+#[auto_trait(Named => u32, field = value, shared)]
+#[auto_trait(Delegated => u32, via = Delegate)]
+#[auto_trait(NullBackend, stub)]
+#[auto_trait(newtype = Plain)]
+pub trait Lolka {
+    const ID: u32 = 0;
+    fn lolka(&self) -> u32;
+    fn increment(&mut self);
+    fn into_lolka(self) -> u32 where Self: Sized;
+    fn initial() -> Self where Self: Sized;
+    fn parse(value: u32) -> Result<Self, u32> where Self: Sized;
+    fn added(&self, left: u32, right: u32) -> u32 {
+        left + right
+    }
+    fn name<'a>(&self, name: &'a str) -> &'a str;
+}
+
+impl Lolka for u32 {
+    const ID: u32 = 1;
+
+    fn lolka(&self) -> u32 {
+        *self
+    }
+
+    fn increment(&mut self) {
+        *self += 1;
+    }
+
+    fn into_lolka(self) -> u32 {
+        self
+    }
+
+    fn initial() -> Self {
+        1
+    }
+
+    fn parse(value: u32) -> Result<Self, u32> {
+        Ok(value)
+    }
+
+    fn added(&self, left: u32, right: u32) -> u32 {
+        *self + left + right
+    }
+
+    fn name<'a>(&self, name: &'a str) -> &'a str {
+        name
+    }
+}
+
+#[auto_trait(Mutex<T: Counter>, RefCell<T: Counter>, lock_mut, try_trait(TryCounter), panic_msg = "{target} cannot {method}")]
+#[auto_trait(Weak<T: Counter>, upgrade = default, no_mut)]
+#[auto_trait(Rc<T: Counter>, Arc<T: Counter>, &, Box<dyn>, no_mut)]
+#[auto_trait(&mut)]
+#[auto_trait(cache = Cached)]
+#[auto_trait(Either<L: Counter, R: Counter>, dispatch = match)]
+#[auto_trait(enum = Any, Wrapper => u32, Box<u32>; variant = Boxed)]
+pub trait Counter {
+    #[auto_trait(cache)]
+    fn get(&self) -> u32;
+    fn reset(&mut self) {
+    }
+}
+
+impl Counter for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+
+    fn reset(&mut self) {
+        *self = 0;
+    }
+}
+
+#[auto_trait((A, B), &dyn)]
+pub trait Observer {
+    fn notify(&self, value: u32);
+}
+
+impl Observer for Cell<u32> {
+    fn notify(&self, value: u32) {
+        self.set(value);
+    }
+}
+
+#[auto_trait(fn)]
+pub trait Callback {
+    fn call(&self, value: u32) -> u32;
+}
+
+#[auto_trait(export)]
+pub trait Exported {
+    fn exported(&self) -> u32;
+}
+
+impl Exported for u32 {
+    fn exported(&self) -> u32 {
+        *self
+    }
+}
+
+#[derive(AutoTrait)]
+#[auto_trait(Exported)]
+pub struct Derived(pub u32);
+
+impl std::ops::Deref for Derived {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+auto_wrap!(pub struct Meters(pub u32); impl Exported);
+
+pub struct Bytes(Vec<u8>);
+
+impl std::ops::Deref for Bytes {
+    type Target = Vec<u8>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Bytes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+auto_trait_std!(std::io::Write for Bytes);
+
+#[test]
+fn should_forward_within_crate_of_edition() {
+    let mut wrapper = Wrapper(1);
+    wrapper.increment();
+    assert_eq!(wrapper.lolka(), 2);
+    assert_eq!(Wrapper::added(&wrapper, 1, 2), 5);
+    assert_eq!(wrapper.name("lolka"), "lolka");
+    assert_eq!(wrapper.into_lolka(), 2);
+    assert_eq!(<Wrapper as Lolka>::initial().0, 1);
+    assert_eq!(<Wrapper as Lolka>::parse(3).map(|wrapper| wrapper.0), Ok(3));
+    assert_eq!(<Wrapper as Lolka>::ID, 1);
+
+    let mut boxed = Box::new(3u32);
+    boxed.increment();
+    assert_eq!(boxed.lolka(), 4);
+    assert_eq!(Named { value: 5 }.lolka(), 5);
+    assert_eq!(Delegated(6).into_lolka(), 6);
+    assert_eq!(Plain(8u32).lolka(), 8);
+
+    let mut cell = RefCell::new(9u32);
+    Counter::reset(&mut cell);
+    assert_eq!(Counter::get(&cell), 0);
+    assert_eq!(TryCounter::get(&cell), Ok(0));
+    assert_eq!(TryCounter::get(&Mutex::new(10u32)), Ok(10));
+    assert_eq!(Counter::get(&Weak::<u32>::new()), 0);
+    let boxed: Box<dyn Counter> = Box::new(11u32);
+    assert_eq!(boxed.get(), 11);
+    assert_eq!(Counter::get(&Rc::new(12u32)), 12);
+    assert_eq!(Counter::get(&Arc::new(13u32)), 13);
+    let mut value = 14u32;
+    Counter::reset(&mut &mut value);
+    assert_eq!(Counter::get(&&value), 0);
+    assert_eq!(Cached::new(15u32).get(), 15);
+    assert_eq!(Any::from(Box::new(16u32)).get(), 16);
+    assert_eq!(Either::<u32, Wrapper>::Right(Wrapper(7)).get(), 7);
+
+    let pair = (Cell::new(0), Cell::new(0));
+    pair.notify(17);
+    assert_eq!((pair.0.get(), pair.1.get()), (17, 17));
+    let observer: &dyn Observer = &pair.0;
+    Observer::notify(&observer, 18);
+    assert_eq!(pair.0.get(), 18);
+    assert_eq!(Callback::call(&|value| value + 1, 19), 20);
+    assert_eq!(Derived(21).exported(), 21);
+    assert_eq!(Meters(22).exported(), 22);
+
+    let mut bytes = Bytes(vec![23]);
+    std::io::Write::write_all(&mut bytes, &[24]).expect("to write");
+    assert_eq!(*bytes, [23, 24]);
+}
@@ -0,0 +1,59 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+fn make_wrapper() -> (Wrapper, u32) {
+    (Wrapper(5), 5)
+}
+
+fn make_box() -> (Box<u32>, u32) {
+    (Box::new(5), 5)
+}
+
+pub struct Lolkas(u32);
+
+#[auto_trait(Wrapper, test(with = make_wrapper, skip(lolka_lolkas)))]
+#[auto_trait(Box<T: Lolka>, test(with = make_box, skip(lolka_lolkas)))]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_name(&self) -> String;
+
+    fn lolka_lolkas(&self) -> Lolkas {
+        Lolkas(0)
+    }
+
+    fn lolka_add(&self, value: u32) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_name(&self) -> String {
+        format!("lolka_{}", self)
+    }
+
+    fn lolka_lolkas(&self) -> Lolkas {
+        Lolkas(*self)
+    }
+
+    fn lolka_add(&self, value: u32) -> u32 {
+        *self + value
+    }
+}
+
+#[test]
+fn should_forward_to_inner_value() {
+    let (wrapper, _) = make_wrapper();
+    assert_eq!(wrapper.lolka_lolkas().0, 5);
+    assert_eq!(wrapper.lolka_add(1), 6);
+}
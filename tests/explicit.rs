@@ -0,0 +1,85 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> u32 {
+        wrapper.0
+    }
+}
+
+impl From<Wrapper> for u64 {
+    fn from(wrapper: Wrapper) -> u64 {
+        wrapper.0 as u64
+    }
+}
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait(Wrapper => u32)]
+pub trait Lolka {
+    fn lolka() -> u32;
+
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32;
+
+    fn lolka_self(self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka() -> u32 {
+        1
+    }
+
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+}
+
+impl Lolka for u64 {
+    fn lolka() -> u32 {
+        2
+    }
+
+    fn lolka_ref(&self) -> u32 {
+        0
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        0
+    }
+
+    fn lolka_self(self) -> u32 {
+        0
+    }
+}
+
+#[test]
+fn should_forward_to_explicit_type() {
+    let mut wrapper = Wrapper(5);
+    assert_eq!(Wrapper::lolka(), 1);
+    assert_eq!(wrapper.lolka_ref(), 5);
+    assert_eq!(wrapper.lolka_mut(), 6);
+    assert_eq!(wrapper.lolka_self(), 60);
+}
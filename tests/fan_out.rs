@@ -0,0 +1,80 @@
+use auto_trait::auto_trait;
+
+#[derive(Default)]
+pub struct Lines(Vec<String>);
+
+#[derive(Default)]
+pub struct Count(usize);
+
+#[auto_trait((A, B), (A, B, C))]
+pub trait Sink {
+    fn write(&mut self, line: &str);
+
+    fn write_owned(&mut self, line: String);
+
+    fn flush(self);
+
+    fn written(&self) -> usize {
+        0
+    }
+}
+
+impl Sink for Lines {
+    fn write(&mut self, line: &str) {
+        self.0.push(line.to_owned());
+    }
+
+    fn write_owned(&mut self, line: String) {
+        self.0.push(line);
+    }
+
+    fn flush(self) {
+    }
+
+    fn written(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Sink for Count {
+    fn write(&mut self, _line: &str) {
+        self.0 += 1;
+    }
+
+    fn write_owned(&mut self, _line: String) {
+        self.0 += 1;
+    }
+
+    fn flush(self) {
+    }
+}
+
+#[auto_trait((A: Observer, self::Lines))]
+pub trait Observer {
+    fn notify(&self, event: u32);
+}
+
+impl Observer for Lines {
+    fn notify(&self, _event: u32) {
+    }
+}
+
+#[test]
+fn should_call_every_element_of_tuple_in_order() {
+    let mut sinks = (Lines::default(), Count::default());
+    sinks.write("lolka");
+    sinks.write_owned("kek".to_owned());
+    assert_eq!(sinks.0.0, ["lolka", "kek"]);
+    assert_eq!(sinks.1.0, 2);
+    //Value returning method is left with its default implementation
+    assert_eq!(sinks.written(), 0);
+    sinks.flush();
+
+    let mut sinks = (Lines::default(), Count::default(), Lines::default());
+    sinks.write("lolka");
+    assert_eq!(sinks.0.0, ["lolka"]);
+    assert_eq!(sinks.1.0, 1);
+    assert_eq!(sinks.2.0, ["lolka"]);
+
+    (Lines::default(), Lines::default()).notify(1);
+}
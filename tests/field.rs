@@ -0,0 +1,54 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+pub struct Named<T> {
+    pub name: &'static str,
+    pub value: T,
+}
+
+#[auto_trait(Wrapper => u32; field = 0, Named<T: Lolka>; field = value)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+    fn lolka_mut(&mut self) -> u32;
+    fn lolka_self(self) -> u32;
+    fn lolka_new() -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+
+    fn lolka_new() -> u32 {
+        42
+    }
+}
+
+#[test]
+fn should_forward_to_field() {
+    let mut wrapper = Wrapper(1);
+    assert_eq!(wrapper.lolka_ref(), 1);
+    assert_eq!(wrapper.lolka_mut(), 2);
+    assert_eq!(wrapper.lolka_self(), 20);
+    assert_eq!(Wrapper::lolka_new(), 42);
+
+    let mut named = Named {
+        name: "lolka",
+        value: 3u32,
+    };
+    assert_eq!(named.lolka_ref(), 3);
+    assert_eq!(named.lolka_mut(), 4);
+    assert_eq!(named.name, "lolka");
+    assert_eq!(named.lolka_self(), 40);
+    assert_eq!(Named::<u32>::lolka_new(), 42);
+}
@@ -0,0 +1,156 @@
+use auto_trait::auto_trait;
+
+use std::sync::Arc;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(Arc<T: Lolka>, only(lolka_ref))]
+#[auto_trait(Wrapper, except(lolka_mut, lolka_self))]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32 {
+        0
+    }
+
+    fn lolka_self(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+}
+
+#[test]
+fn should_filter_methods() {
+    let mut arc = Arc::new(5u32);
+    assert_eq!(arc.lolka_ref(), 5);
+    assert_eq!(Lolka::lolka_mut(&mut arc), 0);
+    assert_eq!(arc.lolka_self(), 0);
+
+    let mut wrapper = Wrapper(5);
+    assert_eq!(wrapper.lolka_ref(), 5);
+    assert_eq!(wrapper.lolka_mut(), 0);
+    assert_eq!(wrapper.lolka_self(), 0);
+}
+
+pub struct RefOnly(u32);
+pub struct NoMut(u32);
+pub struct NoOwned(u32);
+
+macro_rules! impl_deref {
+    ($($name:ident),*) => {$(
+        impl core::ops::Deref for $name {
+            type Target = u32;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl core::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(value: $name) -> u32 {
+                value.0
+            }
+        }
+    )*};
+}
+
+impl_deref!(RefOnly, NoMut, NoOwned);
+
+#[auto_trait(RefOnly => u32, ref_only)]
+#[auto_trait(NoMut => u32, NoOwned => u32, no_mut)]
+pub trait LolkaReceiver {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32 {
+        0
+    }
+
+    fn lolka_self(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+impl LolkaReceiver for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+}
+
+#[auto_trait(NoOwned => u32, no_owned)]
+pub trait LolkaOwned {
+    fn lolka_owned_ref(&self) -> u32;
+
+    fn lolka_owned_mut(&mut self) -> u32;
+
+    fn lolka_owned_self(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+impl LolkaOwned for u32 {
+    fn lolka_owned_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_owned_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_owned_self(self) -> u32 {
+        self * 10
+    }
+}
+
+#[test]
+fn should_filter_methods_by_receiver() {
+    let mut wrapper = RefOnly(5);
+    assert_eq!(wrapper.lolka_ref(), 5);
+    assert_eq!(wrapper.lolka_mut(), 0);
+    assert_eq!(wrapper.lolka_self(), 0);
+
+    let mut wrapper = NoMut(5);
+    assert_eq!(wrapper.lolka_ref(), 5);
+    assert_eq!(wrapper.lolka_mut(), 0);
+    assert_eq!(wrapper.lolka_self(), 50);
+
+    let mut wrapper = NoOwned(5);
+    assert_eq!(wrapper.lolka_owned_ref(), 5);
+    assert_eq!(wrapper.lolka_owned_mut(), 6);
+    assert_eq!(wrapper.lolka_owned_self(), 0);
+}
@@ -0,0 +1,60 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(fn)]
+pub trait Strategy {
+    fn score(&self, value: u32, bonus: u32) -> u32;
+
+    fn name(&self) -> &'static str {
+        "strategy"
+    }
+}
+
+#[auto_trait(fn)]
+pub trait Select {
+    fn select<'a>(&self, left: &'a str, right: &'a str) -> &'a str;
+}
+
+#[auto_trait(fn)]
+pub trait Notify {
+    fn notify(&self, message: &str);
+}
+
+fn double(value: u32, bonus: u32) -> u32 {
+    value * 2 + bonus
+}
+
+fn longest<'a>(left: &'a str, right: &'a str) -> &'a str {
+    match left.len() >= right.len() {
+        true => left,
+        false => right,
+    }
+}
+
+fn run(strategy: &(impl Strategy + ?Sized), value: u32) -> u32 {
+    strategy.score(value, 1)
+}
+
+#[test]
+fn should_implement_trait_for_closure() {
+    let offset = 10;
+    let closure = move |value: u32, bonus: u32| value + bonus + offset;
+    assert_eq!(run(&closure, 1), 12);
+    assert_eq!(closure.name(), "strategy");
+
+    let messages = core::cell::RefCell::new(Vec::new());
+    let notify = |message: &str| messages.borrow_mut().push(message.to_owned());
+    notify.notify("lolka");
+    Notify::notify(&notify, "kek");
+    assert_eq!(*messages.borrow(), ["lolka", "kek"]);
+}
+
+#[test]
+fn should_implement_trait_for_function() {
+    assert_eq!(run(&double, 2), 5);
+    assert_eq!(run(&(double as fn(u32, u32) -> u32), 3), 7);
+    assert_eq!(Select::select(&longest, "lolka", "kek"), "lolka");
+
+    //Trait object of function implements the trait as well
+    let boxed: Box<dyn Fn(u32, u32) -> u32> = Box::new(double);
+    assert_eq!(run(&*boxed, 4), 9);
+}
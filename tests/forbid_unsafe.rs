@@ -0,0 +1,224 @@
+//!Generated code contains no unsafe code, hence safe traits are forwarded within crate forbidding it
+
+#![forbid(unsafe_code)]
+
+use auto_trait::{auto_trait, AutoTrait};
+use auto_trait_delegate::Delegate;
+
+use core::cell::RefCell;
+use core::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::sync::Mutex;
+
+pub struct Wrapper(pub u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<u32> for Wrapper {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> u32 {
+        wrapper.0
+    }
+}
+
+pub struct Named {
+    value: u32,
+}
+
+impl From<u32> for Named {
+    fn from(value: u32) -> Self {
+        Self {
+            value,
+        }
+    }
+}
+
+pub struct Delegated(u32);
+
+impl From<u32> for Delegated {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl Delegate for Delegated {
+    type Target = u32;
+
+    fn delegate(&self) -> &u32 {
+        &self.0
+    }
+
+    fn delegate_mut(&mut self) -> &mut u32 {
+        &mut self.0
+    }
+
+    fn delegate_owned(self) -> u32 {
+        self.0
+    }
+}
+
+pub struct NullBackend;
+
+#[auto_trait(Wrapper => u32, inherent)]
+#[auto_trait(Box<T: Lolka>)]
+#[auto_trait(Named => u32, field = value, shared)]
+#[auto_trait(Delegated => u32, via = Delegate)]
+#[auto_trait(newtype = Plain)]
+#[auto_trait(NullBackend, stub)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+    fn increment(&mut self);
+    fn into_lolka(self) -> u32 where Self: Sized;
+    fn initial() -> Self where Self: Sized;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+
+    fn increment(&mut self) {
+        *self += 1;
+    }
+
+    fn into_lolka(self) -> u32 {
+        self
+    }
+
+    fn initial() -> Self {
+        1
+    }
+}
+
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[auto_trait(Mutex<T: Counter>, RefCell<T: Counter>, lock_mut, try_trait(TryCounter))]
+#[auto_trait(Weak<T: Counter>, upgrade = default, no_mut)]
+#[auto_trait(Rc<T: Counter>, &, Box<dyn>, no_mut)]
+#[auto_trait(Either<L: Counter, R: Counter>, dispatch = match)]
+#[auto_trait(cache = Cached)]
+#[auto_trait(enum = Any, Wrapper => u32, Box<u32>; variant = Boxed)]
+pub trait Counter {
+    #[auto_trait(cache)]
+    fn get(&self) -> u32;
+    fn reset(&mut self) {
+    }
+}
+
+impl Counter for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+
+    fn reset(&mut self) {
+        *self = 0;
+    }
+}
+
+#[auto_trait((A, B), fn)]
+pub trait Observer {
+    fn notify(&self, value: u32);
+}
+
+impl Observer for core::cell::Cell<u32> {
+    fn notify(&self, value: u32) {
+        self.set(value);
+    }
+}
+
+#[auto_trait(Timed<T: Ticker>, pin_project(inner))]
+pub trait Ticker {
+    fn tick(self: Pin<&mut Self>) -> u32;
+}
+
+impl Ticker for u32 {
+    fn tick(mut self: Pin<&mut Self>) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+#[pin_project::pin_project]
+pub struct Timed<T> {
+    #[pin]
+    inner: T,
+}
+
+#[auto_trait(export)]
+pub trait Exported {
+    fn exported(&self) -> u32;
+}
+
+impl Exported for u32 {
+    fn exported(&self) -> u32 {
+        *self
+    }
+}
+
+#[derive(AutoTrait)]
+#[auto_trait(Exported)]
+pub struct Derived(pub u32);
+
+impl core::ops::Deref for Derived {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+auto_trait::auto_wrap!(pub struct Meters(pub u32); impl Exported);
+
+#[test]
+fn should_forward_within_crate_forbidding_unsafe_code() {
+    let mut wrapper = Wrapper(1);
+    wrapper.increment();
+    assert_eq!(Wrapper::lolka(&wrapper), 2);
+    assert_eq!(wrapper.into_lolka(), 2);
+    assert_eq!(<Wrapper as Lolka>::initial().0, 1);
+    let boxed = Box::new(3u32);
+    assert_eq!(boxed.lolka(), 3);
+    assert_eq!(Named { value: 4 }.lolka(), 4);
+    assert_eq!(Delegated(5).into_lolka(), 5);
+    assert_eq!(Plain(6u32).lolka(), 6);
+
+    let mut cell = RefCell::new(7u32);
+    Counter::reset(&mut cell);
+    assert_eq!(TryCounter::get(&cell), Ok(0));
+    assert_eq!(Counter::get(&Mutex::new(8u32)), 8);
+    assert_eq!(Counter::get(&Weak::<u32>::new()), 0);
+    assert_eq!(Counter::get(&Rc::new(9u32)), 9);
+    assert_eq!(Either::<u32, Wrapper>::Right(Wrapper(10)).get(), 10);
+    assert_eq!(Cached::new(11u32).get(), 11);
+    assert_eq!(Any::from(Box::new(12u32)).get(), 12);
+
+    let pair = (core::cell::Cell::new(0), core::cell::Cell::new(0));
+    pair.notify(13);
+    assert_eq!((pair.0.get(), pair.1.get()), (13, 13));
+    Observer::notify(&|value| assert_eq!(value, 14), 14);
+
+    let mut timed = Box::pin(Timed {
+        inner: 15u32,
+    });
+    assert_eq!(timed.as_mut().tick(), 16);
+    assert_eq!(Derived(17).exported(), 17);
+    assert_eq!(Meters(18).exported(), 18);
+}
@@ -0,0 +1,67 @@
+use auto_trait::auto_trait_impl;
+
+mod codec {
+    pub trait Codec {
+        fn encode(&self) -> Vec<u8>;
+
+        fn decode(&mut self, bytes: &[u8]);
+    }
+
+    impl Codec for Vec<u8> {
+        fn encode(&self) -> Vec<u8> {
+            self.clone()
+        }
+
+        fn decode(&mut self, bytes: &[u8]) {
+            self.extend_from_slice(bytes);
+        }
+    }
+}
+
+use codec::Codec;
+
+pub struct Wrapper(Vec<u8>);
+
+impl core::ops::Deref for Wrapper {
+    type Target = Vec<u8>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+auto_trait_impl!(impl codec::Codec for Wrapper, Box<T: codec::Codec> {
+    fn encode(&self) -> Vec<u8>;
+    fn decode(&mut self, bytes: &[u8]);
+});
+
+pub struct Name(String);
+
+impl core::ops::Deref for Name {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+auto_trait_impl!(impl std::fmt::Display for Name {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+});
+
+#[test]
+fn should_implement_foreign_trait() {
+    let mut wrapper = Wrapper(vec![1]);
+    wrapper.decode(&[2]);
+    assert_eq!(wrapper.encode(), [1, 2]);
+
+    let mut boxed = Box::new(vec![1]);
+    boxed.decode(&[2]);
+    assert_eq!(boxed.encode(), [1, 2]);
+
+    assert_eq!(Name("lolka".to_owned()).to_string(), "lolka");
+}
@@ -0,0 +1,604 @@
+use auto_trait::auto_trait;
+
+pub struct Borrowed<'a>(&'a u32);
+
+impl<'a> core::ops::Deref for Borrowed<'a> {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+pub struct Holder<'a, T>(&'a mut T);
+
+impl<'a, T> core::ops::Deref for Holder<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'a, T> core::ops::DerefMut for Holder<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
+#[auto_trait(Borrowed<'a>)]
+#[auto_trait(Holder<'a, T: Lolka>)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[auto_trait(Holder<'a, T: LolkaMut>)]
+pub trait LolkaMut {
+    fn lolka_mut(&mut self) -> u32;
+}
+
+impl LolkaMut for u32 {
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+#[test]
+fn should_forward_to_lifetime_target() {
+    let value = 1u32;
+    let wrapped = Borrowed(&value);
+    assert_eq!(wrapped.lolka_ref(), value.lolka_ref());
+}
+
+#[test]
+fn should_forward_to_lifetime_and_bound_target() {
+    let mut value = 1u32;
+    let mut wrapped = Holder(&mut value);
+    assert_eq!(wrapped.lolka_ref(), 2);
+    assert_eq!(wrapped.lolka_mut(), 2);
+    assert_eq!(value, 2);
+}
+
+pub struct ArrayWrap<T, const N: usize>([T; N]);
+
+impl<T, const N: usize> core::ops::Deref for ArrayWrap<T, N> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0[0]
+    }
+}
+
+pub struct Fixed<const N: usize>(u32);
+
+impl<const N: usize> core::ops::Deref for Fixed<N> {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(ArrayWrap<T: LolkaConst, const N: usize>)]
+#[auto_trait(Fixed<const N: usize>)]
+pub trait LolkaConst {
+    fn lolka_const(&self) -> u32;
+}
+
+impl LolkaConst for u32 {
+    fn lolka_const(&self) -> u32 {
+        *self * 2
+    }
+}
+
+#[test]
+fn should_forward_to_const_generic_target() {
+    let wrapped = ArrayWrap([3u32, 4u32]);
+    assert_eq!(wrapped.lolka_const(), 6);
+
+    let wrapped = Fixed::<5>(5);
+    assert_eq!(wrapped.lolka_const(), 10);
+}
+
+pub struct Tagged<T, Meta>(T, core::marker::PhantomData<Meta>);
+
+impl<T, Meta> core::ops::Deref for Tagged<T, Meta> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, Meta> core::ops::DerefMut for Tagged<T, Meta> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+pub struct Meters;
+
+#[auto_trait(for<Meta> Tagged<T: LolkaTagged, Meta>)]
+pub trait LolkaTagged {
+    fn lolka_tagged(&self) -> u32;
+    fn lolka_tagged_mut(&mut self) -> u32;
+}
+
+impl LolkaTagged for u32 {
+    fn lolka_tagged(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_tagged_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+#[test]
+fn should_forward_to_target_with_unbound_parameter() {
+    let mut wrapped = Tagged::<u32, Meters>(1, core::marker::PhantomData);
+    assert_eq!(wrapped.lolka_tagged(), 1);
+    assert_eq!(wrapped.lolka_tagged_mut(), 2);
+    assert_eq!(wrapped.0, 2);
+}
+
+pub struct Left<A, B>(A, B);
+
+impl<A, B> core::ops::Deref for Left<A, B> {
+    type Target = A;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Right<A, B>(A, B);
+
+impl<A, B> core::ops::Deref for Right<A, B> {
+    type Target = B;
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+#[auto_trait(Left<A: LolkaPair, B: LolkaPair>, forward = A)]
+#[auto_trait(Right<A: LolkaPair, B: LolkaPair>, forward = B)]
+pub trait LolkaPair {
+    fn lolka_pair(&self) -> u32;
+}
+
+impl LolkaPair for u32 {
+    fn lolka_pair(&self) -> u32 {
+        *self
+    }
+}
+
+impl LolkaPair for u8 {
+    fn lolka_pair(&self) -> u32 {
+        *self as u32 + 100
+    }
+}
+
+#[test]
+fn should_forward_to_selected_parameter() {
+    assert_eq!(Left(1u32, 2u8).lolka_pair(), 1);
+    assert_eq!(Right(1u32, 2u8).lolka_pair(), 102);
+}
+
+pub struct Inner<T>(T);
+
+impl<T> core::ops::Deref for Inner<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for Inner<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+pub struct Outer<T>(T);
+
+impl<T> core::ops::Deref for Outer<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for Outer<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait(Box<Inner<T: LolkaNested>>)]
+#[auto_trait(Box<Outer<Inner<T: LolkaNested>>>)]
+pub trait LolkaNested {
+    fn lolka_nested(&self) -> u32;
+    fn lolka_nested_mut(&mut self) -> u32;
+}
+
+impl LolkaNested for u32 {
+    fn lolka_nested(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_nested_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+#[test]
+fn should_forward_to_nested_target() {
+    let mut wrapped = Box::new(Inner(1u32));
+    assert_eq!(wrapped.lolka_nested(), 1);
+    assert_eq!(wrapped.lolka_nested_mut(), 2);
+
+    let mut wrapped = Box::new(Outer(Inner(1u32)));
+    assert_eq!(wrapped.lolka_nested(), 1);
+    assert_eq!(wrapped.lolka_nested_mut(), 2);
+    assert_eq!((wrapped.0).0, 2);
+}
+
+pub struct SmallVec<A> {
+    buffer: A,
+    len: usize,
+}
+
+impl<T, const N: usize> core::ops::Deref for SmallVec<[T; N]> {
+    type Target = [T];
+    fn deref(&self) -> &Self::Target {
+        &self.buffer[..self.len]
+    }
+}
+
+impl<T, const N: usize> core::ops::DerefMut for SmallVec<[T; N]> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer[..self.len]
+    }
+}
+
+#[auto_trait(SmallVec<[u8; 16]>)]
+#[auto_trait(Vec<u8>)]
+pub trait LolkaSlice {
+    fn lolka_sum(&self) -> u32;
+    fn lolka_clear(&mut self);
+}
+
+impl LolkaSlice for [u8] {
+    fn lolka_sum(&self) -> u32 {
+        self.iter().map(|byte| *byte as u32).sum()
+    }
+
+    fn lolka_clear(&mut self) {
+        for byte in self.iter_mut() {
+            *byte = 0;
+        }
+    }
+}
+
+#[test]
+fn should_forward_array_argument_target_to_slice() {
+    let mut wrapped = SmallVec {
+        buffer: [1u8; 16],
+        len: 3,
+    };
+    assert_eq!(wrapped.lolka_sum(), 3);
+    wrapped.lolka_clear();
+    assert_eq!(wrapped.lolka_sum(), 0);
+    assert_eq!(wrapped.buffer[3], 1);
+
+    let mut wrapped = vec![1u8, 2, 3];
+    assert_eq!(wrapped.lolka_sum(), 6);
+    wrapped.lolka_clear();
+    assert_eq!(wrapped, [0, 0, 0]);
+}
+
+pub struct Counter(u32);
+
+impl Iterator for Counter {
+    type Item = u32;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0 += 1;
+        Some(self.0)
+    }
+}
+
+#[auto_trait(Box<T: Iterator<Item = u32> + LolkaIter>)]
+#[auto_trait(Inner<T: LolkaIter + Iterator<Item = u32>>)]
+pub trait LolkaIter {
+    fn lolka_next_sum(&mut self, count: usize) -> u32;
+}
+
+impl LolkaIter for Counter {
+    fn lolka_next_sum(&mut self, count: usize) -> u32 {
+        self.take(count).sum()
+    }
+}
+
+fn next_sum<T: LolkaIter + Iterator<Item = u32>>(mut iter: T) -> (u32, Option<u32>) {
+    let sum = iter.lolka_next_sum(2);
+    (sum, iter.next())
+}
+
+#[test]
+fn should_preserve_associated_type_bindings() {
+    assert_eq!(next_sum(Box::new(Counter(0))), (3, Some(3)));
+
+    let mut wrapped = Inner(Counter(1));
+    assert_eq!(wrapped.lolka_next_sum(1), 2);
+}
+
+pub struct Global;
+pub struct MyAlloc;
+
+pub struct MyWrap<T, A = Global>(T, core::marker::PhantomData<A>);
+
+impl<T, A> MyWrap<T, A> {
+    fn new(value: T) -> Self {
+        Self(value, core::marker::PhantomData)
+    }
+}
+
+impl<T, A> core::ops::Deref for MyWrap<T, A> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(MyWrap<T: LolkaDefault>)]
+#[auto_trait(MyWrap<T: LolkaDefault, MyAlloc>)]
+pub trait LolkaDefault {
+    fn lolka_default(&self) -> u32;
+}
+
+#[auto_trait(for<A> MyWrap<T: LolkaGeneric, A>)]
+#[auto_trait(Box<MyWrap<T: LolkaGeneric, MyAlloc>>)]
+pub trait LolkaGeneric {
+    fn lolka_generic(&self) -> u32;
+}
+
+impl LolkaDefault for u32 {
+    fn lolka_default(&self) -> u32 {
+        *self
+    }
+}
+
+impl LolkaGeneric for u32 {
+    fn lolka_generic(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[test]
+fn should_forward_to_target_with_default_parameter() {
+    assert_eq!(MyWrap::<u32>::new(1).lolka_default(), 1);
+    assert_eq!(MyWrap::<u32, MyAlloc>::new(2).lolka_default(), 2);
+
+    assert_eq!(MyWrap::<u32>::new(1).lolka_generic(), 2);
+    assert_eq!(MyWrap::<u32, MyAlloc>::new(2).lolka_generic(), 3);
+    assert_eq!(Box::new(MyWrap::<u32, MyAlloc>::new(3)).lolka_generic(), 4);
+}
+
+pub struct Bytes(Vec<u8>);
+
+#[auto_trait(for<'a> std::borrow::Cow<'a, str>, deref = str::as_bytes)]
+#[auto_trait(for<'a> &'a Bytes)]
+pub trait LolkaFor {
+    fn lolka_for(&self) -> usize;
+}
+
+impl LolkaFor for [u8] {
+    fn lolka_for(&self) -> usize {
+        self.len()
+    }
+}
+
+impl LolkaFor for Bytes {
+    fn lolka_for(&self) -> usize {
+        self.0.len() * 2
+    }
+}
+
+fn lolka_for(lolka: impl LolkaFor) -> usize {
+    lolka.lolka_for()
+}
+
+#[test]
+fn should_declare_explicit_parameters() {
+    let cow = std::borrow::Cow::Borrowed("lolka");
+    assert_eq!(cow.lolka_for(), 5);
+
+    let bytes = Bytes(vec![1, 2]);
+    assert_eq!(lolka_for(&bytes), 4);
+}
+
+pub struct Pair<A, B>(A, B);
+
+impl<A, B> core::ops::Deref for Pair<A, B> {
+    type Target = B;
+
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+#[auto_trait(Box<T> where T: LolkaWhere + Send)]
+#[auto_trait(Pair<A, B> where A: Clone, B: LolkaWhere)]
+pub trait LolkaWhere {
+    fn lolka_where(&self) -> u32;
+}
+
+#[auto_trait(Pair<A, B: LolkaWhereMixed> where A: Clone)]
+pub trait LolkaWhereMixed {
+    fn lolka_where_mixed(&self) -> u32;
+}
+
+impl LolkaWhere for u32 {
+    fn lolka_where(&self) -> u32 {
+        *self
+    }
+}
+
+impl LolkaWhereMixed for u32 {
+    fn lolka_where_mixed(&self) -> u32 {
+        *self + 1
+    }
+}
+
+fn lolka_where(lolka: impl LolkaWhere) -> u32 {
+    lolka.lolka_where()
+}
+
+#[test]
+fn should_forward_to_target_with_where_clause() {
+    assert_eq!(Box::new(1u32).lolka_where(), 1);
+    assert_eq!(lolka_where(Pair("lolka", 2u32)), 2);
+    assert_eq!(Pair('a', 3u32).lolka_where_mixed(), 4);
+}
+
+#[auto_trait(Box<T: LolkaParam>)]
+#[auto_trait(std::rc::Rc<U: LolkaParam>, &)]
+pub trait LolkaParam<T> {
+    fn lolka_param(&self, value: T) -> u32;
+
+    fn lolka_convert<U: From<u32>>(&self, value: T) -> U {
+        U::from(self.lolka_param(value))
+    }
+}
+
+impl<T: Into<u32>> LolkaParam<T> for u32 {
+    fn lolka_param(&self, value: T) -> u32 {
+        *self + value.into()
+    }
+}
+
+fn lolka_param<T: LolkaParam<u8>>(lolka: T) -> u32 {
+    lolka.lolka_param(1)
+}
+
+#[test]
+fn should_rename_parameters_colliding_with_trait() {
+    assert_eq!(lolka_param(Box::new(1u32)), 2);
+    assert_eq!(lolka_param(std::rc::Rc::new(2u32)), 3);
+    let lolka = 3u32;
+    let lolka_ref = &lolka;
+    assert_eq!(lolka_param(lolka_ref), 4);
+    assert_eq!(Box::new(1u32).lolka_convert::<u64>(2u8), 3);
+}
+
+#[auto_trait(Borrowed<'a> for LolkaConvert<u32>, Holder<'a, T: LolkaConvert<u64>> for LolkaConvert<u64>)]
+pub trait LolkaConvert<T> where T: Copy {
+    fn lolka_convert(&self, value: T) -> T;
+
+    fn lolka_twice(&self, value: T) -> T {
+        self.lolka_convert(self.lolka_convert(value))
+    }
+}
+
+impl LolkaConvert<u32> for u32 {
+    fn lolka_convert(&self, value: u32) -> u32 {
+        *self + value
+    }
+}
+
+impl LolkaConvert<u64> for u32 {
+    fn lolka_convert(&self, value: u64) -> u64 {
+        u64::from(*self) * value
+    }
+}
+
+#[test]
+fn should_implement_trait_for_specified_arguments() {
+    let value = 2u32;
+    let borrowed = Borrowed(&value);
+    assert_eq!(borrowed.lolka_convert(1u32), 3);
+    assert_eq!(borrowed.lolka_twice(1u32), 5);
+
+    let mut value = 3u32;
+    let holder = Holder(&mut value);
+    assert_eq!(holder.lolka_convert(2u64), 6);
+    assert_eq!(holder.lolka_twice(2u64), 18);
+}
+
+#[auto_trait(Holder<'a, T>, generics = "<'a, T> where T: LolkaOverride + Copy")]
+pub trait LolkaOverride {
+    fn lolka_override(&self) -> u32;
+}
+
+impl LolkaOverride for u32 {
+    fn lolka_override(&self) -> u32 {
+        *self * 10
+    }
+}
+
+#[test]
+fn should_use_overridden_generics() {
+    let mut value = 2u32;
+    let holder = Holder(&mut value);
+    assert_eq!(holder.lolka_override(), 20);
+}
+
+#[auto_trait(Box<T: LolkaBound<u32>>, std::rc::Rc<T> where T: LolkaBound<u64>)]
+#[auto_trait(for<'a, U> &'a U where U: LolkaBound<&'a str>)]
+pub trait LolkaBound<T> {
+    fn lolka_bound(&self, value: T) -> u64;
+}
+
+impl LolkaBound<u32> for u32 {
+    fn lolka_bound(&self, value: u32) -> u64 {
+        u64::from(*self + value)
+    }
+}
+
+impl LolkaBound<u64> for u32 {
+    fn lolka_bound(&self, value: u64) -> u64 {
+        u64::from(*self) * value
+    }
+}
+
+impl<'a> LolkaBound<&'a str> for u32 {
+    fn lolka_bound(&self, value: &'a str) -> u64 {
+        u64::from(*self) + value.len() as u64
+    }
+}
+
+#[test]
+fn should_take_trait_arguments_from_bound() {
+    assert_eq!(Box::new(2u32).lolka_bound(3u32), 5);
+    assert_eq!(std::rc::Rc::new(2u32).lolka_bound(3u64), 6);
+    let value = 2u32;
+    let value_ref = &value;
+    assert_eq!(value_ref.lolka_bound("lolka"), 7);
+}
+
+#[auto_trait(Box<T: LolkaPick>, std::rc::Rc<U: LolkaPick>)]
+pub trait LolkaPick {
+    fn lolka_pick<T: Into<u32>>(&self, value: T) -> u32;
+    fn lolka_pick_other<U: Into<u32>, T: Into<u32>>(&self, left: U, right: T) -> u32;
+}
+
+impl LolkaPick for u32 {
+    fn lolka_pick<T: Into<u32>>(&self, value: T) -> u32 {
+        *self + value.into()
+    }
+
+    fn lolka_pick_other<U: Into<u32>, T: Into<u32>>(&self, left: U, right: T) -> u32 {
+        *self + left.into() * right.into()
+    }
+}
+
+#[test]
+fn should_rename_parameters_colliding_with_method_parameters() {
+    assert_eq!(Box::new(1u32).lolka_pick(2u8), 3);
+    assert_eq!(Box::new(1u32).lolka_pick_other(2u8, 3u16), 7);
+    assert_eq!(std::rc::Rc::new(1u32).lolka_pick_other(2u8, 3u16), 7);
+}
@@ -0,0 +1,105 @@
+use auto_trait::auto_trait;
+
+use core::cell::{Ref, RefCell, RefMut};
+use std::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+#[auto_trait(MutexGuard<'a, T: Lolka>)]
+#[auto_trait(RefMut<'a, T: Lolka>)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32;
+
+    fn lolka_self(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+}
+
+#[auto_trait(RwLockReadGuard<'a, T: LolkaShared>)]
+#[auto_trait(Ref<'a, T: LolkaShared>)]
+pub trait LolkaShared {
+    fn lolka_shared(&self) -> u32;
+
+    fn lolka_shared_mut(&mut self) -> u32 {
+        0
+    }
+}
+
+impl LolkaShared for u32 {
+    fn lolka_shared(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_shared_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+fn call_ref<T: Lolka>(value: &T) -> u32 {
+    value.lolka_ref()
+}
+
+#[auto_trait(RwLockWriteGuard<'a, T: LolkaExclusive>)]
+pub trait LolkaExclusive {
+    fn lolka_exclusive(&mut self) -> u32;
+}
+
+impl LolkaExclusive for u32 {
+    fn lolka_exclusive(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+#[test]
+fn should_forward_through_mutex_guard() {
+    let mutex = Mutex::new(1u32);
+    let mut guard = mutex.lock().unwrap();
+    assert_eq!(call_ref(&guard), 1);
+    assert_eq!(guard.lolka_mut(), 2);
+    assert_eq!(Lolka::lolka_self(guard), 0);
+    assert_eq!(*mutex.lock().unwrap(), 2);
+}
+
+#[test]
+fn should_forward_through_rw_lock_guards() {
+    let lock = RwLock::new(1u32);
+    {
+        let mut guard = lock.read().unwrap();
+        assert_eq!(guard.lolka_shared(), 1);
+        assert_eq!(guard.lolka_shared_mut(), 0);
+    }
+
+    let mut guard = lock.write().unwrap();
+    assert_eq!(guard.lolka_exclusive(), 2);
+}
+
+#[test]
+fn should_forward_through_ref_guards() {
+    let cell = RefCell::new(1u32);
+    {
+        let mut guard = cell.borrow();
+        assert_eq!(guard.lolka_shared(), 1);
+        assert_eq!(guard.lolka_shared_mut(), 0);
+    }
+
+    let mut guard = cell.borrow_mut();
+    assert_eq!(guard.lolka_mut(), 2);
+    assert_eq!(call_ref(&guard), 2);
+}
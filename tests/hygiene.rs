@@ -0,0 +1,99 @@
+//!Identifiers introduced by generated code are hygienic, hence parameters named the same are neither shadowed nor duplicated
+
+use auto_trait::auto_trait;
+
+use core::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+thread_local! {
+    static CALLS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+mod my_hooks {
+    pub fn before(method: &'static str) {
+        super::CALLS.with(|calls| calls.borrow_mut().push(method));
+    }
+
+    pub fn after(_: &'static str) {
+    }
+}
+
+pub struct Wrapper(pub u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+//Parameters are named after bindings of generated code: renamed pattern, matched variant, upgraded pointer and result of hooks
+#[auto_trait(Box<T: Lolka>, shared)]
+#[auto_trait(Wrapper, inherent)]
+#[auto_trait(Either<L: Lolka, R: Lolka>, dispatch = match)]
+#[auto_trait(Weak<T: Lolka>, upgrade = default)]
+#[auto_trait(RefCell<T: Lolka>, try_trait(TryLolka))]
+#[auto_trait(newtype = Logged, hooks = my_hooks)]
+pub trait Lolka {
+    fn lolka(&self, (left, right): (u32, u32), __arg1: u32) -> u32 {
+        left + right + __arg1
+    }
+    fn variant(&self, __inner: u32) -> u32;
+    fn upgraded(&self, strong: u32, __strong: u32) -> u32;
+    fn result(&self, __result: u32) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self, (left, right): (u32, u32), value: u32) -> u32 {
+        *self + left * right + value
+    }
+
+    fn variant(&self, value: u32) -> u32 {
+        *self + value
+    }
+
+    fn upgraded(&self, strong: u32, weak: u32) -> u32 {
+        *self + strong * weak
+    }
+
+    fn result(&self, value: u32) -> u32 {
+        *self * value
+    }
+}
+
+#[test]
+fn should_forward_parameters_named_as_generated_bindings() {
+    let boxed = Box::new(1u32);
+    assert_eq!(boxed.lolka((2, 3), 4), 11);
+    assert_eq!(boxed.variant(2), 3);
+    assert_eq!(boxed.upgraded(2, 3), 7);
+    assert_eq!(boxed.result(5), 5);
+
+    let wrapper = Wrapper(2);
+    assert_eq!(Wrapper::lolka(&wrapper, (1, 1), 1), 4);
+    assert_eq!(Wrapper::variant(&wrapper, 3), 5);
+
+    let either = Either::<u32, Box<u32>>::Right(Box::new(3));
+    assert_eq!(either.variant(4), 7);
+    assert_eq!(either.lolka((1, 2), 3), 8);
+
+    let strong = Rc::new(4u32);
+    let weak = Rc::downgrade(&strong);
+    assert_eq!(weak.upgraded(2, 3), 10);
+    drop(strong);
+    assert_eq!(weak.upgraded(2, 3), 0);
+
+    let cell = RefCell::new(5u32);
+    assert_eq!(TryLolka::lolka(&cell, (1, 1), 1), Ok(7));
+    assert_eq!(Lolka::upgraded(&cell, 1, 1), 6);
+
+    let logged = Logged(6u32);
+    assert_eq!(logged.result(2), 12);
+    assert_eq!(logged.lolka((1, 1), 1), 8);
+    assert_eq!(CALLS.with(|calls| calls.take()), ["result", "lolka"]);
+}
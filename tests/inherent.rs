@@ -0,0 +1,57 @@
+mod api {
+    use auto_trait::auto_trait;
+
+    pub struct Wrapper(pub u32);
+
+    impl core::ops::Deref for Wrapper {
+        type Target = u32;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl core::ops::DerefMut for Wrapper {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    #[auto_trait(self::Wrapper, inherent)]
+    pub trait Lolka {
+        type Output;
+
+        fn lolka_ref(&self) -> u32;
+        fn lolka_mut(&mut self, value: u32) -> u32;
+        fn lolka_output(&self) -> Self::Output;
+
+        fn lolka_default(&self) -> u32 {
+            self.lolka_ref() * 2
+        }
+    }
+
+    impl Lolka for u32 {
+        type Output = Option<u32>;
+
+        fn lolka_ref(&self) -> u32 {
+            *self + 1
+        }
+
+        fn lolka_mut(&mut self, value: u32) -> u32 {
+            *self += value + 1;
+            *self
+        }
+
+        fn lolka_output(&self) -> Self::Output {
+            Some(*self)
+        }
+    }
+}
+
+#[test]
+fn should_call_inherent_methods_without_trait() {
+    let mut wrapper = api::Wrapper(1);
+    assert_eq!(wrapper.lolka_ref(), 2);
+    assert_eq!(wrapper.lolka_default(), 4);
+    assert_eq!(wrapper.lolka_mut(1), 3);
+    assert_eq!(wrapper.lolka_output(), Some(3));
+}
@@ -0,0 +1,67 @@
+use auto_trait::auto_trait;
+
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use std::cell::RefCell;
+
+thread_local! {
+    static CALLS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn on_call(trait_name: &str, method: &str) {
+    CALLS.with(|calls| calls.borrow_mut().push(format!("{}::{}", trait_name, method)));
+}
+
+fn calls() -> Vec<String> {
+    CALLS.with(|calls| calls.borrow_mut().drain(..).collect())
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[allow(async_fn_in_trait)]
+#[auto_trait(Box<T: Service>, self::Wrapper => u32, instrument(on_call = on_call))]
+pub trait Service {
+    fn name(&self) -> &'static str;
+
+    async fn call(&self, request: u32) -> u32;
+}
+
+impl Service for u32 {
+    fn name(&self) -> &'static str {
+        "u32"
+    }
+
+    async fn call(&self, request: u32) -> u32 {
+        *self + request
+    }
+}
+
+#[test]
+fn should_call_hook_before_forwarded_call() {
+    let service = Box::new(1u32);
+    assert_eq!(service.name(), "u32");
+    assert_eq!(calls(), ["Service::name"]);
+    assert_eq!(block_on(service.call(2)), 3);
+    assert_eq!(calls(), ["Service::call"]);
+    assert_eq!(block_on(Wrapper(5).call(1)), 6);
+    assert_eq!(calls(), ["Service::call"]);
+}
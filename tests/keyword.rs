@@ -0,0 +1,82 @@
+use auto_trait::auto_trait;
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[auto_trait(&, &mut, Box, Rc, Arc)]
+pub trait Lolka {
+    fn lolka() -> u32;
+
+    fn lolka_ref(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka() -> u32 {
+        1
+    }
+
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+}
+
+impl Lolka for str {
+    fn lolka() -> u32 {
+        2
+    }
+
+    fn lolka_ref(&self) -> u32 {
+        self.len() as u32
+    }
+}
+
+#[auto_trait(&mut, Box)]
+pub trait LolkaMut {
+    fn lolka_mut(&mut self) -> u32;
+}
+
+impl LolkaMut for u32 {
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+#[auto_trait(Box)]
+pub trait LolkaSelf {
+    fn lolka_self(self) -> u32;
+}
+
+impl LolkaSelf for u32 {
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+}
+
+fn lolka_ref(lolka: impl Lolka) -> u32 {
+    lolka.lolka_ref()
+}
+
+#[test]
+fn should_implement_for_keyword_targets() {
+    let mut value = 5u32;
+    let by_ref = &value;
+    assert_eq!(lolka_ref(by_ref), 5);
+    let by_mut = &mut value;
+    assert_eq!(lolka_ref(by_mut), 5);
+    assert_eq!(lolka_ref(Box::new(5u32)), 5);
+    assert_eq!(lolka_ref(Rc::new(5u32)), 5);
+    assert_eq!(lolka_ref(Arc::new(5u32)), 5);
+    assert_eq!(<&u32 as Lolka>::lolka(), 1);
+    assert_eq!(<Arc<u32> as Lolka>::lolka(), 1);
+
+    assert_eq!(lolka_ref("lolka"), 5);
+    let boxed: Box<str> = "lolka".into();
+    assert_eq!(lolka_ref(boxed), 5);
+    assert_eq!(<Rc<str> as Lolka>::lolka(), 2);
+
+    let mut by_mut = &mut value;
+    assert_eq!(<&mut u32 as LolkaMut>::lolka_mut(&mut by_mut), 6);
+    assert_eq!(Box::new(value).lolka_mut(), 7);
+    assert_eq!(Box::new(value).lolka_self(), 60);
+}
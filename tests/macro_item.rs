@@ -0,0 +1,33 @@
+use auto_trait::auto_trait;
+
+macro_rules! provided {
+    ($name:ident) => {
+        fn $name(&self) -> u32 {
+            0
+        }
+    };
+}
+
+#[auto_trait(Box<T: Lolka>)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+
+    #[auto_trait(skip)]
+    provided!(lolka_provided);
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_provided(&self) -> u32 {
+        1
+    }
+}
+
+#[test]
+fn should_skip_macro_invocation() {
+    assert_eq!(Lolka::lolka(&Box::new(2u32)), 2);
+    assert_eq!(Lolka::lolka_provided(&Box::new(2u32)), 0);
+}
@@ -0,0 +1,139 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Pair<T>(T, T);
+
+impl<T> core::ops::Deref for Pair<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+macro_rules! forward_trait {
+    ($first:ty, $second:ty, $name:ident) => {
+        #[auto_trait($first)]
+        #[auto_trait($second)]
+        pub trait $name {
+            fn value(&self) -> u32;
+        }
+
+        impl $name for u32 {
+            fn value(&self) -> u32 {
+                *self
+            }
+        }
+    };
+}
+
+forward_trait!(Wrapper, Box<u32>, Lolka);
+
+macro_rules! forward_keyword {
+    ($first:ident, $second:path) => {
+        #[auto_trait(Pair<T: Kek>, $first)]
+        #[auto_trait($second)]
+        pub trait Kek {
+            fn kek(&self) -> u32;
+        }
+
+        impl Kek for u32 {
+            fn kek(&self) -> u32 {
+                *self
+            }
+        }
+    };
+}
+
+forward_keyword!(Box, Rc);
+
+macro_rules! forward_path {
+    ($target:path) => {
+        #[auto_trait(Box<T: Cheburek>)]
+        #[auto_trait($target; inline)]
+        pub trait Cheburek {
+            fn cheburek(&self) -> u32;
+        }
+
+        impl Cheburek for u32 {
+            fn cheburek(&self) -> u32 {
+                *self + 1
+            }
+        }
+    };
+}
+
+forward_path!(self::Wrapper);
+
+#[test]
+fn should_forward_targets_from_declarative_macro() {
+    assert_eq!(Wrapper(1).value(), 1);
+    assert_eq!(Box::new(2u32).value(), 2);
+
+    assert_eq!(Pair(1u32, 3u32).kek(), 3);
+    assert_eq!(Box::new(4u32).kek(), 4);
+    assert_eq!(std::rc::Rc::new(5u32).kek(), 5);
+
+    assert_eq!(Wrapper(6).cheburek(), 7);
+    assert_eq!(Box::new(7u32).cheburek(), 8);
+}
+
+macro_rules! forward_type {
+    ($first:ty, $second:ty) => {
+        #[auto_trait($first)]
+        #[auto_trait($second, for<T: Pepega> Pair<T>)]
+        pub trait Pepega {
+            fn pepega(&self) -> u32;
+        }
+
+        impl Pepega for u32 {
+            fn pepega(&self) -> u32 {
+                *self
+            }
+        }
+    };
+}
+
+forward_type!(Rc, std::sync::Arc<u32>);
+
+pub struct Bytes(Vec<u8>);
+
+impl Kappa for Bytes {
+    fn kappa(&self) -> usize {
+        self.0.len()
+    }
+}
+
+macro_rules! forward_reference {
+    ($target:ty) => {
+        #[auto_trait(Box<T: Kappa>)]
+        #[auto_trait($target)]
+        pub trait Kappa {
+            fn kappa(&self) -> usize;
+        }
+    };
+}
+
+forward_reference!(&'static Bytes);
+
+fn kappa(kappa: impl Kappa) -> usize {
+    kappa.kappa()
+}
+
+#[test]
+fn should_forward_type_fragments_from_declarative_macro() {
+    assert_eq!(std::rc::Rc::new(2u32).pepega(), 2);
+    assert_eq!(std::sync::Arc::new(3u32).pepega(), 3);
+    assert_eq!(Pair(4u32, 5u32).pepega(), 5);
+
+    static BYTES: Bytes = Bytes(Vec::new());
+    assert_eq!(kappa(&BYTES), 0);
+    assert_eq!(Box::new(Bytes(vec![1, 2])).kappa(), 2);
+}
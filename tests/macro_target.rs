@@ -0,0 +1,72 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper<T>(T);
+
+impl<T> core::ops::Deref for Wrapper<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Wrapper<u32>> for u32 {
+    fn from(wrapper: Wrapper<u32>) -> Self {
+        wrapper.0
+    }
+}
+
+macro_rules! wrapper_ty {
+    ($inner:ty) => {
+        Wrapper<$inner>
+    };
+}
+
+macro_rules! boxed_ty {
+    ($inner:ty) => {
+        Box<$inner>
+    };
+}
+
+#[auto_trait(wrapper_ty!(u32) => u32)]
+pub trait Lolka {
+    const LOLKA: u32;
+
+    fn lolka_ref(&self) -> u32;
+    fn lolka_self(self) -> u32;
+}
+
+impl Lolka for u32 {
+    const LOLKA: u32 = 10;
+
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self + 1
+    }
+}
+
+#[auto_trait(for<T: Kek> boxed_ty!(T))]
+pub trait Kek {
+    const KEK: u32;
+
+    fn kek_ref(&self) -> u32;
+}
+
+impl Kek for u32 {
+    const KEK: u32 = 20;
+
+    fn kek_ref(&self) -> u32 {
+        *self
+    }
+}
+
+#[test]
+fn should_implement_trait_for_macro_type() {
+    assert_eq!(Wrapper(1u32).lolka_ref(), 1);
+    assert_eq!(Wrapper(1u32).lolka_self(), 2);
+    assert_eq!(<Wrapper<u32> as Lolka>::LOLKA, 10);
+    assert_eq!(Kek::kek_ref(&Box::new(3u32)), 3);
+    assert_eq!(<Box<u32> as Kek>::KEK, 20);
+}
@@ -0,0 +1,57 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Inverted(u32);
+
+impl core::ops::Deref for Inverted {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+fn double(value: u32) -> u32 {
+    value * 2
+}
+
+fn invert(value: bool) -> bool {
+    !value
+}
+
+#[auto_trait(self::Wrapper, map_return = double)]
+#[auto_trait(self::Inverted, strict)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    #[auto_trait(map_return = invert)]
+    fn lolka_zero(&self) -> bool;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self + 1
+    }
+
+    fn lolka_zero(&self) -> bool {
+        *self == 0
+    }
+}
+
+#[test]
+fn should_map_return_of_forwarded_call() {
+    let wrapper = Wrapper(2);
+    assert_eq!(wrapper.lolka_ref(), 6);
+    assert!(wrapper.lolka_zero());
+
+    let inverted = Inverted(0);
+    assert_eq!(inverted.lolka_ref(), 1);
+    assert!(!inverted.lolka_zero());
+}
@@ -0,0 +1,70 @@
+use auto_trait::auto_trait;
+
+use std::sync::{Arc, Weak};
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.0
+    }
+}
+
+//Methods share names with the ones used to forward them
+#[auto_trait(Wrapper => u32)]
+pub trait Lolka {
+    fn into(self) -> u32;
+    fn deref(&self) -> u32;
+    fn deref_mut(&mut self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn into(self) -> u32 {
+        self
+    }
+
+    fn deref(&self) -> u32 {
+        *self + 1
+    }
+
+    fn deref_mut(&mut self) -> u32 {
+        *self += 2;
+        *self
+    }
+}
+
+#[auto_trait(Weak<T: Kek>, std::rc::Weak<T: Kek>, upgrade = default)]
+pub trait Kek {
+    fn upgrade(&self) -> u32;
+}
+
+impl Kek for u32 {
+    fn upgrade(&self) -> u32 {
+        *self
+    }
+}
+
+#[test]
+fn should_not_resolve_forwarding_to_trait_methods() {
+    assert_eq!(Lolka::into(Wrapper(1)), 1);
+    assert_eq!(Lolka::deref(&Wrapper(1)), 2);
+    assert_eq!(Lolka::deref_mut(&mut Wrapper(1)), 3);
+
+    let strong = Arc::new(6u32);
+    assert_eq!(Kek::upgrade(&Arc::downgrade(&strong)), 6);
+    let strong = std::rc::Rc::new(7u32);
+    assert_eq!(Kek::upgrade(&std::rc::Rc::downgrade(&strong)), 7);
+}
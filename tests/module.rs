@@ -0,0 +1,75 @@
+use auto_trait::auto_trait_all;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.0
+    }
+}
+
+#[auto_trait_all(Wrapper, Box<T: Self>)]
+mod traits {
+    use super::Wrapper;
+
+    pub const LOLKA: u32 = 10;
+
+    pub trait Lolka {
+        fn lolka(&self) -> u32;
+    }
+
+    #[auto_trait(std::rc::Rc<T: LolkaRef>)]
+    pub trait LolkaRef {
+        #[auto_trait(body(Wrapper) = { 0 })]
+        fn lolka_ref(&self) -> u32;
+    }
+
+    #[auto_trait_all(skip)]
+    pub trait LolkaSkipped {
+        fn lolka_skipped(&self) -> u32;
+    }
+
+    impl Lolka for u32 {
+        fn lolka(&self) -> u32 {
+            *self + LOLKA
+        }
+    }
+
+    impl LolkaRef for u32 {
+        fn lolka_ref(&self) -> u32 {
+            *self + 1
+        }
+    }
+
+    impl LolkaSkipped for Wrapper {
+        fn lolka_skipped(&self) -> u32 {
+            self.0
+        }
+    }
+}
+
+use traits::{Lolka, LolkaRef, LolkaSkipped};
+
+#[test]
+fn should_forward_every_trait_of_module() {
+    assert_eq!(Wrapper(1).lolka(), 11);
+    assert_eq!(Box::new(2u32).lolka(), 12);
+
+    assert_eq!(Wrapper(1).lolka_ref(), 0);
+    assert_eq!(Box::new(2u32).lolka_ref(), 3);
+    assert_eq!(std::rc::Rc::new(3u32).lolka_ref(), 4);
+    assert_eq!(traits::LOLKA, 10);
+}
+
+#[test]
+fn should_skip_opted_out_trait() {
+    assert_eq!(Wrapper(3).lolka_skipped(), 3);
+}
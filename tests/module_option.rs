@@ -0,0 +1,54 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+//Implementations of both traits are placed into separate modules
+#[allow(unused_imports)]
+use self::{auto_impls_lolka as _, auto_impls_lolka_mut as _};
+
+#[auto_trait(module = auto_impls)]
+#[auto_trait(Wrapper, Box<T: Lolka>)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+#[auto_trait(Wrapper, module = auto_impls)]
+#[auto_trait(Box<T: LolkaMut>)]
+pub trait LolkaMut {
+    fn lolka_mut(&mut self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+impl LolkaMut for u32 {
+    fn lolka_mut(&mut self) -> u32 {
+        *self + 1
+    }
+}
+
+#[test]
+fn should_place_implementations_into_module() {
+    assert_eq!(Wrapper(1).lolka(), 1);
+    assert_eq!(Box::new(2u32).lolka(), 2);
+
+    assert_eq!(Wrapper(1).lolka_mut(), 2);
+    assert_eq!(Box::new(2u32).lolka_mut(), 3);
+}
@@ -0,0 +1,59 @@
+use auto_trait::auto_trait;
+
+use std::sync::{Arc, Mutex};
+
+#[auto_trait(Mutex<T: Lolka>, lock_mut)]
+#[auto_trait(Arc<Mutex<T: Lolka>>, lock_mut)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+    fn lolka_mut(&mut self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+#[auto_trait(Arc<Mutex<T: LolkaRef>>)]
+pub trait LolkaRef {
+    fn lolka_only_ref(&self) -> u32;
+}
+
+impl LolkaRef for u32 {
+    fn lolka_only_ref(&self) -> u32 {
+        *self * 10
+    }
+}
+
+#[test]
+fn should_forward_through_shared_mutex() {
+    let mut shared = Arc::new(Mutex::new(0u32));
+
+    let threads = (0..4).map(|_| {
+        let mut shared = shared.clone();
+        std::thread::spawn(move || {
+            shared.lolka_mut();
+        })
+    }).collect::<Vec<_>>();
+
+    for thread in threads {
+        thread.join().expect("To finish thread");
+    }
+
+    assert_eq!(shared.lolka_ref(), 4);
+    assert_eq!(shared.lolka_mut(), 5);
+    assert_eq!(shared.lolka_only_ref(), 50);
+    assert_eq!(*shared.lock().unwrap(), 5);
+}
+
+#[test]
+fn should_forward_through_mutex() {
+    let mutex = Mutex::new(1u32);
+    assert_eq!(mutex.lolka_ref(), 1);
+}
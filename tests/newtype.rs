@@ -0,0 +1,78 @@
+use auto_trait::auto_trait;
+
+use std::cell::RefCell;
+
+thread_local! {
+    static CALLS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+mod my_hooks {
+    pub fn before(method: &'static str) {
+        super::CALLS.with(|calls| calls.borrow_mut().push(format!("before {}", method)));
+    }
+
+    pub fn after(method: &'static str) {
+        super::CALLS.with(|calls| calls.borrow_mut().push(format!("after {}", method)));
+    }
+}
+
+fn calls() -> Vec<String> {
+    CALLS.with(|calls| calls.borrow_mut().drain(..).collect())
+}
+
+#[auto_trait(newtype = Logged, hooks = my_hooks)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self, value: u32) -> u32;
+
+    fn lolka_self(self) -> u32;
+}
+
+#[auto_trait(newtype = Plain)]
+pub trait Kek {
+    fn kek(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        CALLS.with(|calls| calls.borrow_mut().push("lolka_ref".to_owned()));
+        *self
+    }
+
+    fn lolka_mut(&mut self, value: u32) -> u32 {
+        CALLS.with(|calls| calls.borrow_mut().push("lolka_mut".to_owned()));
+        *self += value;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        CALLS.with(|calls| calls.borrow_mut().push("lolka_self".to_owned()));
+        self * 2
+    }
+}
+
+impl Kek for u32 {
+    fn kek(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[test]
+fn should_call_hooks_around_forwarded_call() {
+    let mut logged = Logged(1u32);
+    assert_eq!(logged.lolka_ref(), 1);
+    assert_eq!(calls(), ["before lolka_ref", "lolka_ref", "after lolka_ref"]);
+    assert_eq!(logged.lolka_mut(2), 3);
+    assert_eq!(calls(), ["before lolka_mut", "lolka_mut", "after lolka_mut"]);
+    assert_eq!(logged.lolka_self(), 6);
+    assert_eq!(calls(), ["before lolka_self", "lolka_self", "after lolka_self"]);
+}
+
+#[test]
+fn should_forward_through_newtype() {
+    let plain = Plain(1u32);
+    assert_eq!(plain.kek(), 2);
+    assert_eq!(*plain, 1);
+    assert_eq!(plain.into_inner(), 1);
+}
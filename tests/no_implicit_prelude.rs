@@ -0,0 +1,361 @@
+//!Generated code names everything by absolute paths, hence it compiles within crate without prelude
+
+#![no_implicit_prelude]
+#![allow(dead_code)]
+
+use ::auto_trait::{auto_trait, auto_trait_impl, auto_trait_std, AutoTrait};
+use ::core::cell::{Cell, RefCell};
+use ::core::convert::From;
+use ::core::result::Result::Ok;
+use ::std::boxed::Box;
+use ::std::rc::{Rc, Weak};
+use ::std::sync::{Arc, Mutex};
+use ::std::{assert_eq, thread_local, vec};
+use ::std::vec::Vec;
+
+thread_local! {
+    static CALLS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+mod my_hooks {
+    pub fn before(method: &'static str) {
+        super::CALLS.with(|calls| calls.borrow_mut().push(method));
+    }
+
+    pub fn after(_: &'static str) {
+    }
+}
+
+pub struct Wrapper(pub u32);
+
+impl ::core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ::core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<u32> for Wrapper {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> u32 {
+        wrapper.0
+    }
+}
+
+impl ::core::convert::AsRef<u32> for Wrapper {
+    fn as_ref(&self) -> &u32 {
+        &self.0
+    }
+}
+
+pub struct Named {
+    value: u32,
+}
+
+impl From<u32> for Named {
+    fn from(value: u32) -> Self {
+        Self {
+            value,
+        }
+    }
+}
+
+pub struct Delegated(u32);
+
+impl From<u32> for Delegated {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl ::auto_trait_delegate::Delegate for Delegated {
+    type Target = u32;
+
+    fn delegate(&self) -> &u32 {
+        &self.0
+    }
+
+    fn delegate_mut(&mut self) -> &mut u32 {
+        &mut self.0
+    }
+
+    fn delegate_owned(self) -> u32 {
+        self.0
+    }
+}
+
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+fn make_wrapper() -> (Wrapper, u32) {
+    (Wrapper(1), 1)
+}
+
+#[auto_trait(Wrapper => u32, inherent, doc_note, test(with = make_wrapper))]
+#[auto_trait(Box<T: Lolka>)]
+#[auto_trait(Named => u32, field = value, shared)]
+#[auto_trait(Delegated => u32, via = Delegate)]
+#[auto_trait(NullBackend, stub)]
+pub trait Lolka {
+    const ID: u32 = 0;
+    fn lolka(&self) -> u32;
+    fn increment(&mut self);
+    fn into_lolka(self) -> u32 where Self: ::core::marker::Sized;
+    fn initial() -> Self where Self: ::core::marker::Sized;
+    fn maybe() -> ::core::option::Option<Self> where Self: ::core::marker::Sized;
+    fn parse(value: u32) -> ::core::result::Result<Self, u32> where Self: ::core::marker::Sized;
+    fn added(&self, (left, right): (u32, u32)) -> u32 {
+        left + right
+    }
+}
+
+pub struct NullBackend;
+
+impl Lolka for u32 {
+    const ID: u32 = 1;
+
+    fn lolka(&self) -> u32 {
+        *self
+    }
+
+    fn increment(&mut self) {
+        *self += 1;
+    }
+
+    fn into_lolka(self) -> u32 {
+        self
+    }
+
+    fn initial() -> Self {
+        1
+    }
+
+    fn maybe() -> ::core::option::Option<Self> {
+        ::core::option::Option::Some(2)
+    }
+
+    fn parse(value: u32) -> ::core::result::Result<Self, u32> {
+        Ok(value)
+    }
+
+    fn added(&self, (left, right): (u32, u32)) -> u32 {
+        *self + left + right
+    }
+}
+
+#[auto_trait(Mutex<T: Counter>, RefCell<T: Counter>, lock_mut, try_trait(TryCounter), panic_msg = "{target} cannot {method}")]
+#[auto_trait(Weak<T: Counter>, upgrade = default, no_mut)]
+#[auto_trait(Rc<T: Counter>, Arc<T: Counter>, &, Box<dyn>, no_mut)]
+#[auto_trait(&mut)]
+#[auto_trait(cache = Cached)]
+#[auto_trait(newtype = Logged, hooks = my_hooks)]
+#[auto_trait(enum = Any, Wrapper => u32, Box<u32>; variant = Boxed)]
+pub trait Counter {
+    #[auto_trait(cache)]
+    fn get(&self) -> u32;
+    fn reset(&mut self) {
+    }
+}
+
+impl Counter for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+
+    fn reset(&mut self) {
+        *self = 0;
+    }
+}
+
+#[auto_trait((A, B), &dyn)]
+#[auto_trait(Either<L: Observer, R: Observer>, dispatch = match)]
+pub trait Observer {
+    fn notify(&self, value: u32);
+}
+
+impl Observer for Cell<u32> {
+    fn notify(&self, value: u32) {
+        self.set(value);
+    }
+}
+
+fn double(value: u32) -> u32 {
+    value * 2
+}
+
+#[auto_trait(Wrapper, test_target = u32, also_ref)]
+#[auto_trait(Box<T: Scaled>, map_return = double)]
+pub trait Scaled {
+    fn scaled(&self) -> u32;
+}
+
+impl Scaled for u32 {
+    fn scaled(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[auto_trait(registered)]
+pub trait Registered {
+    fn registered(&self) -> u32;
+}
+
+impl Registered for u32 {
+    fn registered(&self) -> u32 {
+        *self
+    }
+}
+
+#[::auto_trait::auto_trait_register(self::Registered)]
+pub struct Entry(u32);
+
+impl ::core::ops::Deref for Entry {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(::auto_trait::AutoWrapper)]
+#[auto_wrapper(forwarder = forward_to_seconds)]
+pub struct Seconds(u32);
+
+forward_to_seconds!(impl self::Scaled {
+    fn scaled(&self) -> u32;
+});
+
+#[auto_trait(fn)]
+pub trait Callback {
+    fn call(&self, value: u32) -> u32;
+}
+
+#[auto_trait(export)]
+pub trait Exported {
+    fn exported(&self) -> u32;
+}
+
+impl Exported for u32 {
+    fn exported(&self) -> u32 {
+        *self
+    }
+}
+
+#[derive(AutoTrait)]
+#[auto_trait(self::Exported)]
+pub struct Derived(pub u32);
+
+impl ::core::ops::Deref for Derived {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+::auto_trait::auto_wrap!(pub struct Meters(pub u32); impl self::Exported);
+
+pub struct Bytes(Vec<u8>);
+
+impl ::core::ops::Deref for Bytes {
+    type Target = Vec<u8>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ::core::ops::DerefMut for Bytes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+mod codec {
+    pub trait Codec {
+        fn encode(&self) -> ::std::vec::Vec<u8>;
+    }
+
+    impl Codec for ::std::vec::Vec<u8> {
+        fn encode(&self) -> ::std::vec::Vec<u8> {
+            ::core::clone::Clone::clone(self)
+        }
+    }
+}
+
+auto_trait_impl!(impl codec::Codec for Bytes {
+    fn encode(&self) -> ::std::vec::Vec<u8>;
+});
+
+auto_trait_std!(::std::io::Write for Bytes);
+
+#[::core::prelude::v1::test]
+fn should_forward_without_prelude() {
+    let mut wrapper = Wrapper(1);
+    wrapper.increment();
+    assert_eq!(wrapper.lolka(), 2);
+    assert_eq!(Wrapper::added(&wrapper, (1, 2)), 5);
+    assert_eq!(wrapper.into_lolka(), 2);
+    assert_eq!(<Wrapper as Lolka>::initial().0, 1);
+    assert_eq!(<Wrapper as Lolka>::maybe().map(|wrapper| wrapper.0), ::core::option::Option::Some(2));
+    assert_eq!(<Wrapper as Lolka>::parse(3).map(|wrapper| wrapper.0), Ok(3));
+    assert_eq!(<Wrapper as Lolka>::ID, 1);
+
+    let mut boxed = Box::new(3u32);
+    boxed.increment();
+    assert_eq!(boxed.lolka(), 4);
+    assert_eq!(Named { value: 7 }.lolka(), 7);
+    assert_eq!(Delegated(8).into_lolka(), 8);
+
+    let logged = Logged(10u32);
+    assert_eq!(Counter::get(&logged), 10);
+    assert_eq!(CALLS.with(|calls| calls.take()), ["get"]);
+
+    let mut cell = RefCell::new(11u32);
+    Counter::reset(&mut cell);
+    assert_eq!(Counter::get(&cell), 0);
+    assert_eq!(TryCounter::get(&cell), Ok(0));
+    assert_eq!(TryCounter::get(&Mutex::new(12u32)), Ok(12));
+    let weak: Weak<u32> = Rc::downgrade(&Rc::new(13u32));
+    assert_eq!(Counter::get(&weak), 0);
+    let boxed: Box<dyn Counter> = Box::new(14u32);
+    assert_eq!(boxed.get(), 14);
+    assert_eq!(Counter::get(&Rc::new(5u32)), 5);
+    assert_eq!(Counter::get(&Arc::new(6u32)), 6);
+    let mut value = 9u32;
+    Counter::reset(&mut &mut value);
+    assert_eq!(Counter::get(&&value), 0);
+    assert_eq!(Cached::new(15u32).get(), 15);
+    assert_eq!(Any::from(Box::new(16u32)).get(), 16);
+
+    let pair = (Cell::new(0), Cell::new(0));
+    pair.notify(17);
+    assert_eq!((pair.0.get(), pair.1.get()), (17, 17));
+    let observer: &dyn Observer = &pair.0;
+    Observer::notify(&observer, 18);
+    Observer::notify(&Either::<&dyn Observer, Cell<u32>>::Left(observer), 19);
+    assert_eq!(pair.0.get(), 19);
+    assert_eq!(Callback::call(&|value| value + 1, 18), 19);
+    assert_eq!(Derived(20).exported(), 20);
+    assert_eq!(Meters(21).exported(), 21);
+
+    assert_eq!(Wrapper(24).scaled(), 25);
+    assert_eq!(Scaled::scaled(&&Wrapper(25)), 26);
+    assert_eq!(Box::new(26u32).scaled(), 54);
+    assert_eq!(Entry(27).registered(), 27);
+    assert_eq!(Seconds(28).scaled(), 29);
+
+    let mut bytes = Bytes(vec![22]);
+    ::std::io::Write::write_all(&mut bytes, &[23]).expect("to write");
+    assert_eq!(codec::Codec::encode(&bytes), [22, 23]);
+}
@@ -0,0 +1,22 @@
+//!Runs tests of fixture crate `tests/no_std`, which forwards traits within `#![no_std]` crate
+//!
+//!Fixture is workspace of its own, so that `std` feature of the macro, enabled by default for this crate, is not enabled for it by feature unification.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn should_forward_within_no_std_crate() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    //Dependencies are the same as of this crate, hence they are already available without network
+    let status = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .args(["test", "--quiet", "--offline", "--manifest-path"])
+        .arg(root.join("tests").join("no_std").join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(root.join("target").join("no-std"))
+        .current_dir(root)
+        .env_remove("CARGO_ENCODED_RUSTFLAGS")
+        .status()
+        .expect("to run cargo");
+    assert!(status.success(), "Tests of `no_std` fixture failed");
+}
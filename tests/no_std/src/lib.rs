@@ -0,0 +1,113 @@
+//!Traits forwarded within `no_std` crate, which has no `std` to refer to
+
+#![no_std]
+
+extern crate alloc;
+
+use auto_trait::auto_trait;
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+pub struct Wrapper(pub u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.0
+    }
+}
+
+impl From<u32> for Wrapper {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+pub struct Named {
+    pub value: u32,
+}
+
+impl From<u32> for Named {
+    fn from(value: u32) -> Self {
+        Self {
+            value,
+        }
+    }
+}
+
+#[auto_trait(Wrapper => u32, Box)]
+#[auto_trait(Named => u32, field = value, shared)]
+#[auto_trait(newtype = Plain)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+    fn increment(&mut self);
+    fn into_lolka(self) -> u32 where Self: Sized;
+    fn initial() -> Self where Self: Sized;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+
+    fn increment(&mut self) {
+        *self += 1;
+    }
+
+    fn into_lolka(self) -> u32 {
+        self
+    }
+
+    fn initial() -> Self {
+        1
+    }
+}
+
+#[auto_trait(Rc, alloc::sync::Arc<T: Counter>, Box<dyn>, &dyn)]
+#[auto_trait(RefCell<T: Counter>, try_trait(TryCounter))]
+pub trait Counter {
+    fn get(&self) -> u32;
+}
+
+impl Counter for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+}
+
+//Built-in targets are spelled by their paths within `alloc`, as they are within crate without `std`
+#[auto_trait(alloc::boxed::Box, ::alloc::sync::Arc)]
+#[auto_trait(alloc::rc::Weak<T: Scored>, ::alloc::rc::Rc<::core::cell::RefCell<T: Scored>>, upgrade = default)]
+pub trait Scored {
+    fn score(&self) -> u32;
+}
+
+impl Scored for u32 {
+    fn score(&self) -> u32 {
+        *self
+    }
+}
+
+///Returns counter as trait object, which is forwarded to by `Box<dyn>` target
+pub fn boxed(value: u32) -> Box<dyn Counter> {
+    Box::new(value)
+}
+
+///Returns shared counter, which is borrowed for every forwarded call
+pub fn shared(value: u32) -> Rc<RefCell<u32>> {
+    Rc::new(RefCell::new(value))
+}
@@ -0,0 +1,33 @@
+use auto_trait_no_std::{boxed, shared, Counter, Lolka, Named, Plain, Scored, TryCounter, Wrapper};
+
+#[test]
+fn should_forward_within_no_std_crate() {
+    let mut wrapper = Wrapper(1);
+    wrapper.increment();
+    assert_eq!(wrapper.lolka(), 2);
+    assert_eq!(wrapper.into_lolka(), 2);
+    assert_eq!(<Wrapper as Lolka>::initial().0, 1);
+
+    let mut boxed_value = Box::new(3u32);
+    boxed_value.increment();
+    assert_eq!(boxed_value.into_lolka(), 4);
+
+    let mut named = Named {
+        value: 5,
+    };
+    named.increment();
+    assert_eq!(named.lolka(), 6);
+    assert_eq!(Plain(7u32).into_lolka(), 7);
+
+    assert_eq!(boxed(8).get(), 8);
+    let counter = shared(9);
+    assert_eq!(Counter::get(&counter), 9);
+    assert_eq!(TryCounter::get(&*counter), Ok(9));
+    assert_eq!(std::sync::Arc::new(10u32).get(), 10);
+
+    assert_eq!(Box::new(11u32).score(), 11);
+    assert_eq!(std::sync::Arc::new(12u32).score(), 12);
+    let strong = std::rc::Rc::new(13u32);
+    assert_eq!(std::rc::Rc::downgrade(&strong).score(), 13);
+    assert_eq!(Scored::score(&std::rc::Rc::new(core::cell::RefCell::new(14u32))), 14);
+}
@@ -0,0 +1,27 @@
+use quote::quote;
+
+fn input() -> proc_macro2::TokenStream {
+    quote! {
+        pub trait Lolka {
+            fn lolka(&self) -> u32;
+        }
+    }
+}
+
+#[test]
+fn should_refer_to_alloc() {
+    let output = auto_trait_expand::expand(quote!(Box, Rc, Arc), input()).expect("to expand").to_string();
+    assert!(output.contains("for :: alloc :: boxed :: Box < __AutoTraitInner >"), "{}", output);
+    assert!(output.contains("for :: alloc :: rc :: Rc < __AutoTraitInner >"), "{}", output);
+    assert!(output.contains("for :: alloc :: sync :: Arc < __AutoTraitInner >"), "{}", output);
+    assert!(!output.contains(":: std"), "{}", output);
+}
+
+#[test]
+fn should_require_std_for_mutex_and_cache() {
+    let error = auto_trait_expand::expand(quote!(Mutex<T: Lolka>), input()).expect_err("to require std").to_string();
+    assert!(error.starts_with("[auto_trait::std_feature_required] Forwarding through Mutex"), "{}", error);
+
+    let error = auto_trait_expand::expand(quote!(cache = Cached), input()).expect_err("to require std").to_string();
+    assert!(error.starts_with("[auto_trait::std_feature_required] `cache` option"), "{}", error);
+}
@@ -0,0 +1,92 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper<T>(T);
+
+impl<T> core::ops::Deref for Wrapper<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<Wrapper<T>> for Option<T> {
+    fn from(wrapper: Wrapper<T>) -> Self {
+        Some(wrapper.0)
+    }
+}
+
+impl From<Wrapper<u32>> for u32 {
+    fn from(wrapper: Wrapper<u32>) -> Self {
+        wrapper.0
+    }
+}
+
+impl From<Wrapper<u32>> for String {
+    fn from(wrapper: Wrapper<u32>) -> Self {
+        wrapper.0.to_string()
+    }
+}
+
+pub struct Concrete(u32);
+
+impl core::ops::Deref for Concrete {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Concrete> for u32 {
+    fn from(concrete: Concrete) -> Self {
+        concrete.0
+    }
+}
+
+impl From<Concrete> for String {
+    fn from(concrete: Concrete) -> Self {
+        concrete.0.to_string()
+    }
+}
+
+#[auto_trait(Wrapper<T: Lolka>)]
+#[auto_trait(self::Concrete => u32)]
+pub trait Lolka {
+    fn lolka_self(self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_self(self) -> u32 {
+        self
+    }
+}
+
+#[auto_trait(self::Concrete)]
+pub trait Kek {
+    fn kek_ref(&self) -> u32;
+
+    fn kek_self(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+impl Kek for u32 {
+    fn kek_ref(&self) -> u32 {
+        *self
+    }
+
+    fn kek_self(self) -> u32 {
+        self
+    }
+}
+
+#[test]
+fn should_convert_into_bound_parameter() {
+    assert_eq!(Wrapper(1u32).lolka_self(), 1);
+    assert_eq!(Lolka::lolka_self(Concrete(20)), 20);
+}
+
+#[test]
+fn should_leave_default_for_concrete_target() {
+    assert_eq!(Concrete(2).kek_ref(), 2);
+    assert_eq!(Concrete(2).kek_self(), 0);
+}
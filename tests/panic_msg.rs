@@ -0,0 +1,57 @@
+use auto_trait::auto_trait;
+
+use core::cell::RefCell;
+use std::sync::{Arc, Weak};
+
+pub struct NullBackend;
+
+#[auto_trait(NullBackend, stub, panic_msg = "{target} has no {trait}::{method}")]
+#[auto_trait(Weak<T: Lolka>, panic_msg = "{trait}::{method} called on dropped {target}")]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+}
+
+#[auto_trait(RefCell<T: LolkaCell>, panic_msg = "{trait}::{method} is called while borrowed")]
+pub trait LolkaCell {
+    fn lolka_cell(&self) -> u32;
+}
+
+impl LolkaCell for u32 {
+    fn lolka_cell(&self) -> u32 {
+        *self
+    }
+}
+
+#[test]
+#[should_panic(expected = "NullBackend has no Lolka::lolka_ref")]
+fn should_format_stub_panic_message() {
+    NullBackend.lolka_ref();
+}
+
+#[test]
+#[should_panic(expected = "Lolka::lolka_ref called on dropped Weak<T>")]
+fn should_format_weak_panic_message() {
+    let weak = Arc::downgrade(&Arc::new(1u32));
+    weak.lolka_ref();
+}
+
+#[test]
+#[should_panic(expected = "LolkaCell::lolka_cell is called while borrowed")]
+fn should_format_ref_cell_panic_message() {
+    let cell = RefCell::new(1u32);
+    let _guard = cell.borrow_mut();
+    cell.lolka_cell();
+}
+
+#[test]
+fn should_not_panic_with_panic_message() {
+    let strong = Arc::new(1u32);
+    assert_eq!(Arc::downgrade(&strong).lolka_ref(), 1);
+    assert_eq!(RefCell::new(2u32).lolka_cell(), 2);
+}
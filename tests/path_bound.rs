@@ -0,0 +1,33 @@
+mod traits {
+    use auto_trait::auto_trait;
+
+    pub struct Wrapper<T>(pub T);
+
+    impl<T> core::ops::Deref for Wrapper<T> {
+        type Target = T;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    #[auto_trait(Box<T: crate::traits::Lolka>, std::rc::Rc<T: self::Lolka>)]
+    #[auto_trait(Wrapper<T> where T: super::traits::Lolka)]
+    pub trait Lolka {
+        fn lolka(&self) -> u32;
+    }
+
+    impl Lolka for u32 {
+        fn lolka(&self) -> u32 {
+            *self + 1
+        }
+    }
+}
+
+use traits::{Lolka, Wrapper};
+
+#[test]
+fn should_detect_path_qualified_bounds() {
+    assert_eq!(Box::new(1u32).lolka(), 2);
+    assert_eq!(std::rc::Rc::new(2u32).lolka(), 3);
+    assert_eq!(Wrapper(3u32).lolka(), 4);
+}
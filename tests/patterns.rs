@@ -0,0 +1,62 @@
+use auto_trait::auto_trait;
+
+pub struct Pair {
+    pub left: u32,
+    pub right: u32,
+}
+
+#[auto_trait(Box<T: Lolka>)]
+pub trait Lolka {
+    fn lolka_mut(&self, mut value: u32) -> u32 {
+        value += 1;
+        value
+    }
+
+    fn lolka_tuple(&self, (left, right): (u32, u32)) -> u32 {
+        left + right
+    }
+
+    fn lolka_wildcard(&self, _: u32) -> u32;
+
+    #[allow(clippy::toplevel_ref_arg)]
+    fn lolka_ref(&self, ref value: u32) -> u32 {
+        *value
+    }
+
+    fn lolka_struct(&self, Pair { left, right }: Pair) -> u32 {
+        left + right
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka_mut(&self, mut value: u32) -> u32 {
+        value *= *self;
+        value
+    }
+
+    fn lolka_tuple(&self, (left, right): (u32, u32)) -> u32 {
+        *self + left * right
+    }
+
+    fn lolka_wildcard(&self, _: u32) -> u32 {
+        *self
+    }
+
+    fn lolka_ref(&self, value: u32) -> u32 {
+        *self + value
+    }
+
+    fn lolka_struct(&self, Pair { left, right }: Pair) -> u32 {
+        *self + left - right
+    }
+}
+
+#[test]
+fn should_forward_arguments_with_patterns() {
+    let boxed = Box::new(2u32);
+    assert_eq!(boxed.lolka_mut(3), 6);
+    assert_eq!(boxed.lolka_tuple((2, 3)), 8);
+    assert_eq!(boxed.lolka_wildcard(10), 2);
+    assert_eq!(boxed.lolka_ref(4), 6);
+    assert_eq!(boxed.lolka_struct(Pair { left: 5, right: 1 }), 6);
+}
@@ -0,0 +1,81 @@
+use auto_trait::auto_trait;
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+#[auto_trait(Timed<T: Ticker>, pin_project(inner))]
+#[auto_trait(Named => Counter, pin_project(counter))]
+pub trait Ticker {
+    fn tick(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32>;
+    fn peek(self: Pin<&Self>) -> u32;
+}
+
+pub struct Counter {
+    count: u32,
+    //Makes counter `!Unpin`, so that it can be projected only by pin-project
+    _pinned: core::marker::PhantomPinned,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            _pinned: core::marker::PhantomPinned,
+        }
+    }
+}
+
+impl Ticker for Counter {
+    fn tick(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        //Safe, as `count` is not structurally pinned
+        let this = unsafe { self.get_unchecked_mut() };
+        this.count += 1;
+        match this.count {
+            count if count < 2 => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            },
+            count => Poll::Ready(count),
+        }
+    }
+
+    fn peek(self: Pin<&Self>) -> u32 {
+        self.count
+    }
+}
+
+#[pin_project::pin_project]
+pub struct Timed<T> {
+    #[pin]
+    inner: T,
+    started: u64,
+}
+
+#[pin_project::pin_project]
+pub struct Named {
+    name: &'static str,
+    #[pin]
+    counter: Counter,
+}
+
+#[test]
+fn should_forward_through_projection() {
+    let mut cx = Context::from_waker(core::task::Waker::noop());
+
+    let mut timed = Box::pin(Timed {
+        inner: Counter::new(),
+        started: 0,
+    });
+    assert_eq!(timed.as_mut().tick(&mut cx), Poll::Pending);
+    assert_eq!(timed.as_mut().tick(&mut cx), Poll::Ready(2));
+    assert_eq!(timed.as_ref().peek(), 2);
+    assert_eq!(timed.started, 0);
+
+    let mut named = Box::pin(Named {
+        name: "lolka",
+        counter: Counter::new(),
+    });
+    assert_eq!(named.as_mut().tick(&mut cx), Poll::Pending);
+    assert_eq!(named.as_ref().peek(), 1);
+    assert_eq!(named.name, "lolka");
+}
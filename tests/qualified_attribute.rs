@@ -0,0 +1,62 @@
+#[auto_trait::auto_trait(Box<T: Lolka>)]
+#[auto_trait::auto_trait(std::rc::Rc<T: Lolka>)]
+#[cfg_attr(all(), auto_trait::auto_trait(std::sync::Arc<T: Lolka>))]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+
+    #[auto_trait::auto_trait(skip)]
+    fn lolka_skipped(&self) -> u32 {
+        0
+    }
+}
+
+#[auto_trait::auto_trait_default(inline)]
+#[auto_trait::auto_trait(Box<T: Kek>)]
+pub trait Kek {
+    fn kek(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_skipped(&self) -> u32 {
+        *self
+    }
+}
+
+impl Kek for u32 {
+    fn kek(&self) -> u32 {
+        *self
+    }
+}
+
+#[auto_trait::auto_trait_all(Box<T: Self>)]
+mod traits {
+    #[::auto_trait::auto_trait(std::rc::Rc<T: Cheburek>)]
+    pub trait Cheburek {
+        fn cheburek(&self) -> u32;
+    }
+
+    impl Cheburek for u32 {
+        fn cheburek(&self) -> u32 {
+            *self
+        }
+    }
+}
+
+use traits::Cheburek;
+
+#[test]
+fn should_recognize_qualified_attributes() {
+    assert_eq!(Box::new(1u32).lolka(), 1);
+    assert_eq!(std::rc::Rc::new(2u32).lolka(), 2);
+    assert_eq!(std::sync::Arc::new(3u32).lolka(), 3);
+    assert_eq!(Box::new(4u32).lolka_skipped(), 0);
+
+    assert_eq!(Box::new(5u32).kek(), 5);
+
+    assert_eq!(Box::new(6u32).cheburek(), 6);
+    assert_eq!(std::rc::Rc::new(7u32).cheburek(), 7);
+}
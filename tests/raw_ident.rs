@@ -0,0 +1,98 @@
+use auto_trait::{auto_trait, auto_trait_all};
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait(Wrapper; inherent, Box<T: Lolka>, &mut, std::sync::Mutex<T: Lolka>; lock_mut)]
+pub trait Lolka {
+    fn r#type(&self, r#ref: u32) -> u32;
+
+    fn r#match(&mut self, r#in: u32) -> u32;
+}
+
+impl Lolka for u32 {
+    fn r#type(&self, r#ref: u32) -> u32 {
+        *self + r#ref
+    }
+
+    fn r#match(&mut self, r#in: u32) -> u32 {
+        *self += r#in;
+        *self
+    }
+}
+
+#[test]
+fn should_forward_methods_with_raw_identifiers() {
+    let mut wrapper = Wrapper(1);
+    assert_eq!(wrapper.r#type(1), 2);
+    assert_eq!(wrapper.r#match(2), 3);
+
+    let mut boxed = Box::new(2u32);
+    assert_eq!(Lolka::r#type(&boxed, 1), 3);
+    assert_eq!(Lolka::r#match(&mut boxed, 1), 3);
+
+    let mut value = 4u32;
+    assert_eq!(Lolka::r#match(&mut &mut value, 1), 5);
+
+    let mut mutex = std::sync::Mutex::new(5u32);
+    assert_eq!(Lolka::r#type(&mutex, 1), 6);
+    assert_eq!(Lolka::r#match(&mut mutex, 1), 6);
+}
+
+#[auto_trait_all(Box<T: Self>)]
+mod traits {
+    pub trait r#Kek {
+        fn r#kek(&self) -> u32;
+    }
+
+    impl r#Kek for u32 {
+        fn r#kek(&self) -> u32 {
+            *self
+        }
+    }
+}
+
+#[test]
+fn should_forward_trait_with_raw_identifier() {
+    use traits::Kek;
+
+    assert_eq!(Box::new(1u32).kek(), 1);
+}
+
+#[allow(non_camel_case_types)]
+#[auto_trait(Box<T: r#async>, module = raw_impls, Wrapper => u32; inherent, test(with = make_wrapper))]
+pub trait r#async {
+    const LIMIT: u32;
+
+    fn poll_it(&self) -> u32;
+}
+
+impl r#async for u32 {
+    const LIMIT: u32 = 5;
+
+    fn poll_it(&self) -> u32 {
+        *self
+    }
+}
+
+fn make_wrapper() -> (Wrapper, u32) {
+    (Wrapper(3), 3)
+}
+
+#[test]
+fn should_forward_raw_trait_name() {
+    assert_eq!(r#async::poll_it(&Box::new(1u32)), 1);
+    assert_eq!(<Box<u32> as r#async>::LIMIT, 5);
+}
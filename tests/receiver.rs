@@ -0,0 +1,63 @@
+#![deny(unused_mut)]
+
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.0
+    }
+}
+
+#[auto_trait(self::Wrapper => u32; inherent, Box)]
+pub trait Lolka {
+    fn lolka_self(mut self, value: u32) -> u32 where Self: Sized {
+        self.lolka_mut(value)
+    }
+
+    fn lolka_default(mut self, mut value: u32) -> u32 where Self: Sized {
+        value += self.lolka_ref();
+        self.lolka_mut(value)
+    }
+
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self, value: u32) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_self(mut self, value: u32) -> u32 {
+        self += value * 2;
+        self
+    }
+
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self, value: u32) -> u32 {
+        *self += value;
+        *self
+    }
+}
+
+#[test]
+fn should_forward_mutable_receiver() {
+    assert_eq!(Wrapper(1).lolka_self(1), 3);
+    assert_eq!(Lolka::lolka_self(Box::new(3u32), 1), 5);
+    assert_eq!(Lolka::lolka_default(Box::new(3u32), 1), 7);
+}
@@ -0,0 +1,41 @@
+use auto_trait::auto_trait;
+
+use core::cell::RefCell;
+use std::rc::Rc;
+
+#[auto_trait(RefCell<T: Lolka>)]
+#[auto_trait(Rc<RefCell<T: Lolka>>)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+    fn lolka_mut(&mut self, add: u32) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self, add: u32) -> u32 {
+        *self += add;
+        *self
+    }
+}
+
+#[test]
+fn should_forward_through_shared_ref_cell() {
+    let mut first = Rc::new(RefCell::new(1u32));
+    let mut second = first.clone();
+
+    assert_eq!(first.lolka_mut(1), 2);
+    assert_eq!(second.lolka_ref(), 2);
+    assert_eq!(second.lolka_mut(2), 4);
+    assert_eq!(first.lolka_ref(), 4);
+    assert_eq!(*first.borrow(), 4);
+}
+
+#[test]
+fn should_forward_through_ref_cell() {
+    let mut cell = RefCell::new(1u32);
+    assert_eq!(cell.lolka_mut(1), 2);
+    assert_eq!(cell.lolka_ref(), 2);
+}
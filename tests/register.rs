@@ -0,0 +1,92 @@
+mod traits {
+    use auto_trait::auto_trait;
+
+    #[auto_trait(registered)]
+    pub trait Lolka {
+        fn lolka_ref(&self) -> u32;
+
+        fn lolka_mut(&mut self) -> u32;
+    }
+
+    impl Lolka for u32 {
+        fn lolka_ref(&self) -> u32 {
+            *self
+        }
+
+        fn lolka_mut(&mut self) -> u32 {
+            *self += 1;
+            *self
+        }
+    }
+
+    //Registered trait can still list its own targets
+    #[auto_trait(Box<T: Named>, registered)]
+    pub trait Named {
+        fn name(&self) -> String;
+    }
+
+    impl Named for u32 {
+        fn name(&self) -> String {
+            self.to_string()
+        }
+    }
+}
+
+use auto_trait::auto_trait_register;
+use traits::{Lolka, Named};
+
+#[auto_trait_register(traits::Lolka, traits::Named)]
+#[derive(Debug)]
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait_register(traits::Lolka)]
+pub struct Generic<T: traits::Lolka>(T);
+
+impl<T: traits::Lolka> core::ops::Deref for Generic<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: traits::Lolka> core::ops::DerefMut for Generic<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait_register(traits::Lolka)]
+pub enum Backend {
+    Plain(u32),
+    Wrapped(Wrapper),
+}
+
+#[test]
+fn should_implement_registered_traits() {
+    let mut wrapper = Wrapper(1);
+    assert_eq!(wrapper.lolka_ref(), 1);
+    assert_eq!(wrapper.lolka_mut(), 2);
+    assert_eq!(wrapper.name(), "2");
+    assert_eq!(Box::new(3u32).name(), "3");
+    //Type keeps its other attributes
+    assert_eq!(format!("{:?}", wrapper), "Wrapper(2)");
+
+    let mut generic = Generic(Wrapper(4));
+    assert_eq!(generic.lolka_mut(), 5);
+
+    let mut backends = [Backend::Plain(6), Backend::Wrapped(Wrapper(7))];
+    assert_eq!(backends.iter_mut().map(Lolka::lolka_mut).collect::<Vec<_>>(), [7, 8]);
+}
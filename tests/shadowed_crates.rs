@@ -0,0 +1,315 @@
+//!Generated code refers to standard crates by absolute paths, hence it compiles next to modules shadowing them
+
+#![allow(dead_code)]
+
+use auto_trait::auto_trait;
+
+//Shadow standard crates for any path without leading `::`
+mod core {
+}
+
+mod std {
+}
+
+mod alloc {
+}
+
+pub struct Wrapper(pub u32);
+
+impl ::core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ::core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<u32> for Wrapper {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(wrapper: Wrapper) -> u32 {
+        wrapper.0
+    }
+}
+
+pub struct Named {
+    value: u32,
+}
+
+impl From<u32> for Named {
+    fn from(value: u32) -> Self {
+        Self {
+            value,
+        }
+    }
+}
+
+#[auto_trait(Wrapper => u32, Box<T: Lolka>)]
+#[auto_trait(Named => u32, field = value)]
+#[auto_trait(newtype = Plain)]
+#[auto_trait(NullBackend, stub)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+    fn increment(&mut self) {
+    }
+    fn into_lolka(self) -> u32 where Self: Sized {
+        0
+    }
+    fn initial() -> Self where Self: Sized;
+    fn maybe() -> ::core::option::Option<Self> where Self: Sized;
+}
+
+pub struct NullBackend;
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+
+    fn increment(&mut self) {
+        *self += 1;
+    }
+
+    fn into_lolka(self) -> u32 {
+        self
+    }
+
+    fn initial() -> Self {
+        1
+    }
+
+    fn maybe() -> ::core::option::Option<Self> {
+        ::core::option::Option::Some(2)
+    }
+}
+
+#[auto_trait(::std::sync::Mutex<T: Counter>, ::std::cell::RefCell<T: Counter>, lock_mut, try_trait(TryCounter))]
+#[auto_trait(::std::rc::Weak<T: Counter>, upgrade = default, no_mut)]
+#[auto_trait(::std::rc::Rc<T: Counter>, ::std::sync::Arc<T: Counter>, &, no_mut)]
+#[auto_trait(cache = Cached)]
+#[auto_trait(enum = Any, Wrapper => u32, Box<u32>; variant = Boxed)]
+#[auto_trait(Named => u32, field = value, shared)]
+pub trait Counter {
+    const ID: u32 = 0;
+    #[auto_trait(cache)]
+    fn get(&self) -> u32;
+    fn reset(&mut self) {
+    }
+}
+
+impl Counter for u32 {
+    const ID: u32 = 1;
+
+    fn get(&self) -> u32 {
+        *self
+    }
+
+    fn reset(&mut self) {
+        *self = 0;
+    }
+}
+
+#[auto_trait((A, B))]
+pub trait Observer {
+    fn notify(&self, value: u32);
+}
+
+impl Observer for ::std::cell::Cell<u32> {
+    fn notify(&self, value: u32) {
+        self.set(value);
+    }
+}
+
+#[test]
+fn should_forward_next_to_shadowing_modules() {
+    let mut wrapper = Wrapper(1);
+    wrapper.increment();
+    assert_eq!(wrapper.lolka(), 2);
+    assert_eq!(wrapper.into_lolka(), 2);
+    assert_eq!(<Wrapper as Lolka>::initial().0, 1);
+    assert_eq!(<Wrapper as Lolka>::maybe().map(|wrapper| wrapper.0), Some(2));
+    assert_eq!(Named { value: 4 }.lolka(), 4);
+    assert_eq!(Plain(5u32).lolka(), 5);
+
+    let mut cell = ::std::cell::RefCell::new(3u32);
+    Counter::reset(&mut cell);
+    assert_eq!(Counter::get(&cell), 0);
+    assert_eq!(TryCounter::get(&cell), Ok(0));
+    assert_eq!(::std::rc::Rc::new(6u32).get(), 6);
+    assert_eq!(Any::from(Box::new(7u32)).get(), 7);
+    assert_eq!(Named { value: 8 }.get(), 8);
+    assert_eq!(<Named as Counter>::ID, 1);
+
+    let pair = (::std::cell::Cell::new(0), ::std::cell::Cell::new(0));
+    pair.notify(9);
+    assert_eq!((pair.0.get(), pair.1.get()), (9, 9));
+}
+
+//Module without prelude resolves only absolute paths
+#[no_implicit_prelude]
+mod without_prelude {
+    use ::auto_trait::auto_trait;
+
+    pub struct Wrapper(pub u32);
+
+    impl ::core::ops::Deref for Wrapper {
+        type Target = u32;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl ::core::ops::DerefMut for Wrapper {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    impl ::core::convert::From<u32> for Wrapper {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+
+    impl ::core::convert::From<Wrapper> for u32 {
+        fn from(wrapper: Wrapper) -> u32 {
+            wrapper.0
+        }
+    }
+
+    #[auto_trait(Wrapper => u32, ::std::boxed::Box<T: Kek>)]
+    #[auto_trait(newtype = Plain)]
+    #[auto_trait(NullBackend, stub)]
+    pub trait Kek {
+        fn kek(&self) -> u32;
+        fn increment(&mut self);
+        fn into_lolka(self) -> u32 where Self: ::core::marker::Sized;
+        fn initial() -> Self where Self: ::core::marker::Sized;
+        fn maybe() -> ::core::option::Option<Self> where Self: ::core::marker::Sized;
+    }
+
+    pub struct NullBackend;
+
+    impl Kek for u32 {
+        fn kek(&self) -> u32 {
+            *self
+        }
+
+        fn increment(&mut self) {
+            *self += 1;
+        }
+
+        fn into_lolka(self) -> u32 {
+            self
+        }
+
+        fn initial() -> Self {
+            1
+        }
+
+        fn maybe() -> ::core::option::Option<Self> {
+            ::core::option::Option::Some(2)
+        }
+    }
+
+    #[auto_trait(::std::sync::Mutex<T: Cheburek>, ::std::cell::RefCell<T: Cheburek>, lock_mut, try_trait(TryCounter))]
+    #[auto_trait(::std::rc::Weak<T: Cheburek>, upgrade = default, no_mut)]
+    #[auto_trait(::std::rc::Rc<T: Cheburek>, &, no_mut)]
+    #[auto_trait(cache = Cached)]
+    #[auto_trait(enum = Any, ::std::boxed::Box<u32>; variant = Boxed)]
+    #[auto_trait(Wrapper => u32, shared)]
+    pub trait Cheburek {
+        #[auto_trait(cache)]
+        fn get(&self) -> u32;
+        fn reset(&mut self) {
+        }
+    }
+
+    impl Cheburek for u32 {
+        fn get(&self) -> u32 {
+            *self
+        }
+    }
+
+    pub enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+
+    #[auto_trait((A, B), &, Box<dyn>)]
+    #[auto_trait(Either<L: Observer, R: Observer>, dispatch = match)]
+    pub trait Observer {
+        fn notify(&self, value: u32);
+    }
+
+    impl Observer for ::std::cell::Cell<u32> {
+        fn notify(&self, value: u32) {
+            self.set(value);
+        }
+    }
+
+    #[auto_trait(fn)]
+    pub trait Callback {
+        fn call(&self, value: u32);
+    }
+
+    #[auto_trait(export)]
+    pub trait Exported {
+        fn exported(&self) -> u32;
+    }
+
+    impl Exported for u32 {
+        fn exported(&self) -> u32 {
+            *self
+        }
+    }
+
+    #[derive(::auto_trait::AutoTrait)]
+    #[auto_trait(self::Exported)]
+    pub struct Derived(pub u32);
+
+    impl ::core::ops::Deref for Derived {
+        type Target = u32;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    ::auto_trait::auto_wrap!(pub struct Meters(pub u32); impl self::Exported);
+}
+
+#[test]
+fn should_forward_without_prelude() {
+    use without_prelude::{Cheburek, Kek};
+
+    let mut wrapper = without_prelude::Wrapper(1);
+    wrapper.increment();
+    assert_eq!(wrapper.kek(), 2);
+    assert_eq!(wrapper.get(), 2);
+    assert_eq!(wrapper.into_lolka(), 2);
+    assert_eq!(without_prelude::Plain(3u32).kek(), 3);
+    assert_eq!(without_prelude::Any::from(Box::new(4u32)).get(), 4);
+
+    let cell = ::std::cell::Cell::new(0);
+    let pair = (&cell, &cell);
+    without_prelude::Observer::notify(&pair, 5);
+    assert_eq!(cell.get(), 5);
+    let boxed: Box<dyn without_prelude::Observer> = Box::new(::std::cell::Cell::new(0));
+    without_prelude::Observer::notify(&boxed, 6);
+    without_prelude::Callback::call(&|value| assert_eq!(value, 7), 7);
+    without_prelude::Observer::notify(&without_prelude::Either::<_, ::std::cell::Cell<u32>>::Left(&cell), 8);
+    assert_eq!(cell.get(), 8);
+
+    use without_prelude::Exported;
+    assert_eq!(without_prelude::Derived(9).exported(), 9);
+    assert_eq!(without_prelude::Meters(10).exported(), 10);
+}
@@ -0,0 +1,142 @@
+use auto_trait::auto_trait;
+
+use std::rc::Rc;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<u32> for Wrapper {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Wrapper> for u32 {
+    fn from(value: Wrapper) -> Self {
+        value.0
+    }
+}
+
+pub struct Named {
+    value: u32,
+}
+
+#[auto_trait(Wrapper => u32, Box<T: Lolka>, shared)]
+#[auto_trait(Named => u32, field = value, shared)]
+pub trait Lolka {
+    const ID: u32;
+    fn lolka(&self) -> u32;
+    fn add(&self, value: u32) -> u32;
+    fn name<'a>(&'a self, prefix: &'a str) -> &'a str;
+    fn increment(&mut self);
+    fn into_lolka(self) -> u32 where Self: Sized;
+    fn initial() -> Self where Self: Sized;
+}
+
+impl Lolka for u32 {
+    const ID: u32 = 1;
+
+    fn lolka(&self) -> u32 {
+        *self
+    }
+
+    fn add(&self, value: u32) -> u32 {
+        *self + value
+    }
+
+    fn name<'a>(&'a self, prefix: &'a str) -> &'a str {
+        prefix
+    }
+
+    fn increment(&mut self) {
+        *self += 1;
+    }
+
+    fn into_lolka(self) -> u32 {
+        self
+    }
+
+    fn initial() -> Self {
+        42
+    }
+}
+
+impl From<u32> for Named {
+    fn from(value: u32) -> Self {
+        Self {
+            value,
+        }
+    }
+}
+
+#[auto_trait(Rc<T: Counter>, no_mut, shared)]
+pub trait Counter {
+    fn get(&self) -> u32;
+    fn reset(&mut self) {
+    }
+}
+
+impl Counter for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+}
+
+#[test]
+fn should_forward_through_shared_helper() {
+    let mut wrapper = Wrapper(1);
+    assert_eq!(wrapper.lolka(), 1);
+    assert_eq!(wrapper.add(2), 3);
+    assert_eq!(wrapper.name("lolka"), "lolka");
+    wrapper.increment();
+    assert_eq!(wrapper.into_lolka(), 2);
+    assert_eq!(<Wrapper as Lolka>::initial().0, 42);
+    assert_eq!(<Wrapper as Lolka>::ID, 1);
+
+    let mut boxed = Box::new(3u32);
+    boxed.increment();
+    assert_eq!(boxed.add(1), 5);
+    assert_eq!(boxed.into_lolka(), 4);
+
+    let mut named = Named {
+        value: 5,
+    };
+    named.increment();
+    assert_eq!(named.lolka(), 6);
+    assert_eq!(named.into_lolka(), 6);
+}
+
+#[test]
+fn should_keep_default_implementation() {
+    let mut shared = Rc::new(7u32);
+    shared.reset();
+    assert_eq!(shared.get(), 7);
+}
+
+#[auto_trait(Wrapper => u32, shared, module = impls)]
+pub trait Scoped {
+    fn scoped(&self) -> u32;
+}
+
+impl Scoped for u32 {
+    fn scoped(&self) -> u32 {
+        *self * 2
+    }
+}
+
+#[test]
+fn should_forward_within_module() {
+    assert_eq!(Wrapper(2).scoped(), 4);
+}
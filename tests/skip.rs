@@ -0,0 +1,54 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(Wrapper)]
+#[auto_trait(Box<T: Lolka>)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    #[auto_trait(skip)]
+    fn lolka_skip(&self) -> u32 {
+        0
+    }
+
+    #[auto_trait(skip)]
+    fn lolka_new() -> u32 where Self: Sized {
+        0
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_skip(&self) -> u32 {
+        *self + 1
+    }
+
+    fn lolka_new() -> u32 {
+        1
+    }
+}
+
+#[test]
+fn should_skip_method() {
+    let wrapper = Wrapper(5);
+    assert_eq!(wrapper.lolka_ref(), 5);
+    assert_eq!(wrapper.lolka_skip(), 0);
+    assert_eq!(Wrapper::lolka_new(), 0);
+
+    let boxed = Box::new(5u32);
+    assert_eq!(boxed.lolka_ref(), 5);
+    assert_eq!(boxed.lolka_skip(), 0);
+    assert_eq!(<Box<u32> as Lolka>::lolka_new(), 0);
+    assert_eq!(5u32.lolka_skip(), 6);
+}
@@ -0,0 +1,6 @@
+#![cfg_attr(feature = "specialization", feature(min_specialization))]
+
+//Specialization requires nightly compiler, hence it is within module, which is not loaded otherwise
+#[cfg(feature = "specialization")]
+#[path = "specialization/nightly.rs"]
+mod nightly;
@@ -0,0 +1,81 @@
+use auto_trait::auto_trait;
+
+//Inner type is local, so that blanket implementation cannot overlap with its own implementation
+pub struct Meters(u32);
+
+pub struct Wrapper(Meters);
+
+impl core::ops::Deref for Wrapper {
+    type Target = Meters;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+pub struct Overridden(Meters);
+
+impl core::ops::Deref for Overridden {
+    type Target = Meters;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Overridden {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait(specialization = Meters)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+    fn name(&self) -> &'static str;
+    fn increment(&mut self);
+}
+
+impl Lolka for Meters {
+    fn lolka(&self) -> u32 {
+        self.0
+    }
+
+    fn name(&self) -> &'static str {
+        "meters"
+    }
+
+    fn increment(&mut self) {
+        self.0 += 1;
+    }
+}
+
+//Only one method is specialized, while others are still forwarded by blanket implementation
+impl Lolka for Overridden {
+    fn name(&self) -> &'static str {
+        "overridden"
+    }
+}
+
+#[test]
+fn should_forward_through_blanket_implementation() {
+    let mut wrapper = Wrapper(Meters(1));
+    wrapper.increment();
+    assert_eq!(wrapper.lolka(), 2);
+    assert_eq!(wrapper.name(), "meters");
+
+    let boxed = Box::new(Meters(3));
+    assert_eq!(boxed.lolka(), 3);
+}
+
+#[test]
+fn should_specialize_method() {
+    let mut overridden = Overridden(Meters(4));
+    overridden.increment();
+    assert_eq!(overridden.lolka(), 5);
+    assert_eq!(overridden.name(), "overridden");
+}
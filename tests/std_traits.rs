@@ -0,0 +1,137 @@
+use auto_trait::auto_trait_std;
+
+use core::fmt;
+use std::hash::Hasher;
+use std::io::{self, BufRead, Read, Seek, Write};
+
+pub struct Stream(io::Cursor<Vec<u8>>);
+
+impl core::ops::Deref for Stream {
+    type Target = io::Cursor<Vec<u8>>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Stream {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+pub struct Reader<T>(T);
+
+impl<T> core::ops::Deref for Reader<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> core::ops::DerefMut for Reader<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+auto_trait_std!(std::io::Read, io::Write, Seek for Stream);
+auto_trait_std!(Read, BufRead for Reader<T: Self>);
+
+pub struct Name(String);
+
+impl core::ops::Deref for Name {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+auto_trait_std!(core::fmt::Display, fmt::Debug for Name);
+
+pub struct Counter(core::ops::Range<u32>);
+
+impl core::ops::Deref for Counter {
+    type Target = core::ops::Range<u32>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Counter {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+auto_trait_std!(Iterator for Counter => core::ops::Range<u32>);
+
+pub struct Hashing(std::collections::hash_map::DefaultHasher);
+
+impl core::ops::Deref for Hashing {
+    type Target = std::collections::hash_map::DefaultHasher;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Hashing {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+auto_trait_std!(std::hash::Hasher for Hashing);
+
+#[test]
+fn should_forward_io_traits() {
+    let mut stream = Stream(Default::default());
+    stream.write_all(b"lolka").unwrap();
+    write!(stream, "{}", 2).unwrap();
+    stream.flush().unwrap();
+    assert_eq!(stream.stream_position().unwrap(), 6);
+
+    stream.rewind().unwrap();
+    let mut text = String::new();
+    stream.read_to_string(&mut text).unwrap();
+    assert_eq!(text, "lolka2");
+}
+
+#[test]
+fn should_forward_buffered_read() {
+    let mut reader = Reader(io::Cursor::new(b"lolka\nlolka2\n".to_vec()));
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    assert_eq!(line, "lolka\n");
+    assert_eq!(reader.lines().next().unwrap().unwrap(), "lolka2");
+
+    let mut reader = Reader(io::Cursor::new(b"lolka".to_vec()));
+    let mut buf = [0; 2];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"lo");
+}
+
+#[test]
+fn should_forward_formatting() {
+    let name = Name("lolka".to_owned());
+    assert_eq!(name.to_string(), "lolka");
+    assert_eq!(format!("{:?}", name), "\"lolka\"");
+    assert_eq!(format!("{:>6}", name), " lolka");
+}
+
+#[test]
+fn should_forward_iterator() {
+    let counter = Counter(1..4);
+    assert_eq!(counter.size_hint(), (3, Some(3)));
+    assert_eq!(counter.collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn should_forward_hasher() {
+    let mut hashing = Hashing(Default::default());
+    let mut expected = std::collections::hash_map::DefaultHasher::default();
+    hashing.write_u32(1);
+    expected.write_u32(1);
+    hashing.write(b"lolka");
+    expected.write(b"lolka");
+    assert_eq!(hashing.finish(), expected.finish());
+}
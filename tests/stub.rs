@@ -0,0 +1,52 @@
+use auto_trait::auto_trait;
+
+pub struct NullBackend;
+pub struct QuietBackend;
+
+#[auto_trait(NullBackend, stub)]
+#[auto_trait(QuietBackend, stub = noop)]
+pub trait Lolka {
+    fn lolka() -> u32;
+    fn lolka_ref(&self, value: u32) -> u32;
+    fn lolka_mut(&mut self, value: u32);
+    fn lolka_self(self) -> u32;
+
+    fn lolka_default(&self) -> u32 {
+        0
+    }
+}
+
+#[test]
+#[should_panic(expected = "NullBackend does not support Lolka::lolka_ref")]
+fn should_panic_in_stub_method() {
+    NullBackend.lolka_ref(1);
+}
+
+#[test]
+#[should_panic(expected = "NullBackend does not support Lolka::lolka_mut")]
+fn should_panic_in_stub_unit_method() {
+    NullBackend.lolka_mut(1);
+}
+
+#[test]
+#[should_panic(expected = "NullBackend does not support Lolka::lolka_default")]
+fn should_stub_method_with_default() {
+    NullBackend.lolka_default();
+}
+
+#[test]
+fn should_do_nothing_in_noop_stub_unit_method() {
+    QuietBackend.lolka_mut(1);
+}
+
+#[test]
+#[should_panic(expected = "QuietBackend does not support Lolka::lolka_self")]
+fn should_panic_in_noop_stub_method() {
+    QuietBackend.lolka_self();
+}
+
+#[test]
+#[should_panic(expected = "QuietBackend does not support Lolka::lolka")]
+fn should_panic_in_noop_stub_function() {
+    <QuietBackend as Lolka>::lolka();
+}
@@ -0,0 +1,90 @@
+use auto_trait::auto_trait;
+
+use std::rc::Rc;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[auto_trait(Wrapper; inline, Rc<T: Lolka>; only(lolka_ref), Box<T: Lolka>, no_owned)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32 {
+        0
+    }
+
+    fn lolka_self(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+#[auto_trait(Wrapper => u32; cfg(all()), Box<T: LolkaCfg>; cfg(any()))]
+pub trait LolkaCfg {
+    fn lolka_cfg(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self + 1
+    }
+
+    fn lolka_self(self) -> u32 {
+        self + 2
+    }
+}
+
+impl LolkaCfg for u32 {
+    fn lolka_cfg(&self) -> u32 {
+        *self
+    }
+}
+
+//Box is excluded from forwarding by its `cfg`, hence it can be implemented manually
+impl<T: LolkaCfg> LolkaCfg for Box<T> {
+    fn lolka_cfg(&self) -> u32 {
+        10
+    }
+}
+
+fn lolka_mut(mut lolka: impl Lolka) -> u32 {
+    lolka.lolka_mut()
+}
+
+fn lolka_self(lolka: impl Lolka) -> u32 {
+    lolka.lolka_self()
+}
+
+#[test]
+fn should_apply_options_to_own_target() {
+    assert_eq!(lolka_mut(Wrapper(1)), 2);
+    assert_eq!(lolka_self(Wrapper(1)), 0);
+
+    assert_eq!(lolka_mut(Rc::new(1u32)), 0);
+    assert_eq!(lolka_self(Rc::new(1u32)), 0);
+
+    assert_eq!(lolka_mut(Box::new(1u32)), 2);
+    assert_eq!(lolka_self(Box::new(1u32)), 0);
+}
+
+#[test]
+fn should_gate_targets_separately() {
+    assert_eq!(Wrapper(1).lolka_cfg(), 1);
+    assert_eq!(LolkaCfg::lolka_cfg(&Box::new(1u32)), 10);
+}
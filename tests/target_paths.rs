@@ -0,0 +1,68 @@
+//!Built-in targets are recognized however they are spelled, while user's type of the same name opts out with `no_builtin`
+
+use auto_trait::auto_trait;
+
+use core::cell::Cell;
+
+mod prelude {
+    pub use std::cell::RefCell;
+    pub use std::sync::Mutex;
+}
+
+mod user {
+    use core::cell::Cell;
+
+    ///Counts how many times it is dereferenced, unlike `std::sync::Mutex` it is named after
+    pub struct Mutex<T> {
+        pub value: T,
+        pub derefs: Cell<u32>,
+    }
+
+    impl<T> core::ops::Deref for Mutex<T> {
+        type Target = T;
+        fn deref(&self) -> &Self::Target {
+            self.derefs.set(self.derefs.get() + 1);
+            &self.value
+        }
+    }
+}
+
+#[auto_trait(std::boxed::Box)]
+#[auto_trait(::std::sync::Arc)]
+#[auto_trait(std::rc::Weak<T: Lolka>, upgrade = default)]
+#[auto_trait(prelude::Mutex<T: Lolka>, ::std::rc::Rc<prelude::RefCell<T: Lolka>>)]
+#[auto_trait(user::Mutex<T: Lolka>; no_builtin)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+#[test]
+fn should_forward_builtin_targets_spelled_by_path() {
+    assert_eq!(Box::new(1u32).lolka(), 1);
+    assert_eq!(std::sync::Arc::new(2u32).lolka(), 2);
+
+    let strong = std::rc::Rc::new(3u32);
+    let weak = std::rc::Rc::downgrade(&strong);
+    assert_eq!(weak.lolka(), 3);
+    drop(strong);
+    assert_eq!(weak.lolka(), 0);
+
+    assert_eq!(prelude::Mutex::new(4u32).lolka(), 4);
+    assert_eq!(std::rc::Rc::new(prelude::RefCell::new(5u32)).lolka(), 5);
+}
+
+#[test]
+fn should_forward_user_type_named_after_builtin_target_through_deref() {
+    let mutex = user::Mutex {
+        value: 6u32,
+        derefs: Cell::new(0),
+    };
+    assert_eq!(mutex.lolka(), 6);
+    assert_eq!(mutex.derefs.get(), 1);
+}
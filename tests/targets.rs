@@ -0,0 +1,78 @@
+use auto_trait::auto_trait;
+
+use std::collections::HashMap;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct OtherWrapper(u32);
+
+impl core::ops::Deref for OtherWrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Map<K, V>(HashMap<K, V>, V);
+
+impl<K, V> core::ops::Deref for Map<K, V> {
+    type Target = V;
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+#[auto_trait(Wrapper, OtherWrapper, Box<T: Lolka>)]
+#[auto_trait(for<K> Map<K, V: Lolka>, Vec<u32>)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+}
+
+impl Lolka for [u32] {
+    fn lolka_ref(&self) -> u32 {
+        self.len() as u32
+    }
+}
+
+#[auto_trait(Wrapper)]
+#[auto_trait(OtherWrapper, Box<T: LolkaStacked>)]
+pub trait LolkaStacked {
+    fn lolka_stacked(&self) -> u32;
+}
+
+impl LolkaStacked for u32 {
+    fn lolka_stacked(&self) -> u32 {
+        *self + 1
+    }
+}
+
+#[test]
+fn should_implement_every_listed_target() {
+    assert_eq!(Wrapper(1).lolka_ref(), 1);
+    assert_eq!(OtherWrapper(2).lolka_ref(), 2);
+    assert_eq!(Box::new(3u32).lolka_ref(), 3);
+    let map = Map(HashMap::<String, u32>::new(), 4);
+    assert_eq!(map.lolka_ref(), 4);
+    assert!(map.0.is_empty());
+    assert_eq!(vec![1u32, 2].lolka_ref(), 2);
+}
+
+#[test]
+fn should_mix_list_with_stacked_attributes() {
+    assert_eq!(Wrapper(1).lolka_stacked(), 2);
+    assert_eq!(OtherWrapper(2).lolka_stacked(), 3);
+    assert_eq!(Box::new(3u32).lolka_stacked(), 4);
+}
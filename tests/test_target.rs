@@ -0,0 +1,114 @@
+use auto_trait::auto_trait;
+
+pub struct Real;
+
+pub struct Mock {
+    calls: u32,
+}
+
+impl Storage for Real {
+    fn name(&self) -> &'static str {
+        "real"
+    }
+
+    fn store(&mut self, _value: u32) -> u32 {
+        unreachable!("Real storage is not used by tests");
+    }
+
+    fn into_name(self) -> &'static str {
+        "real"
+    }
+}
+
+impl Storage for Mock {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn store(&mut self, value: u32) -> u32 {
+        self.calls += 1;
+        value + self.calls
+    }
+
+    fn into_name(self) -> &'static str {
+        "mock"
+    }
+}
+
+pub struct Wrapper {
+    inner: Real,
+    #[cfg(test)]
+    mock: Mock,
+}
+
+impl Wrapper {
+    fn new() -> Self {
+        Self {
+            inner: Real,
+            #[cfg(test)]
+            mock: Mock {
+                calls: 0,
+            },
+        }
+    }
+}
+
+impl core::ops::Deref for Wrapper {
+    type Target = Real;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl From<Wrapper> for Real {
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.inner
+    }
+}
+
+#[cfg(test)]
+impl AsRef<Mock> for Wrapper {
+    fn as_ref(&self) -> &Mock {
+        &self.mock
+    }
+}
+
+#[cfg(test)]
+impl AsMut<Mock> for Wrapper {
+    fn as_mut(&mut self) -> &mut Mock {
+        &mut self.mock
+    }
+}
+
+#[cfg(test)]
+impl From<Wrapper> for Mock {
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.mock
+    }
+}
+
+#[auto_trait(self::Wrapper => Real, test_target = Mock)]
+pub trait Storage {
+    fn name(&self) -> &'static str;
+
+    fn store(&mut self, value: u32) -> u32;
+
+    fn into_name(self) -> &'static str;
+}
+
+#[test]
+fn should_forward_to_test_target_in_tests() {
+    let mut wrapper = Wrapper::new();
+    assert_eq!(wrapper.name(), "mock");
+    assert_eq!(wrapper.store(1), 2);
+    assert_eq!(wrapper.store(1), 3);
+    assert_eq!(wrapper.mock.calls, 2);
+    assert_eq!(wrapper.into_name(), "mock");
+}
@@ -0,0 +1,44 @@
+mod internal {
+    use auto_trait::auto_trait;
+
+    #[auto_trait(Box<T: crate::api::Lolka>, trait_path = crate::api::Lolka)]
+    #[auto_trait(crate::Wrapper => u32, trait_path = crate::api::Lolka)]
+    pub trait Lolka {
+        fn lolka() -> u32;
+
+        fn lolka_ref(&self) -> u32;
+    }
+
+    impl Lolka for u32 {
+        fn lolka() -> u32 {
+            1
+        }
+
+        fn lolka_ref(&self) -> u32 {
+            *self
+        }
+    }
+}
+
+pub mod api {
+    pub use super::internal::Lolka;
+}
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+use api::Lolka;
+
+#[test]
+fn should_use_trait_path() {
+    assert_eq!(Box::new(5u32).lolka_ref(), 5);
+    assert_eq!(<Box<u32> as Lolka>::lolka(), 1);
+    assert_eq!(Wrapper(5).lolka_ref(), 5);
+    assert_eq!(Wrapper::lolka(), 1);
+}
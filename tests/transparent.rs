@@ -0,0 +1,10 @@
+//`bytemuck` is dependency of the crate using the option, hence it is enabled only with the feature
+#[cfg(feature = "bytemuck")]
+#[path = "transparent/peel.rs"]
+mod peel;
+
+//Test crate stands for `bytemuck`, which generated code refers to as `::bytemuck`
+#[cfg(feature = "bytemuck")]
+extern crate self as bytemuck;
+#[cfg(feature = "bytemuck")]
+pub use peel::TransparentWrapper;
@@ -0,0 +1,72 @@
+use auto_trait::auto_trait;
+
+///Mirrors `bytemuck::TransparentWrapper` with the same methods, restricted to sized types
+///
+///# Safety
+///
+///Implementor is `#[repr(transparent)]` wrapper of `Inner`.
+pub unsafe trait TransparentWrapper<Inner>: Sized {
+    fn peel(s: Self) -> Inner {
+        let s = core::mem::ManuallyDrop::new(s);
+        unsafe {
+            core::mem::transmute_copy(&*s)
+        }
+    }
+
+    fn peel_ref(s: &Self) -> &Inner {
+        unsafe {
+            &*(s as *const Self as *const Inner)
+        }
+    }
+
+    fn peel_mut(s: &mut Self) -> &mut Inner {
+        unsafe {
+            &mut *(s as *mut Self as *mut Inner)
+        }
+    }
+}
+
+#[auto_trait(Meters => u32, Wrapper<T: Lolka>, via = transparent)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+    fn lolka_mut(&mut self) -> u32;
+    fn lolka_self(self) -> u32 where Self: Sized;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self * 10
+    }
+}
+
+#[repr(transparent)]
+pub struct Meters(u32);
+
+unsafe impl TransparentWrapper<u32> for Meters {}
+
+#[repr(transparent)]
+pub struct Wrapper<T>(T);
+
+unsafe impl<T> TransparentWrapper<T> for Wrapper<T> {}
+
+#[test]
+fn should_forward_through_transparent_wrapper() {
+    let mut meters = Meters(1);
+    assert_eq!(meters.lolka_ref(), 1);
+    assert_eq!(meters.lolka_mut(), 2);
+    assert_eq!(meters.lolka_self(), 20);
+
+    let mut wrapper = Wrapper(2u32);
+    assert_eq!(wrapper.lolka_ref(), 2);
+    assert_eq!(wrapper.lolka_mut(), 3);
+    assert_eq!(wrapper.lolka_self(), 30);
+}
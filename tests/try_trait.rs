@@ -0,0 +1,102 @@
+use auto_trait::auto_trait;
+use auto_trait_delegate::AccessError;
+
+use core::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::sync::{Arc, Mutex};
+
+#[auto_trait(Mutex<T: Lolka>, RefCell<T: Lolka>, Weak<T: Lolka>, Box<T: Lolka>, no_owned, try_trait(TryLolka))]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+    fn add(&self, value: u32) -> u32;
+    //Borrowing result cannot outlive access, hence it has no counterpart
+    fn name(&self) -> &str {
+        "lolka"
+    }
+    fn into_lolka(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+#[auto_trait(Mutex<T: Counter>, RefCell<T: Counter>, lock_mut, try_trait(TryCounter, error = CounterError))]
+pub trait Counter {
+    fn get(&self) -> u32;
+    fn increment(&mut self);
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CounterError(AccessError);
+
+impl From<AccessError> for CounterError {
+    fn from(error: AccessError) -> Self {
+        Self(error)
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+
+    fn add(&self, value: u32) -> u32 {
+        *self + value
+    }
+}
+
+impl Counter for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+
+    fn increment(&mut self) {
+        *self += 1;
+    }
+}
+
+#[test]
+fn should_forward_fallible_counterpart() {
+    let mutex = Mutex::new(1u32);
+    assert_eq!(TryLolka::lolka(&mutex), Ok(1));
+    assert_eq!(TryLolka::add(&mutex, 2), Ok(3));
+
+    let cell = RefCell::new(2u32);
+    assert_eq!(TryLolka::lolka(&cell), Ok(2));
+    let _guard = cell.borrow_mut();
+    assert_eq!(TryLolka::lolka(&cell), Err(AccessError::Borrowed));
+
+    let strong = Rc::new(3u32);
+    let weak = Rc::downgrade(&strong);
+    assert_eq!(TryLolka::add(&weak, 1), Ok(4));
+    drop(strong);
+    assert_eq!(TryLolka::add(&weak, 1), Err(AccessError::Dropped));
+}
+
+#[test]
+fn should_report_poisoned_mutex() {
+    let mutex = Arc::new(Mutex::new(1u32));
+    let poisoned = mutex.clone();
+    let _ = std::thread::spawn(move || {
+        let _guard = poisoned.lock().unwrap();
+        panic!("poison");
+    }).join();
+
+    assert_eq!(TryLolka::lolka(&*mutex), Err(AccessError::Poisoned));
+}
+
+#[test]
+fn should_convert_into_specified_error() {
+    let mut mutex = Mutex::new(1u32);
+    assert_eq!(TryCounter::increment(&mut mutex), Ok(()));
+    assert_eq!(TryCounter::get(&mutex), Ok(2));
+
+    let mut cell = RefCell::new(1u32);
+    {
+        let _guard = cell.borrow();
+        assert_eq!(TryCounter::get(&cell), Ok(1));
+    }
+    assert_eq!(TryCounter::increment(&mut cell), Ok(()));
+    //Original trait is implemented as well
+    assert_eq!(Counter::get(&cell), 2);
+    assert_eq!(AccessError::Borrowed.to_string(), "RefCell is already borrowed");
+    assert_eq!(CounterError::from(AccessError::Dropped), CounterError(AccessError::Dropped));
+}
@@ -0,0 +1,32 @@
+use auto_trait::auto_trait;
+
+pub struct Pair<A, B>(pub A, B);
+
+impl<A, B> core::ops::Deref for Pair<A, B> {
+    type Target = B;
+    fn deref(&self) -> &Self::Target {
+        &self.1
+    }
+}
+
+#[auto_trait(Pair::<u8, u32>, std::boxed::Box::<T: Lolka>, Pair::<u16, Pair::<u8, T: Lolka>>)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+fn lolka(lolka: impl Lolka) -> u32 {
+    lolka.lolka()
+}
+
+#[test]
+fn should_accept_turbofish_in_target() {
+    assert_eq!(lolka(Pair(1u8, 2u32)), 2);
+    assert_eq!(lolka(Box::new(3u32)), 3);
+    assert_eq!(lolka(Pair(1u16, Pair(2u8, Box::new(4u32)))), 4);
+}
@@ -0,0 +1,26 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+
+    fn lolka_default(&self) -> u32 {
+        0
+    }
+
+    #[auto_trait(skip)]
+    fn lolka_skip(&self) -> u32 {
+        1
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+fn main() {
+    //Warnings are reported only if compilation fails
+    let _: () = Box::new(1u32).lolka();
+}
@@ -0,0 +1,17 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl Wrapper {
+    fn value(&self) -> &u32 {
+        &self.0
+    }
+}
+
+#[auto_trait(self::Wrapper, deref = Wrapper::value)]
+pub trait Lolka {
+    fn lolka() -> u32;
+}
+
+fn main() {
+}
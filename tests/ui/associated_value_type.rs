@@ -0,0 +1,12 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>)]
+pub trait Lolka {
+    #[auto_trait(value = u32)]
+    type Output;
+
+    fn lolka_output(&self) -> Self::Output;
+}
+
+fn main() {
+}
@@ -0,0 +1,15 @@
+use auto_trait::auto_trait;
+
+pub trait Base {
+    fn base(&self) -> u32;
+}
+
+#[auto_trait(Box<Lolka>)]
+#[auto_trait(Rc<Base>)]
+#[auto_trait(Arc<Base + Send>)]
+pub trait Lolka: Base {
+    fn lolka(&self) -> u32;
+}
+
+fn main() {
+}
@@ -0,0 +1,18 @@
+use auto_trait::auto_trait;
+
+use std::rc::Rc;
+
+#[auto_trait(Box<T: Lolka>, bound(T: Send))]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+}
+
+impl Lolka for Rc<u32> {
+    fn lolka_ref(&self) -> u32 {
+        0
+    }
+}
+
+fn main() {
+    <Box<Rc<u32>> as Lolka>::lolka_ref(&Box::new(Rc::new(0u32)));
+}
@@ -0,0 +1,12 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(cache = CachedConfig)]
+pub trait Config {
+    #[auto_trait(cache)]
+    fn value(&self, idx: usize) -> u32;
+    #[auto_trait(cache)]
+    fn name(&self) -> &str;
+}
+
+fn main() {
+}
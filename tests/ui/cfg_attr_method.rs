@@ -0,0 +1,12 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>)]
+pub trait Lolka {
+    #[cfg_attr(all(), auto_trait(skip))]
+    fn lolka_ref(&self) -> u32 {
+        0
+    }
+}
+
+fn main() {
+}
@@ -0,0 +1,25 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(export)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait::complete]
+impl Lolka for Wrapper {
+    fn kek(&self) -> u32 {
+        42
+    }
+}
+
+fn main() {
+}
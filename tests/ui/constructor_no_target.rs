@@ -0,0 +1,30 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(Wrapper)]
+pub trait Parse: Sized {
+    fn value(&self) -> u32;
+
+    fn parse(text: &str) -> Self;
+}
+
+impl Parse for u32 {
+    fn value(&self) -> u32 {
+        *self
+    }
+
+    fn parse(text: &str) -> Self {
+        text.parse().unwrap_or_default()
+    }
+}
+
+fn main() {
+}
@@ -0,0 +1,43 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(self::Wrapper)]
+#[auto_trait(self::Wrapper)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+#[auto_trait(Box<T: Kek>, Rc)]
+#[auto_trait(Box<T: Kek>)]
+pub trait Kek {
+    fn kek(&self) -> u32;
+}
+
+#[auto_trait(self::Wrapper, cfg(test))]
+#[auto_trait(self::Wrapper, cfg(not(test)))]
+pub trait Cheburek {
+    fn cheburek(&self) -> u32;
+}
+
+impl Cheburek for u32 {
+    fn cheburek(&self) -> u32 {
+        *self
+    }
+}
+
+fn main() {
+}
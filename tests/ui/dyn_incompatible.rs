@@ -0,0 +1,11 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<dyn>)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_new() -> Self where Self: Sized;
+}
+
+fn main() {
+}
@@ -0,0 +1,23 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(dyn)]
+pub trait Lolka {
+    const LOLKA: u32;
+
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_generic<T: Into<u32>>(&self, value: T) -> u32 {
+        value.into()
+    }
+
+    fn lolka_clone(&self) -> Self;
+
+    fn lolka_new() -> u32 {
+        0
+    }
+
+    fn lolka_sized() -> Self where Self: Sized;
+}
+
+fn main() {
+}
@@ -0,0 +1,36 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+mod other {
+    pub struct Wrapper(pub u32);
+
+    impl core::ops::Deref for Wrapper {
+        type Target = u32;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+}
+
+#[auto_trait(enum = AnyLolka, Wrapper, other::Wrapper)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+fn main() {
+    assert_eq!(Wrapper(1).lolka(), 1);
+}
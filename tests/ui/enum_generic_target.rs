@@ -0,0 +1,16 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(enum = AnyLolka, Box<T: Lolka>, &u32; variant = Borrowed)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+fn main() {
+    assert_eq!(Box::new(1u32).lolka(), 1);
+}
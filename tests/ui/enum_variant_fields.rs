@@ -0,0 +1,36 @@
+use auto_trait::{auto_trait, AutoTrait};
+
+#[auto_trait(export)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+#[derive(AutoTrait)]
+#[auto_trait(Lolka)]
+pub enum WithUnit {
+    Fast(u32),
+    Unit,
+}
+
+#[derive(AutoTrait)]
+#[auto_trait(Lolka)]
+pub enum Pair {
+    Fast(u32),
+    Both(u32, u32),
+}
+
+#[derive(AutoTrait)]
+#[auto_trait(Lolka)]
+pub enum Named {
+    Fast {
+        value: u32,
+    },
+}
+
+#[derive(AutoTrait)]
+#[auto_trait(Lolka)]
+pub enum Never {
+}
+
+fn main() {
+}
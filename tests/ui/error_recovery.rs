@@ -0,0 +1,37 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>)]
+#[auto_trait(Rc; unknown_option)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+#[auto_trait(Box<T: Kek>, for<T> std::vec::Vec<T>)]
+pub trait Kek {
+    fn kek(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+impl Kek for u32 {
+    fn kek(&self) -> u32 {
+        *self
+    }
+}
+
+fn lolka(lolka: impl Lolka) -> u32 {
+    lolka.lolka()
+}
+
+fn kek(kek: impl Kek) -> u32 {
+    kek.kek()
+}
+
+fn main() {
+    lolka(Box::new(1u32));
+    kek(Box::new(2u32));
+}
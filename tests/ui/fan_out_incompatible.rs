@@ -0,0 +1,11 @@
+use auto_trait::auto_trait;
+
+#[auto_trait((A, B))]
+pub trait Sink {
+    fn write(&mut self, line: &str);
+
+    fn written(&self) -> usize;
+}
+
+fn main() {
+}
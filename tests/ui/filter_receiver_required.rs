@@ -0,0 +1,13 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>, ref_only)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32;
+
+    fn lolka_self(self) -> u32;
+}
+
+fn main() {
+}
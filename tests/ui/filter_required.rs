@@ -0,0 +1,16 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>, except(lolka_mut))]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32;
+}
+
+#[auto_trait(Box<T: LolkaOnly>, only(lolka_unknown))]
+pub trait LolkaOnly {
+    fn lolka_ref(&self) -> u32;
+}
+
+fn main() {
+}
@@ -0,0 +1,20 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(fn)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+    fn kek(&self) -> u32;
+}
+
+#[auto_trait(fn)]
+pub trait Counter {
+    fn increment(&mut self, value: u32);
+}
+
+#[auto_trait(fn)]
+pub trait Named {
+    fn name(&self) -> &str;
+}
+
+fn main() {
+}
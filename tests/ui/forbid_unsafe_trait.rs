@@ -0,0 +1,16 @@
+//Unsafe trait is forwarded only by `unsafe impl`, which is reported by `unsafe_code` lint as implementation written by hand
+#![deny(unsafe_code)]
+
+use auto_trait::auto_trait;
+
+///# Safety
+///
+///Returns the same value on every call
+#[allow(unsafe_code)]
+#[auto_trait(Box<T: Stable>, unsafe)]
+pub unsafe trait Stable {
+    fn stable(&self) -> u32;
+}
+
+fn main() {
+}
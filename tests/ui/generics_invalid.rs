@@ -0,0 +1,9 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T>, generics = "<T: Lolka")]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+fn main() {
+}
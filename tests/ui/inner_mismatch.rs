@@ -0,0 +1,46 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Wrapper> for String {
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.0.to_string()
+    }
+}
+
+#[auto_trait(self::Wrapper => String)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_self(self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self
+    }
+}
+
+impl Lolka for String {
+    fn lolka_ref(&self) -> u32 {
+        self.len() as u32
+    }
+
+    fn lolka_self(self) -> u32 {
+        self.len() as u32
+    }
+}
+
+fn main() {
+}
@@ -0,0 +1,14 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(&)]
+pub trait Lolka {
+    fn lolka_mut(&mut self) -> u32;
+}
+
+#[auto_trait(Rc)]
+pub trait LolkaSelf {
+    fn lolka_self(self) -> u32;
+}
+
+fn main() {
+}
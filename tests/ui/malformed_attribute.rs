@@ -0,0 +1,32 @@
+use auto_trait::auto_trait;
+
+#[auto_trait]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+#[auto_trait(Box<T: Kek>)]
+#[auto_trait = "Rc"]
+pub trait Kek {
+    fn kek(&self) -> u32;
+}
+
+#[auto_trait(Box<T: Cheburek>)]
+#[auto_trait()]
+pub trait Cheburek {
+    fn cheburek(&self) -> u32;
+}
+
+#[auto_trait(Box<T: Pepega>)]
+#[auto_trait]
+pub trait Pepega {
+    fn pepega(&self) -> u32;
+}
+
+#[auto_trait(Box<T: Kappa>, 1)]
+pub trait Kappa {
+    fn kappa(&self) -> u32;
+}
+
+fn main() {
+}
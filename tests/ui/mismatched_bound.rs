@@ -0,0 +1,19 @@
+use auto_trait::auto_trait;
+
+mod pkg {
+    #[allow(unused_imports)]
+    pub use super::Kek as Delegate;
+}
+
+#[auto_trait(Box<T: Lolke>)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+#[auto_trait(Box<T> where T: pkg::Delegate)]
+pub trait Kek {
+    fn kek(&self) -> u32;
+}
+
+fn main() {
+}
@@ -0,0 +1,11 @@
+use auto_trait::auto_trait;
+
+pub struct Pair<T, U>(T, U);
+
+#[auto_trait(for<T: Lolka, U> Pair<T, U> where U: Lolka)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+fn main() {
+}
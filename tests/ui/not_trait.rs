@@ -0,0 +1,19 @@
+use auto_trait::auto_trait;
+
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+#[auto_trait(Box<T: Lolka>)]
+pub struct Wrapper(u32);
+
+#[auto_trait(Wrapper)]
+impl Lolka for Wrapper {
+    fn lolka(&self) -> u32 {
+        self.0
+    }
+}
+
+fn main() {
+    Wrapper(1).lolka();
+}
@@ -0,0 +1,14 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>, no_ownd)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+}
+
+#[auto_trait(Box<T: Kek>; inline, dref = str::len)]
+pub trait Kek {
+    fn kek(&self) -> usize;
+}
+
+fn main() {
+}
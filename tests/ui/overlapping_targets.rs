@@ -0,0 +1,27 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>)]
+#[auto_trait(Box<u32>)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+#[auto_trait(Box<u32>, Box)]
+pub trait Kek {
+    fn kek(&self) -> u32;
+}
+
+impl Kek for u32 {
+    fn kek(&self) -> u32 {
+        *self
+    }
+}
+
+fn main() {
+}
@@ -0,0 +1,42 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Into<u32> for Wrapper {
+    fn into(self) -> u32 {
+        self.0
+    }
+}
+
+impl Into<String> for Wrapper {
+    fn into(self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[auto_trait(Wrapper)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_self(self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_self(self) -> u32 {
+        self
+    }
+}
+
+fn main() {
+}
@@ -0,0 +1,49 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Rc, std::sync::Mutex<T: Lolka>)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_consume(self) -> u32;
+}
+
+#[auto_trait(std::rc::Rc<T: Cheburek>)]
+pub trait Cheburek {
+    fn cheburek_consume(self) -> u32;
+}
+
+//Default implementation is kept instead
+#[auto_trait(std::sync::Arc<T: Kek>)]
+pub trait Kek {
+    fn kek_ref(&self) -> u32;
+
+    fn kek_consume(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_consume(self) -> u32 {
+        self
+    }
+}
+
+impl Kek for u32 {
+    fn kek_ref(&self) -> u32 {
+        *self
+    }
+}
+
+impl Cheburek for u32 {
+    fn cheburek_consume(self) -> u32 {
+        self
+    }
+}
+
+fn main() {
+    let _: () = std::sync::Arc::new(1u32).kek_consume();
+}
@@ -0,0 +1,15 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper<F>(F);
+
+pub struct Callback<F>(F);
+
+#[auto_trait(Box<dyn Fn(u32) -> u32>)]
+#[auto_trait(Wrapper<F: Fn(u32) -> u32>)]
+#[auto_trait(Callback<Fn(u32)>)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+fn main() {
+}
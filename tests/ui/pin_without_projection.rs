@@ -0,0 +1,12 @@
+use auto_trait::auto_trait;
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+#[auto_trait(Box<T: Ticker>)]
+pub trait Ticker {
+    fn tick(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32>;
+}
+
+fn main() {
+}
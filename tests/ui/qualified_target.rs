@@ -0,0 +1,17 @@
+use auto_trait::auto_trait;
+
+pub trait Holder {
+    type Value;
+}
+
+impl Holder for u32 {
+    type Value = Box<u32>;
+}
+
+#[auto_trait(<u32 as Holder>::Value)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+fn main() {
+}
@@ -0,0 +1,16 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+#[auto_trait(Box<T: Lolka>)]
+#[auto_trait(Wrapper, 1 + 2)]
+#[auto_trait(Wrapper<T: >)]
+#[auto_trait(std::sync::Arc<T: Lolka>, kek = 1)]
+#[cfg_attr(all(), auto_trait(&'static str, Vec<>>), auto_trait(Wrapper, 3))]
+#[auto_trait(std::rc::Rc<T: Lolka>, unsafe)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+fn main() {
+}
@@ -0,0 +1,17 @@
+use auto_trait::auto_trait;
+
+use std::sync::Mutex;
+
+#[auto_trait(Mutex<T: Lolka>, shared)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+#[auto_trait(Box<T: Convert<u32>>, shared)]
+pub trait Convert<T> {
+    fn convert(&self) -> T;
+}
+
+fn main() {
+    let _ = Mutex::new(0u32);
+}
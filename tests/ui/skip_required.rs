@@ -0,0 +1,10 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>)]
+pub trait Lolka {
+    #[auto_trait(skip)]
+    fn lolka_ref(&self) -> u32;
+}
+
+fn main() {
+}
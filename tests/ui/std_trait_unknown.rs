@@ -0,0 +1,16 @@
+use auto_trait::auto_trait_std;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+auto_trait_std!(std::fmt::Display, std::ops::Add for Wrapper);
+
+fn main() {
+    assert_eq!(Wrapper(1).to_string(), "1");
+}
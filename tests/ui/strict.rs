@@ -0,0 +1,36 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(self::Wrapper, no_mut, strict)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32 {
+        0
+    }
+
+}
+
+#[auto_trait(&dyn, strict)]
+pub trait LolkaDyn {
+    fn lolka_ref(&self) -> u32;
+
+    fn lolka_mut(&mut self) -> u32 {
+        0
+    }
+
+    fn lolka_generic<T: Into<u32>>(&self, value: T) -> u32 {
+        value.into()
+    }
+}
+
+fn main() {
+}
@@ -0,0 +1,38 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(String);
+
+impl core::ops::Deref for Wrapper {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct Converted(u64);
+
+impl core::ops::Deref for Converted {
+    type Target = u64;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(Box<T: Lolka>)]
+#[auto_trait(Wrapper, Converted => u64)]
+pub trait Lolka {
+    const LOLKA: u32;
+
+    fn lolka(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    const LOLKA: u32 = 1;
+
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+fn main() {
+}
@@ -0,0 +1,9 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka>; lolka)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+}
+
+fn main() {
+}
@@ -0,0 +1,27 @@
+use auto_trait::auto_trait;
+
+pub struct Plain(u32);
+
+pub struct Wrapper(String);
+
+impl core::ops::Deref for Wrapper {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(Box<T: Lolka>, self::Plain)]
+#[auto_trait(self::Wrapper)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka(&self) -> u32 {
+        *self
+    }
+}
+
+fn main() {
+}
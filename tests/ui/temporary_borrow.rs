@@ -0,0 +1,13 @@
+use auto_trait::auto_trait;
+
+use core::future::Future;
+use core::pin::Pin;
+
+#[auto_trait(Mutex<T: Fetch>)]
+pub trait Fetch {
+    fn fetch<'a>(&'a self) -> Pin<Box<dyn Future<Output = u32> + Send + 'a>>;
+    fn name(&self) -> &str;
+}
+
+fn main() {
+}
@@ -0,0 +1,23 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[auto_trait(self::Wrapper for Lolka<u32, u8>)]
+pub trait Lolka<T> {
+    fn lolka(&self, value: T) -> u32;
+}
+
+#[auto_trait(self::Wrapper for Other<u32>)]
+pub trait Kek<T> {
+    fn kek(&self, value: T) -> u32;
+}
+
+fn main() {
+}
@@ -0,0 +1,12 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Weak<T: Lolka>, no_mut, try_trait(TryLolka))]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+    fn set(&mut self, value: u32) {
+        let _ = value;
+    }
+}
+
+fn main() {
+}
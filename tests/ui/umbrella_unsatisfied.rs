@@ -0,0 +1,16 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(std::io::Cursor<Vec<u8>>);
+
+impl std::io::Read for Wrapper {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+#[auto_trait(Box<T: Storage>, self::Wrapper)]
+pub trait Storage: std::io::Read + std::io::Write {
+}
+
+fn main() {
+}
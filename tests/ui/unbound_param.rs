@@ -0,0 +1,9 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(for<T> Box<T>)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+fn main() {
+}
@@ -0,0 +1,14 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Stable>)]
+pub unsafe trait Stable {
+    fn stable(&self) -> u32;
+}
+
+#[auto_trait(Box<T: Lolka>, unsafe)]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+fn main() {
+}
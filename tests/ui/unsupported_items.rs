@@ -0,0 +1,28 @@
+use auto_trait::auto_trait;
+
+pub struct Wrapper(u32);
+
+impl Wrapper {
+    fn get(&self) -> &u32 {
+        &self.0
+    }
+}
+
+#[auto_trait(Wrapper, deref = Wrapper::get)]
+pub trait Lolka {
+    type Output;
+    type Error;
+    const LIMIT: u32;
+
+    fn lolka() -> u32;
+    fn lolka_self(self) -> u32;
+    fn lolka_ref(&self) -> u32;
+
+    kek!();
+
+    #[auto_trait(value = 1)]
+    kek!();
+}
+
+fn main() {
+}
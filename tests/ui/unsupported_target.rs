@@ -0,0 +1,24 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(())]
+pub trait Lolka {
+    fn lolka(&self) -> u32;
+}
+
+#[auto_trait([u32; 2])]
+pub trait Kek {
+    fn kek(&self) -> u32;
+}
+
+#[auto_trait(*const u32)]
+pub trait Cheburek {
+    fn cheburek(&self) -> u32;
+}
+
+#[auto_trait(fn() -> u32)]
+pub trait Lolka2 {
+    fn lolka2(&self) -> u32;
+}
+
+fn main() {
+}
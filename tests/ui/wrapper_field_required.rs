@@ -0,0 +1,18 @@
+use auto_trait::AutoWrapper;
+
+#[derive(AutoWrapper)]
+pub struct Pair(u32, u32);
+
+#[derive(AutoWrapper)]
+#[auto_wrapper(field = value)]
+pub struct Named {
+    pub name: u32,
+}
+
+#[derive(AutoWrapper)]
+pub enum Either {
+    Left(u32),
+}
+
+fn main() {
+}
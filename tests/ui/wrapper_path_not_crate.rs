@@ -0,0 +1,8 @@
+use auto_trait::AutoWrapper;
+
+#[derive(AutoWrapper)]
+#[auto_wrapper(forwarder = forward_to_meters, path = units::Meters)]
+pub struct Meters(u32);
+
+fn main() {
+}
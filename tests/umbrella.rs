@@ -0,0 +1,50 @@
+use auto_trait::auto_trait;
+
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+
+pub struct Wrapper(Cursor<Vec<u8>>);
+
+impl Read for Wrapper {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl Write for Wrapper {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl Seek for Wrapper {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.0.seek(pos)
+    }
+}
+
+//`Rc` implements neither of supertraits, hence it is left without implementation
+#[auto_trait(Box<T: Storage>, std::rc::Rc<T: Storage>, self::Wrapper)]
+pub trait Storage: Read + Write + Seek {
+}
+
+impl Storage for Cursor<Vec<u8>> {
+}
+
+fn round_trip<T: Storage>(mut storage: T) -> Vec<u8> {
+    storage.write_all(b"lolka").expect("to write");
+    storage.seek(SeekFrom::Start(0)).expect("to seek");
+    let mut result = Vec::new();
+    storage.read_to_end(&mut result).expect("to read");
+    result
+}
+
+#[test]
+fn should_implement_umbrella_trait_for_targets_implementing_supertraits() {
+    assert_eq!(round_trip(Cursor::new(Vec::new())), b"lolka");
+    assert_eq!(round_trip(Box::new(Cursor::new(Vec::new()))), b"lolka");
+    assert_eq!(round_trip(Wrapper(Cursor::new(Vec::new()))), b"lolka");
+}
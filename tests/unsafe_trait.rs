@@ -0,0 +1,61 @@
+use auto_trait::{auto_trait, auto_trait_impl, AutoTrait};
+
+pub struct Wrapper(u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+///# Safety
+///
+///Returns the same value on every call
+#[auto_trait(Wrapper, Box<T: Stable>, unsafe)]
+#[auto_trait(export)]
+pub unsafe trait Stable {
+    fn stable(&self) -> u32;
+}
+
+unsafe impl Stable for u32 {
+    fn stable(&self) -> u32 {
+        *self
+    }
+}
+
+///# Safety
+///
+///Returns the same value on every call
+pub unsafe trait StableForeign {
+    fn stable_foreign(&self) -> u32;
+}
+
+unsafe impl StableForeign for u32 {
+    fn stable_foreign(&self) -> u32 {
+        *self + 1
+    }
+}
+
+auto_trait_impl!(unsafe impl StableForeign for Wrapper {
+    fn stable_foreign(&self) -> u32;
+});
+
+#[derive(AutoTrait)]
+#[auto_trait(unsafe Stable)]
+pub struct Derived(u32);
+
+impl core::ops::Deref for Derived {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[test]
+fn should_implement_unsafe_trait() {
+    assert_eq!(Wrapper(5).stable(), 5);
+    assert_eq!(Box::new(5u32).stable(), 5);
+    assert_eq!(Wrapper(5).stable_foreign(), 6);
+    assert_eq!(Derived(5).stable(), 5);
+}
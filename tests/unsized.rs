@@ -0,0 +1,97 @@
+use auto_trait::auto_trait;
+
+pub trait Base {
+    fn base(&self) -> &[u8];
+    fn base_mut(&mut self) -> &mut [u8];
+}
+
+impl Base for Vec<u8> {
+    fn base(&self) -> &[u8] {
+        self
+    }
+
+    fn base_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+#[auto_trait(str, deref = str::as_bytes)]
+pub trait Lolka {
+    fn lolka_len(&self) -> usize;
+
+    fn lolka_self(self) -> usize where Self: Sized {
+        0
+    }
+}
+
+impl Lolka for [u8] {
+    fn lolka_len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[auto_trait(dyn Base, deref = Base::base, deref_mut = Base::base_mut)]
+pub trait LolkaMut {
+    fn lolka_first(&self) -> u8;
+    fn lolka_clear(&mut self);
+}
+
+impl LolkaMut for [u8] {
+    fn lolka_first(&self) -> u8 {
+        self[0]
+    }
+
+    fn lolka_clear(&mut self) {
+        for byte in self.iter_mut() {
+            *byte = 0;
+        }
+    }
+}
+
+#[test]
+fn should_forward_str_to_bytes() {
+    assert_eq!("lolka".lolka_len(), 5);
+}
+
+#[test]
+fn should_forward_trait_object() {
+    let mut base: Box<dyn Base> = Box::new(vec![1u8, 2]);
+    assert_eq!(base.lolka_first(), 1);
+    base.lolka_clear();
+    assert_eq!(base.base(), [0, 0]);
+}
+
+pub trait DataSource: LolkaSource {
+}
+
+impl DataSource for u32 {
+}
+
+#[auto_trait(Box<dyn DataSource + Send + Sync>)]
+#[auto_trait(Box<dyn DataSource + Send + 'static>)]
+pub trait LolkaSource {
+    fn lolka_source(&self) -> u32;
+
+    fn lolka_source_owned(self) -> u32 where Self: Sized {
+        0
+    }
+}
+
+impl LolkaSource for u32 {
+    fn lolka_source(&self) -> u32 {
+        *self
+    }
+}
+
+#[test]
+fn should_forward_boxed_trait_object_with_markers() {
+    let source: Box<dyn DataSource + Send + Sync> = Box::new(5u32);
+    let thread = std::thread::spawn(move || source.lolka_source());
+    assert_eq!(thread.join().expect("To finish thread"), 5);
+}
+
+#[test]
+fn should_forward_boxed_trait_object_with_static_lifetime() {
+    let source: Box<dyn DataSource + Send + 'static> = Box::new(6u32);
+    assert_eq!(source.lolka_source(), 6);
+}
@@ -0,0 +1,48 @@
+use auto_trait::auto_trait;
+
+#[auto_trait(Box<T: Lolka + ?Sized>)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> u32;
+    fn lolka_mut(&mut self) -> u32;
+
+    fn lolka_owned(self) -> u32
+    where
+        Self: Sized,
+    {
+        0
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> u32 {
+        *self
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        *self += 1;
+        *self
+    }
+}
+
+impl Lolka for str {
+    fn lolka_ref(&self) -> u32 {
+        self.len() as u32
+    }
+
+    fn lolka_mut(&mut self) -> u32 {
+        self.make_ascii_uppercase();
+        self.len() as u32
+    }
+}
+
+#[test]
+fn should_forward_to_unsized_parameter() {
+    let mut boxed: Box<str> = "lolka".into();
+    assert_eq!(boxed.lolka_ref(), 5);
+    assert_eq!(boxed.lolka_mut(), 5);
+    assert_eq!(&*boxed, "LOLKA");
+
+    let mut boxed: Box<dyn Lolka> = Box::new(1u32);
+    assert_eq!(boxed.lolka_ref(), 1);
+    assert_eq!(boxed.lolka_mut(), 2);
+}
@@ -0,0 +1,49 @@
+use auto_trait::auto_trait;
+
+use std::sync::{Arc, Weak};
+
+#[auto_trait(Weak<T: Lolka>)]
+pub trait Lolka {
+    fn lolka_ref(&self, add: u32) -> u32;
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self, add: u32) -> u32 {
+        *self + add
+    }
+}
+
+#[auto_trait(std::rc::Weak<T: LolkaDefault>, upgrade = default)]
+pub trait LolkaDefault {
+    fn lolka_default(&self) -> u32;
+}
+
+impl LolkaDefault for u32 {
+    fn lolka_default(&self) -> u32 {
+        *self
+    }
+}
+
+#[test]
+fn should_forward_to_live_target() {
+    let strong = Arc::new(1u32);
+    let weak = Arc::downgrade(&strong);
+    assert_eq!(weak.lolka_ref(1), 2);
+
+    let strong = std::rc::Rc::new(3u32);
+    let weak = std::rc::Rc::downgrade(&strong);
+    assert_eq!(weak.lolka_default(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Weak pointer is dropped")]
+fn should_panic_on_dropped_target() {
+    let weak = Arc::downgrade(&Arc::new(1u32));
+    weak.lolka_ref(1);
+}
+
+#[test]
+fn should_return_default_on_dropped_target() {
+    let weak = std::rc::Rc::downgrade(&std::rc::Rc::new(3u32));
+    assert_eq!(weak.lolka_default(), 0);
+}
@@ -0,0 +1,85 @@
+use auto_trait::{auto_trait, AutoWrapper};
+
+#[derive(AutoWrapper)]
+pub struct Wrapper(u32);
+
+#[derive(AutoWrapper)]
+pub struct Generic<T>(T);
+
+#[derive(AutoWrapper)]
+pub struct Named<T> where T: Clone {
+    pub name: &'static str,
+    #[auto_wrapper(inner)]
+    pub value: Vec<T>,
+}
+
+#[derive(AutoWrapper)]
+#[auto_wrapper(field = 1)]
+pub struct Indexed(pub &'static str, pub u32);
+
+#[auto_trait(Wrapper => u32, Generic<T: Lolka>, Named<T: Clone> => Vec<T>, Indexed => u32)]
+pub trait Lolka {
+    fn lolka_ref(&self) -> usize;
+
+    fn lolka_mut(&mut self) -> usize;
+
+    fn lolka_self(self) -> usize where Self: Sized {
+        0
+    }
+}
+
+impl Lolka for u32 {
+    fn lolka_ref(&self) -> usize {
+        *self as usize
+    }
+
+    fn lolka_mut(&mut self) -> usize {
+        *self += 1;
+        *self as usize
+    }
+
+    fn lolka_self(self) -> usize {
+        self as usize * 10
+    }
+}
+
+impl<T> Lolka for Vec<T> {
+    fn lolka_ref(&self) -> usize {
+        self.len()
+    }
+
+    fn lolka_mut(&mut self) -> usize {
+        self.truncate(1);
+        self.len()
+    }
+
+    fn lolka_self(self) -> usize {
+        self.len() * 10
+    }
+}
+
+#[test]
+fn should_forward_through_derived_conversions() {
+    let mut wrapper = Wrapper(1);
+    assert_eq!(wrapper.lolka_ref(), 1);
+    assert_eq!(wrapper.lolka_mut(), 2);
+    assert_eq!(wrapper.lolka_self(), 20);
+
+    let mut generic = Generic(1u32);
+    assert_eq!(generic.lolka_ref(), 1);
+    assert_eq!(generic.lolka_mut(), 2);
+    assert_eq!(*generic, 2);
+
+    let mut named = Named {
+        name: "lolka",
+        value: vec![1, 2],
+    };
+    assert_eq!(named.lolka_ref(), 2);
+    assert_eq!(named.lolka_mut(), 1);
+    assert_eq!(named.name, "lolka");
+    assert_eq!(named.lolka_self(), 10);
+
+    let indexed = Indexed("lolka", 3);
+    assert_eq!(indexed.lolka_ref(), 3);
+    assert_eq!(u32::from(indexed), 3);
+}
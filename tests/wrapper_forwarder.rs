@@ -0,0 +1,124 @@
+//Forwarders are exported for other crates, hence downstream is mimicked by module, which can reach only public items of wrappers
+//Macros, which are exported by derive of the same crate, are reachable only in textual scope
+#[macro_use]
+mod units {
+    use auto_trait::AutoWrapper;
+
+    #[derive(AutoWrapper)]
+    #[auto_wrapper(forwarder = forward_to_meters, path = crate::units::Meters)]
+    pub struct Meters(u32);
+
+    impl Meters {
+        pub fn new(value: u32) -> Self {
+            Self(value)
+        }
+    }
+
+    #[derive(AutoWrapper)]
+    #[auto_wrapper(forwarder = forward_to_named, path = crate::units::Named)]
+    pub struct Named<T: Clone> {
+        name: &'static str,
+        #[auto_wrapper(inner)]
+        value: Vec<T>,
+    }
+
+    impl<T: Clone> Named<T> {
+        pub fn new(name: &'static str, value: Vec<T>) -> Self {
+            Self {
+                name,
+                value,
+            }
+        }
+
+        pub fn name(&self) -> &'static str {
+            self.name
+        }
+    }
+}
+
+mod downstream {
+    pub trait Lolka {
+        fn lolka_ref(&self) -> u32;
+        fn lolka_mut(&mut self, add: u32) -> u32;
+        fn lolka_self(self) -> u32 where Self: Sized;
+    }
+
+    impl Lolka for u32 {
+        fn lolka_ref(&self) -> u32 {
+            *self
+        }
+
+        fn lolka_mut(&mut self, add: u32) -> u32 {
+            *self += add;
+            *self
+        }
+
+        fn lolka_self(self) -> u32 {
+            self * 10
+        }
+    }
+
+    impl<T> Lolka for Vec<T> {
+        fn lolka_ref(&self) -> u32 {
+            self.len() as u32
+        }
+
+        fn lolka_mut(&mut self, add: u32) -> u32 {
+            self.truncate(add as usize);
+            self.len() as u32
+        }
+
+        fn lolka_self(self) -> u32 {
+            self.len() as u32 * 100
+        }
+    }
+
+    ///# Safety
+    ///
+    ///Returns the same value on every call
+    pub unsafe trait Kek {
+        fn kek(&self) -> u32;
+    }
+
+    unsafe impl Kek for u32 {
+        fn kek(&self) -> u32 {
+            *self + 1
+        }
+    }
+
+    forward_to_meters!(impl Lolka {
+        fn lolka_ref(&self) -> u32;
+        fn lolka_mut(&mut self, add: u32) -> u32;
+        fn lolka_self(self) -> u32 where Self: Sized;
+    });
+
+    forward_to_meters!(unsafe impl self::Kek {
+        fn kek(&self) -> u32;
+    });
+
+    forward_to_named!(impl Lolka {
+        fn lolka_ref(&self) -> u32;
+        fn lolka_mut(&mut self, add: u32) -> u32;
+        fn lolka_self(self) -> u32 where Self: Sized;
+    });
+}
+
+use downstream::{Kek, Lolka};
+
+#[test]
+fn should_forward_downstream_trait() {
+    let mut meters = units::Meters::new(1);
+    assert_eq!(meters.lolka_ref(), 1);
+    assert_eq!(meters.lolka_mut(2), 3);
+    assert_eq!(meters.kek(), 4);
+    assert_eq!(meters.lolka_self(), 30);
+}
+
+#[test]
+fn should_forward_downstream_trait_for_generic_wrapper() {
+    let mut named = units::Named::new("lolka", vec![1, 2, 3]);
+    assert_eq!(named.lolka_ref(), 3);
+    assert_eq!(named.lolka_mut(2), 2);
+    assert_eq!(named.name(), "lolka");
+    assert_eq!(named.lolka_self(), 200);
+}
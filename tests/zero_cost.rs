@@ -0,0 +1,69 @@
+//!Compares optimized code of forwarded calls with direct calls, built for fixture crate `tests/zero_cost`
+//!
+//!Calls are made by example of the fixture, hence forwarders are called across crates.
+//!Automatic inlining of small functions across crates is disabled, so that forwarder is inlined only when generated code asks for it,
+//!as it would be by compiler without such inlining, or for forwarder, which body exceeds its threshold.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+///Names of functions within `tests/zero_cost/examples/calls.rs`, which are prefixed by `direct_` and `forwarded_`
+const CALLS: &[&str] = &["ref", "mut", "owned", "box", "shared", "field", "into", "newtype"];
+
+fn build_ir() -> String {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = root.join("target").join("zero-cost");
+    let status = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .args(["rustc", "--quiet", "--release", "--package", "auto-trait-zero-cost", "--example", "calls", "--target-dir"])
+        .arg(&target_dir)
+        .args(["--", "--emit=llvm-ir"])
+        .current_dir(root)
+        //Flag is unstable, which is fine for test, that only inspects output
+        .env("RUSTC_BOOTSTRAP", "1")
+        .env("RUSTFLAGS", "-Zcross-crate-inline-threshold=never")
+        .env_remove("CARGO_ENCODED_RUSTFLAGS")
+        .status()
+        .expect("to run cargo");
+    assert!(status.success(), "Fixture failed to build");
+
+    //Output is named by hash of build, hence the latest one is picked
+    let examples = target_dir.join("release").join("examples");
+    let ir = std::fs::read_dir(&examples).expect("to read examples").map(|entry| entry.expect("to read entry").path()).filter(|path| {
+        let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        name.starts_with("calls-") && name.ends_with(".ll")
+    }).max_by_key(|path| path.metadata().and_then(|metadata| metadata.modified()).ok()).unwrap_or_else(|| panic!("No LLVM IR within {}", examples.display()));
+    read(&ir)
+}
+
+fn read(path: &PathBuf) -> String {
+    std::fs::read_to_string(path).unwrap_or_else(|error| panic!("Cannot read {}: {}", path.display(), error))
+}
+
+///Returns instructions of function, or instructions of function, which it is alias of, after merging identical functions
+fn instructions<'a>(ir: &'a str, name: &str) -> Vec<&'a str> {
+    let alias = format!("@{} = ", name);
+    if let Some(line) = ir.lines().find(|line| line.starts_with(&alias)) {
+        let aliased = line.rsplit('@').next().expect("aliased function").trim();
+        return instructions(ir, aliased);
+    }
+
+    let definition = format!("@{}(", name);
+    let mut lines = ir.lines().skip_while(|line| !(line.starts_with("define") && line.contains(&definition)));
+    assert!(lines.next().is_some(), "Function '{}' is not defined", name);
+    lines.take_while(|line| *line != "}").map(str::trim).filter(|line| !line.is_empty() && !line.starts_with(';') && !line.ends_with(':')).collect()
+}
+
+#[test]
+fn should_compile_forwarded_call_into_direct_call() {
+    let ir = build_ir();
+    for call in CALLS {
+        let direct = instructions(&ir, &format!("direct_{}", call));
+        let forwarded = instructions(&ir, &format!("forwarded_{}", call));
+        let calls = forwarded.iter().filter(|line| (line.contains("call ") || line.contains("invoke ")) && !line.contains("@llvm.")).collect::<Vec<_>>();
+        assert!(calls.is_empty(), "Forwarded call '{}' is not inlined:\n{}", call, forwarded.join("\n"));
+        //Value taken by `self` is moved, rather than copied into temporary first
+        let copies = |lines: &[&str]| lines.iter().filter(|line| line.contains("@llvm.memcpy")).count();
+        assert!(copies(&forwarded) <= copies(&direct), "Forwarded call '{}' copies more memory than direct call:\n{}\n\nDirect:\n{}", call, forwarded.join("\n"), direct.join("\n"));
+        assert!(forwarded.len() <= direct.len(), "Forwarded call '{}' has more instructions than direct call:\n{}\n\nDirect:\n{}", call, forwarded.join("\n"), direct.join("\n"));
+    }
+}
@@ -0,0 +1,90 @@
+//!Pairs of direct and forwarded calls, which are expected to compile into the same code
+
+use auto_trait_zero_cost::{Checksum, Converted, Holder, Lolka, Owned, Shared, Wrapper};
+
+#[no_mangle]
+pub fn direct_ref(value: &u32) -> u32 {
+    value.add(1)
+}
+
+#[no_mangle]
+pub fn forwarded_ref(wrapper: &Wrapper) -> u32 {
+    wrapper.add(1)
+}
+
+#[no_mangle]
+pub fn direct_mut(value: &mut u32) {
+    value.increment()
+}
+
+#[no_mangle]
+pub fn forwarded_mut(wrapper: &mut Wrapper) {
+    wrapper.increment()
+}
+
+#[no_mangle]
+pub fn direct_owned(value: u32) -> u32 {
+    value.into_lolka()
+}
+
+#[no_mangle]
+pub fn forwarded_owned(wrapper: Wrapper) -> u32 {
+    wrapper.into_lolka()
+}
+
+//Box is borrowed, as it is target of forwarding
+#[allow(clippy::borrowed_box)]
+#[no_mangle]
+pub fn direct_box(value: &Box<u32>) -> u32 {
+    (**value).add(1)
+}
+
+#[allow(clippy::borrowed_box)]
+#[no_mangle]
+pub fn forwarded_box(value: &Box<u32>) -> u32 {
+    value.add(1)
+}
+
+#[no_mangle]
+pub fn direct_shared(value: &u32) -> u32 {
+    value.add(1)
+}
+
+#[no_mangle]
+pub fn forwarded_shared(shared: &Shared) -> u32 {
+    shared.add(1)
+}
+
+//Large value is moved out of wrapper in place, as it would be by destructuring
+#[no_mangle]
+pub fn direct_field(holder: Holder) -> u64 {
+    holder.block.checksum()
+}
+
+#[no_mangle]
+pub fn forwarded_field(holder: Holder) -> u64 {
+    holder.checksum()
+}
+
+#[no_mangle]
+pub fn direct_into(converted: Converted) -> u64 {
+    converted.0.checksum()
+}
+
+#[no_mangle]
+pub fn forwarded_into(converted: Converted) -> u64 {
+    converted.checksum()
+}
+
+#[no_mangle]
+pub fn direct_newtype(owned: Owned<auto_trait_zero_cost::Block>) -> u64 {
+    owned.0.checksum()
+}
+
+#[no_mangle]
+pub fn forwarded_newtype(owned: Owned<auto_trait_zero_cost::Block>) -> u64 {
+    owned.checksum()
+}
+
+fn main() {
+}
@@ -0,0 +1,109 @@
+//!Trait forwarded for wrappers, which calls of other crate are compared with direct calls
+//!
+//!Implementation for `u32` and conversions of wrappers are annotated with `#[inline]`, so that only generated code can prevent direct and forwarded calls from compiling into the same code.
+
+use auto_trait::auto_trait;
+
+pub struct Wrapper(pub u32);
+
+impl core::ops::Deref for Wrapper {
+    type Target = u32;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Wrapper {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Wrapper> for u32 {
+    #[inline]
+    fn from(wrapper: Wrapper) -> Self {
+        wrapper.0
+    }
+}
+
+pub struct Shared(pub u32);
+
+impl core::ops::Deref for Shared {
+    type Target = u32;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Shared {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Shared> for u32 {
+    #[inline]
+    fn from(shared: Shared) -> Self {
+        shared.0
+    }
+}
+
+#[auto_trait(Wrapper => u32, Box<T: Lolka>)]
+#[auto_trait(Shared => u32, shared)]
+pub trait Lolka {
+    fn add(&self, value: u32) -> u32;
+    fn increment(&mut self);
+    fn into_lolka(self) -> u32 where Self: Sized;
+}
+
+impl Lolka for u32 {
+    #[inline]
+    fn add(&self, value: u32) -> u32 {
+        self.wrapping_add(value)
+    }
+
+    #[inline]
+    fn increment(&mut self) {
+        *self = self.wrapping_add(1);
+    }
+
+    #[inline]
+    fn into_lolka(self) -> u32 {
+        self
+    }
+}
+
+///Value, which is large enough to be moved by copying memory
+pub struct Block(pub [u64; 512]);
+
+pub struct Holder {
+    pub block: Block,
+    pub id: u32,
+}
+
+pub struct Converted(pub Block);
+
+impl From<Converted> for Block {
+    #[inline]
+    fn from(converted: Converted) -> Self {
+        converted.0
+    }
+}
+
+#[auto_trait(Holder => Block, field = block)]
+#[auto_trait(Converted => Block)]
+#[auto_trait(newtype = Owned)]
+pub trait Checksum {
+    fn checksum(self) -> u64 where Self: Sized;
+}
+
+impl Checksum for Block {
+    #[inline]
+    fn checksum(self) -> u64 {
+        self.0.iter().fold(0, |sum, value| sum.wrapping_add(*value))
+    }
+}